@@ -39,6 +39,11 @@ fn main() {
     }
     config.include(&qt_include_path).build("src/lib.rs");
 
+    // `qt5` or `qt6` for code that only depends on the major version, and `qt_minor = "N"`
+    // for the exact minor version. Use the `qt_5_N` / `qt_6_N` flags below for "N or later".
+    println!("cargo:rustc-cfg=qt{}", qt_version.major);
+    println!("cargo:rustc-cfg=qt_minor=\"{}\"", qt_version.minor);
+
     for minor in 7..=15 {
         if qt_version >= Version::new(5, minor, 0) {
             println!("cargo:rustc-cfg=qt_{}_{}", 5, minor);
@@ -20,6 +20,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 #include <QtCore/QObject>
 #include <QtCore/QEvent>
 #include <QtCore/QDebug>
+#include <type_traits>
 
 /// Pointer to a method of QObject which takes no arguments and returns nothing.
 /// Actually this is a "type-erased" method with various arguments and return
@@ -178,12 +179,32 @@ struct RustObject : Base {
     }
 };
 
+class QQmlParserStatus;
+class QQmlPropertyValueSource;
+
+/// Offset of the Interface within T, or -1 if T does not implement it.
+/// Same as QQmlPrivate::StaticCastSelector, without depending on QtQml.
+template<typename Interface, typename T, bool = std::is_base_of<Interface, T>::value>
+struct RustObjectInterfaceCast {
+    static int cast() { return -1; }
+};
+
+template<typename Interface, typename T>
+struct RustObjectInterfaceCast<Interface, T, true> {
+    static int cast() {
+        return int(reinterpret_cast<quintptr>(static_cast<Interface *>(
+            reinterpret_cast<T *>(0x10000000))) - 0x10000000);
+    }
+};
+
 struct RustQObjectDescriptor {
     size_t size;
     const QMetaObject *baseMetaObject;
     QObject *(*create)(const TraitObject *, const TraitObject *);
     void (*qmlConstruct)(void *, const TraitObject *, const TraitObject *, void (*extra_destruct)(QObject *));
     TraitObject (*get_rust_refcell)(QObject *); // Possible optimisation: make this an offset
+    int parserStatusCast;
+    int valueSourceCast;
 
     /// Get singleton-per-type descriptor.
     template<typename T>
@@ -217,7 +238,9 @@ const RustQObjectDescriptor *RustQObjectDescriptor::instance() {
         },
         /*get_rust_refcell*/ [](QObject *q) {
             return static_cast<T *>(q)->ptr_qobject;
-        }
+        },
+        /*parserStatusCast*/ RustObjectInterfaceCast<QQmlParserStatus, T>::cast(),
+        /*valueSourceCast*/ RustObjectInterfaceCast<QQmlPropertyValueSource, T>::cast()
     };
     return &desc;
 }
@@ -0,0 +1,693 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Bindings for the animation classes of QtCore.
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QParallelAnimationGroup>
+    #include <QtCore/QPointer>
+    #include <QtCore/QSequentialAnimationGroup>
+    #include <QtCore/QTimeLine>
+    #include <QtCore/QVariantAnimation>
+
+    struct QTimeLineHolder {
+        std::unique_ptr<QTimeLine> timeline;
+
+        QTimeLineHolder(int duration) : timeline(new QTimeLine(duration)) {}
+    };
+
+    /// QVariantAnimation which can delegate the interpolation to a Rust closure.
+    struct RustVariantAnimation : QVariantAnimation {
+        /// Wrapped Box<dyn Fn(QVariant, QVariant, f64) -> QVariant>, if any.
+        TraitObject interpolator = {};
+
+        QVariant interpolated(const QVariant &from, const QVariant &to, qreal progress) const override {
+            if (!interpolator.isValid()) {
+                return QVariantAnimation::interpolated(from, to, progress);
+            }
+            auto interpolator = this->interpolator;
+            return rust!(RustVariantAnimation_interpolated [
+                interpolator: *const dyn Fn(QVariant, QVariant, f64) -> QVariant as "TraitObject",
+                from: &QVariant as "const QVariant &",
+                to: &QVariant as "const QVariant &",
+                progress: f64 as "qreal"
+            ] -> QVariant as "QVariant" {
+                (*interpolator)(from.clone(), to.clone(), progress)
+            });
+        }
+
+        void setInterpolator(TraitObject f) {
+            auto old = interpolator;
+            interpolator = f;
+            dropInterpolator(old);
+        }
+
+        static void dropInterpolator(TraitObject f) {
+            if (f.isValid()) {
+                rust!(RustVariantAnimation_dropInterpolator [
+                    f: *mut dyn Fn(QVariant, QVariant, f64) -> QVariant as "TraitObject"
+                ] {
+                    let _ = Box::from_raw(f);
+                });
+            }
+        }
+
+        ~RustVariantAnimation() {
+            dropInterpolator(interpolator);
+        }
+    };
+
+    static RustVariantAnimation *asVariantAnimation(const QPointer<QAbstractAnimation> &ptr) {
+        return static_cast<RustVariantAnimation *>(ptr.data());
+    }
+
+    static QAnimationGroup *asAnimationGroup(const QPointer<QAbstractAnimation> &ptr) {
+        return static_cast<QAnimationGroup *>(ptr.data());
+    }
+}}
+
+/// Wrapper around [`QTimeLine::State`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qtimeline.html#State-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeLineState {
+    NotRunning,
+    Paused,
+    Running,
+}
+
+cpp_class!(
+    /// Wrapper around [`QTimeLine`][class] class.
+    ///
+    /// The time line needs a running event loop. Use the `*_signal()` functions together
+    /// with [`cpp_ptr`](#method.cpp_ptr) and [`connections::connect`](../connections/fn.connect.html)
+    /// to be notified of the changes.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtimeline.html
+    pub unsafe struct QTimeLine as "QTimeLineHolder"
+);
+
+impl QTimeLine {
+    /// Creates a time line with the given duration in milliseconds.
+    pub fn new(duration_ms: i32) -> QTimeLine {
+        cpp!(unsafe [duration_ms as "int"] -> QTimeLine as "QTimeLineHolder" {
+            return QTimeLineHolder(duration_ms);
+        })
+    }
+
+    /// Returns a pointer to the underlying QTimeLine, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QTimeLineHolder *"] -> *mut c_void as "QTimeLine *" {
+            return self->timeline.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::setDuration
+    pub fn set_duration(&mut self, duration_ms: i32) {
+        cpp!(unsafe [self as "QTimeLineHolder *", duration_ms as "int"] {
+            self->timeline->setDuration(duration_ms);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::duration
+    pub fn duration(&self) -> i32 {
+        cpp!(unsafe [self as "QTimeLineHolder *"] -> i32 as "int" {
+            return self->timeline->duration();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::setEasingCurve
+    pub fn set_easing_curve(&mut self, curve: QEasingCurve) {
+        cpp!(unsafe [self as "QTimeLineHolder *", curve as "QEasingCurve"] {
+            self->timeline->setEasingCurve(curve);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::easingCurve
+    pub fn easing_curve(&self) -> QEasingCurve {
+        cpp!(unsafe [self as "QTimeLineHolder *"] -> QEasingCurve as "QEasingCurve" {
+            return self->timeline->easingCurve();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::setFrameRange
+    pub fn set_frame_range(&mut self, start: i32, end: i32) {
+        cpp!(unsafe [self as "QTimeLineHolder *", start as "int", end as "int"] {
+            self->timeline->setFrameRange(start, end);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::setLoopCount
+    pub fn set_loop_count(&mut self, count: i32) {
+        cpp!(unsafe [self as "QTimeLineHolder *", count as "int"] {
+            self->timeline->setLoopCount(count);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::setUpdateInterval
+    pub fn set_update_interval(&mut self, interval_ms: i32) {
+        cpp!(unsafe [self as "QTimeLineHolder *", interval_ms as "int"] {
+            self->timeline->setUpdateInterval(interval_ms);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::start
+    pub fn start(&self) {
+        cpp!(unsafe [self as "QTimeLineHolder *"] {
+            self->timeline->start();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::stop
+    pub fn stop(&self) {
+        cpp!(unsafe [self as "QTimeLineHolder *"] {
+            self->timeline->stop();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::setPaused
+    pub fn set_paused(&self, paused: bool) {
+        cpp!(unsafe [self as "QTimeLineHolder *", paused as "bool"] {
+            self->timeline->setPaused(paused);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::state
+    pub fn state(&self) -> TimeLineState {
+        cpp!(unsafe [self as "QTimeLineHolder *"] -> TimeLineState as "QTimeLine::State" {
+            return self->timeline->state();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::currentValue
+    pub fn current_value(&self) -> f64 {
+        cpp!(unsafe [self as "QTimeLineHolder *"] -> f64 as "double" {
+            return self->timeline->currentValue();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::currentFrame
+    pub fn current_frame(&self) -> i32 {
+        cpp!(unsafe [self as "QTimeLineHolder *"] -> i32 as "int" {
+            return self->timeline->currentFrame();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::valueChanged
+    pub fn value_changed_signal() -> Signal<fn(qreal)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTimeLine::valueChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::frameChanged
+    pub fn frame_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTimeLine::frameChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QTimeLine::finished
+    pub fn finished_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTimeLine::finished;
+            }))
+        }
+    }
+}
+
+/// Wrapper around [`QAbstractAnimation::State`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qabstractanimation.html#State-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationState {
+    Stopped,
+    Paused,
+    Running,
+}
+
+cpp_class!(
+    /// A non-owning handle to a [`QAbstractAnimation`][class].
+    ///
+    /// This is what the concrete animation types dereference to, so the functions defined here
+    /// are available on all of them. The handle becomes null once the animation is destroyed,
+    /// in which case all the functions do nothing.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qabstractanimation.html
+    #[derive(Clone)]
+    pub unsafe struct QAbstractAnimation as "QPointer<QAbstractAnimation>"
+);
+
+impl QAbstractAnimation {
+    /// Returns a pointer to the underlying QAbstractAnimation, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    ///
+    /// Returns a null pointer if the animation was destroyed.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] -> *mut c_void as "QAbstractAnimation *" {
+            return self->data();
+        })
+    }
+
+    /// Returns true if the animation was destroyed.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::start
+    pub fn start(&self) {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] {
+            if (*self)
+                (*self)->start();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::stop
+    pub fn stop(&self) {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] {
+            if (*self)
+                (*self)->stop();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::setPaused
+    pub fn set_paused(&self, paused: bool) {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *", paused as "bool"] {
+            if (*self)
+                (*self)->setPaused(paused);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::state
+    pub fn state(&self) -> AnimationState {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] -> AnimationState as "QAbstractAnimation::State" {
+            return *self ? (*self)->state() : QAbstractAnimation::Stopped;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::setLoopCount
+    pub fn set_loop_count(&self, count: i32) {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *", count as "int"] {
+            if (*self)
+                (*self)->setLoopCount(count);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::totalDuration
+    pub fn total_duration(&self) -> i32 {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] -> i32 as "int" {
+            return *self ? (*self)->totalDuration() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::currentTime
+    pub fn current_time(&self) -> i32 {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] -> i32 as "int" {
+            return *self ? (*self)->currentTime() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAnimation::finished
+    pub fn finished_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractAnimation::finished;
+            }))
+        }
+    }
+
+    /// Deletes the animation, unless it is owned by a parent such as an animation group.
+    fn delete_if_unowned(&self) {
+        cpp!(unsafe [self as "const QPointer<QAbstractAnimation> *"] {
+            if (*self && !(*self)->parent())
+                delete self->data();
+        })
+    }
+}
+
+/// Wrapper around [`QVariantAnimation`][class] class.
+///
+/// Without an interpolator set by [`set_interpolator`](#method.set_interpolator), the
+/// interpolation falls back to the one from Qt, which supports the usual numeric, geometric
+/// and color types.
+///
+/// The animation is deleted when this object is dropped, unless it was added to an
+/// animation group in the mean time, in which case the group owns it.
+///
+/// [class]: https://doc.qt.io/qt-5/qvariantanimation.html
+pub struct QVariantAnimation {
+    animation: QAbstractAnimation,
+}
+
+impl Default for QVariantAnimation {
+    fn default() -> Self {
+        QVariantAnimation {
+            animation: cpp!(unsafe [] -> QAbstractAnimation as "QPointer<QAbstractAnimation>" {
+                return new RustVariantAnimation;
+            }),
+        }
+    }
+}
+
+impl Drop for QVariantAnimation {
+    fn drop(&mut self) {
+        self.animation.delete_if_unowned();
+    }
+}
+
+impl std::ops::Deref for QVariantAnimation {
+    type Target = QAbstractAnimation;
+    fn deref(&self) -> &QAbstractAnimation {
+        &self.animation
+    }
+}
+
+impl QVariantAnimation {
+    /// Creates a new animation
+    pub fn new() -> QVariantAnimation {
+        QVariantAnimation::default()
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::setStartValue
+    pub fn set_start_value(&mut self, value: QVariant) {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *", value as "QVariant"] {
+            if (auto a = asVariantAnimation(*animation))
+                a->setStartValue(value);
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::startValue
+    pub fn start_value(&self) -> QVariant {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *"] -> QVariant as "QVariant" {
+            auto a = asVariantAnimation(*animation);
+            return a ? a->startValue() : QVariant();
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::setEndValue
+    pub fn set_end_value(&mut self, value: QVariant) {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *", value as "QVariant"] {
+            if (auto a = asVariantAnimation(*animation))
+                a->setEndValue(value);
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::endValue
+    pub fn end_value(&self) -> QVariant {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *"] -> QVariant as "QVariant" {
+            auto a = asVariantAnimation(*animation);
+            return a ? a->endValue() : QVariant();
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::setDuration
+    pub fn set_duration(&mut self, duration_ms: i32) {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *", duration_ms as "int"] {
+            if (auto a = asVariantAnimation(*animation))
+                a->setDuration(duration_ms);
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::duration
+    pub fn duration(&self) -> i32 {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *"] -> i32 as "int" {
+            auto a = asVariantAnimation(*animation);
+            return a ? a->duration() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::setEasingCurve
+    pub fn set_easing_curve(&mut self, curve: QEasingCurve) {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *", curve as "QEasingCurve"] {
+            if (auto a = asVariantAnimation(*animation))
+                a->setEasingCurve(curve);
+        })
+    }
+
+    /// Set the function used to compute the current value from the start value, the end
+    /// value, and the progress (between 0 and 1, after the easing curve was applied).
+    ///
+    /// This replaces any previously set interpolator.
+    pub fn set_interpolator(&mut self, f: impl Fn(QVariant, QVariant, f64) -> QVariant + 'static) {
+        let animation = &self.animation;
+        let f: *mut dyn Fn(QVariant, QVariant, f64) -> QVariant = Box::into_raw(Box::new(f));
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *", f as "TraitObject"] {
+            if (auto a = asVariantAnimation(*animation))
+                a->setInterpolator(f);
+            else
+                RustVariantAnimation::dropInterpolator(f);
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::currentValue
+    pub fn current_value(&self) -> QVariant {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *"] -> QVariant as "QVariant" {
+            auto a = asVariantAnimation(*animation);
+            return a ? a->currentValue() : QVariant();
+        })
+    }
+
+    /// Refer to the Qt documentation of QVariantAnimation::valueChanged
+    pub fn value_changed_signal() -> Signal<fn(QVariant)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QVariantAnimation::valueChanged;
+            }))
+        }
+    }
+}
+
+fn group_add_animation(group: &QAbstractAnimation, animation: &QAbstractAnimation) {
+    cpp!(unsafe [group as "const QPointer<QAbstractAnimation> *",
+                 animation as "const QPointer<QAbstractAnimation> *"] {
+        auto g = asAnimationGroup(*group);
+        if (g && *animation)
+            g->addAnimation(animation->data());
+    })
+}
+
+fn group_remove_animation(group: &QAbstractAnimation, animation: &QAbstractAnimation) {
+    cpp!(unsafe [group as "const QPointer<QAbstractAnimation> *",
+                 animation as "const QPointer<QAbstractAnimation> *"] {
+        auto g = asAnimationGroup(*group);
+        if (g && *animation)
+            g->removeAnimation(animation->data());
+    })
+}
+
+fn group_animation_count(group: &QAbstractAnimation) -> i32 {
+    cpp!(unsafe [group as "const QPointer<QAbstractAnimation> *"] -> i32 as "int" {
+        auto g = asAnimationGroup(*group);
+        return g ? g->animationCount() : 0;
+    })
+}
+
+fn group_animation_at(group: &QAbstractAnimation, index: i32) -> Option<QAbstractAnimation> {
+    let animation = cpp!(unsafe [group as "const QPointer<QAbstractAnimation> *", index as "int"]
+            -> QAbstractAnimation as "QPointer<QAbstractAnimation>" {
+        auto g = asAnimationGroup(*group);
+        return g && index >= 0 && index < g->animationCount() ? g->animationAt(index) : nullptr;
+    });
+    if animation.is_null() {
+        None
+    } else {
+        Some(animation)
+    }
+}
+
+/// Wrapper around [`QParallelAnimationGroup`][class] class.
+///
+/// The group takes ownership of the animations added with
+/// [`add_animation`](#method.add_animation), and deletes them when it is itself deleted.
+/// The group is deleted when this object is dropped, unless it was itself added to another
+/// group.
+///
+/// [class]: https://doc.qt.io/qt-5/qparallelanimationgroup.html
+pub struct QParallelAnimationGroup {
+    animation: QAbstractAnimation,
+}
+
+impl Default for QParallelAnimationGroup {
+    fn default() -> Self {
+        QParallelAnimationGroup {
+            animation: cpp!(unsafe [] -> QAbstractAnimation as "QPointer<QAbstractAnimation>" {
+                return new QParallelAnimationGroup;
+            }),
+        }
+    }
+}
+
+impl Drop for QParallelAnimationGroup {
+    fn drop(&mut self) {
+        self.animation.delete_if_unowned();
+    }
+}
+
+impl std::ops::Deref for QParallelAnimationGroup {
+    type Target = QAbstractAnimation;
+    fn deref(&self) -> &QAbstractAnimation {
+        &self.animation
+    }
+}
+
+impl QParallelAnimationGroup {
+    /// Creates a new empty group
+    pub fn new() -> QParallelAnimationGroup {
+        QParallelAnimationGroup::default()
+    }
+
+    /// Adds an animation to the group, which takes ownership of it.
+    ///
+    /// Refer to the Qt documentation of QAnimationGroup::addAnimation
+    pub fn add_animation(&mut self, animation: &QAbstractAnimation) {
+        group_add_animation(&self.animation, animation)
+    }
+
+    /// Removes an animation from the group. The ownership goes back to the caller: the
+    /// animation is leaked if the object it was created with was already dropped.
+    ///
+    /// Refer to the Qt documentation of QAnimationGroup::removeAnimation
+    pub fn remove_animation(&mut self, animation: &QAbstractAnimation) {
+        group_remove_animation(&self.animation, animation)
+    }
+
+    /// Refer to the Qt documentation of QAnimationGroup::animationCount
+    pub fn animation_count(&self) -> i32 {
+        group_animation_count(&self.animation)
+    }
+
+    /// Refer to the Qt documentation of QAnimationGroup::animationAt
+    pub fn animation_at(&self, index: i32) -> Option<QAbstractAnimation> {
+        group_animation_at(&self.animation, index)
+    }
+}
+
+/// Wrapper around [`QSequentialAnimationGroup`][class] class.
+///
+/// The group takes ownership of the animations added with
+/// [`add_animation`](#method.add_animation), and deletes them when it is itself deleted.
+/// The group is deleted when this object is dropped, unless it was itself added to another
+/// group.
+///
+/// [class]: https://doc.qt.io/qt-5/qsequentialanimationgroup.html
+pub struct QSequentialAnimationGroup {
+    animation: QAbstractAnimation,
+}
+
+impl Default for QSequentialAnimationGroup {
+    fn default() -> Self {
+        QSequentialAnimationGroup {
+            animation: cpp!(unsafe [] -> QAbstractAnimation as "QPointer<QAbstractAnimation>" {
+                return new QSequentialAnimationGroup;
+            }),
+        }
+    }
+}
+
+impl Drop for QSequentialAnimationGroup {
+    fn drop(&mut self) {
+        self.animation.delete_if_unowned();
+    }
+}
+
+impl std::ops::Deref for QSequentialAnimationGroup {
+    type Target = QAbstractAnimation;
+    fn deref(&self) -> &QAbstractAnimation {
+        &self.animation
+    }
+}
+
+impl QSequentialAnimationGroup {
+    /// Creates a new empty group
+    pub fn new() -> QSequentialAnimationGroup {
+        QSequentialAnimationGroup::default()
+    }
+
+    /// Adds an animation to the group, which takes ownership of it.
+    ///
+    /// Refer to the Qt documentation of QAnimationGroup::addAnimation
+    pub fn add_animation(&mut self, animation: &QAbstractAnimation) {
+        group_add_animation(&self.animation, animation)
+    }
+
+    /// Removes an animation from the group. The ownership goes back to the caller: the
+    /// animation is leaked if the object it was created with was already dropped.
+    ///
+    /// Refer to the Qt documentation of QAnimationGroup::removeAnimation
+    pub fn remove_animation(&mut self, animation: &QAbstractAnimation) {
+        group_remove_animation(&self.animation, animation)
+    }
+
+    /// Refer to the Qt documentation of QAnimationGroup::animationCount
+    pub fn animation_count(&self) -> i32 {
+        group_animation_count(&self.animation)
+    }
+
+    /// Refer to the Qt documentation of QAnimationGroup::animationAt
+    pub fn animation_at(&self, index: i32) -> Option<QAbstractAnimation> {
+        group_animation_at(&self.animation, index)
+    }
+
+    /// Refer to the Qt documentation of QSequentialAnimationGroup::addPause
+    pub fn add_pause(&mut self, duration_ms: i32) {
+        let animation = &self.animation;
+        cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *", duration_ms as "int"] {
+            if (*animation)
+                static_cast<QSequentialAnimationGroup *>(animation->data())->addPause(duration_ms);
+        })
+    }
+
+    /// Returns a handle to the animation currently running, if any.
+    ///
+    /// Refer to the Qt documentation of QSequentialAnimationGroup::currentAnimation
+    pub fn current_animation(&self) -> Option<QAbstractAnimation> {
+        let animation = &self.animation;
+        let current = cpp!(unsafe [animation as "const QPointer<QAbstractAnimation> *"]
+                -> QAbstractAnimation as "QPointer<QAbstractAnimation>" {
+            if (!*animation)
+                return nullptr;
+            return static_cast<QSequentialAnimationGroup *>(animation->data())->currentAnimation();
+        });
+        if current.is_null() {
+            None
+        } else {
+            Some(current)
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Functions operating on the application object (`QCoreApplication`).
+//!
+//! An application object (e.g. from [`QmlEngine::new`](../struct.QmlEngine.html#method.new))
+//! must exist in the process for these functions to do anything.
+use cpp::cpp;
+
+use crate::ProcessEventsFlags;
+
+cpp! {{
+    #include <QtCore/QCoreApplication>
+}}
+
+/// Processes all pending events for the calling thread.
+///
+/// This is a lighter alternative to running a full event loop, for example in unit tests.
+///
+/// Refer to the Qt documentation of QCoreApplication::processEvents
+pub fn process_events() {
+    process_events_with_flags(ProcessEventsFlags::AllEvents)
+}
+
+/// Processes pending events for the calling thread, according to the given flags.
+///
+/// Refer to the Qt documentation of QCoreApplication::processEvents
+pub fn process_events_with_flags(flags: ProcessEventsFlags) {
+    cpp!(unsafe [flags as "QEventLoop::ProcessEventsFlags"] {
+        QCoreApplication::processEvents(flags);
+    })
+}
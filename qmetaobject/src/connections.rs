@@ -83,6 +83,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 //! [`connect`]: ./fn.connect.html
 #![deny(missing_docs)]
 use std::os::raw::c_void;
+use std::rc::Rc;
 
 use cpp::{cpp, cpp_class};
 
@@ -510,3 +511,535 @@ pub unsafe fn connect<Args, F: Slot<Args>>(
         );
     })
 }
+
+/// Slot wrapper used by `connect_once`: forwards the first call to the wrapped slot and
+/// disconnects itself.
+struct OnceSlot<F> {
+    slot: Option<F>,
+    connection: Rc<RefCell<Option<ConnectionHandle>>>,
+}
+
+impl<Args, F: Slot<Args>> Slot<Args> for OnceSlot<F> {
+    unsafe fn apply(&mut self, a: *const *const c_void) {
+        if let Some(mut slot) = self.slot.take() {
+            // Qt keeps the slot object alive until the end of the call, so it is fine to
+            // disconnect from within the slot.
+            if let Some(mut connection) = self.connection.borrow_mut().take() {
+                connection.disconnect();
+            }
+            slot.apply(a);
+        }
+    }
+}
+
+/// Connect signal from sender object to a slot that is called only once.
+///
+/// Same as [`connect`][], but the connection is disconnected the first time the signal is
+/// emitted, before the slot is called. The returned handle can still be used to disconnect
+/// before the signal was emitted.
+///
+/// [`connect`]: ./fn.connect.html
+pub unsafe fn connect_once<Args, F: Slot<Args>>(
+    sender: *const c_void,
+    signal: Signal<Args>,
+    slot: F,
+) -> ConnectionHandle {
+    let connection = Rc::new(RefCell::new(None));
+    let handle =
+        connect(sender, signal, OnceSlot { slot: Some(slot), connection: connection.clone() });
+    *connection.borrow_mut() = Some(handle.clone());
+    handle
+}
+
+/// Identifier of a slot added to a [`SignalMultiplexer`][], used to remove it.
+///
+/// [`SignalMultiplexer`]: ./struct.SignalMultiplexer.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotId(usize);
+
+/// Slots of a `SignalMultiplexer`, shared with the slot object of the C++ connection.
+struct MultiplexedSlots<Args> {
+    slots: Vec<(SlotId, Rc<RefCell<dyn Slot<Args>>>)>,
+    next_id: usize,
+}
+
+/// Slot of the C++ connection of a `SignalMultiplexer`, which calls all the Rust slots.
+struct MultiplexerDispatch<Args> {
+    slots: Rc<RefCell<MultiplexedSlots<Args>>>,
+}
+
+impl<Args> Slot<Args> for MultiplexerDispatch<Args> {
+    unsafe fn apply(&mut self, a: *const *const c_void) {
+        // Work on a copy of the list so that slots can be added or removed from within a slot.
+        let snapshot = self.slots.borrow().slots.clone();
+        for (id, slot) in snapshot {
+            if !self.slots.borrow().slots.iter().any(|(i, _)| *i == id) {
+                continue;
+            }
+            // A slot which emits the signal again is not called recursively: it is still
+            // running, so it is skipped for the nested emission.
+            if let Ok(mut slot) = slot.try_borrow_mut() {
+                slot.apply(a);
+            }
+        }
+    }
+}
+
+/// Calls any number of Rust slots from a single connection to a signal.
+///
+/// Each call to [`connect`][] creates a new connection in Qt. When many Rust closures need to
+/// be notified of the same signal, a `SignalMultiplexer` connects only once and dispatches the
+/// signal to all its slots, in the order they were added.
+///
+/// A slot may emit the signal again: the nested emission is dispatched to all the other slots,
+/// but not to the slots which are still running.
+///
+/// The connection is disconnected when the multiplexer is dropped.
+///
+/// [`connect`]: ./fn.connect.html
+pub struct SignalMultiplexer<Args> {
+    slots: Rc<RefCell<MultiplexedSlots<Args>>>,
+    connection: ConnectionHandle,
+}
+
+impl<Args: 'static> SignalMultiplexer<Args> {
+    /// Connects to the signal of the sender object, without any slot yet.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`connect`][]: `sender` must be a pointer to a `QObject` which has this signal.
+    ///
+    /// [`connect`]: ./fn.connect.html
+    pub unsafe fn new(sender: *const c_void, signal: Signal<Args>) -> Self {
+        let slots = Rc::new(RefCell::new(MultiplexedSlots { slots: Vec::new(), next_id: 0 }));
+        let connection = connect(sender, signal, MultiplexerDispatch { slots: slots.clone() });
+        SignalMultiplexer { slots, connection }
+    }
+
+    /// Adds a slot called each time the signal is emitted. Returns an identifier that can be
+    /// passed to [`remove_slot`](#method.remove_slot).
+    pub fn add_slot<F: Slot<Args> + 'static>(&mut self, slot: F) -> SlotId {
+        let mut slots = self.slots.borrow_mut();
+        let id = SlotId(slots.next_id);
+        slots.next_id += 1;
+        let slot: Rc<RefCell<dyn Slot<Args>>> = Rc::new(RefCell::new(slot));
+        slots.slots.push((id, slot));
+        id
+    }
+
+    /// Removes a slot previously added with [`add_slot`](#method.add_slot).
+    /// Returns false if there was no such slot.
+    pub fn remove_slot(&mut self, id: SlotId) -> bool {
+        let mut slots = self.slots.borrow_mut();
+        let len = slots.slots.len();
+        slots.slots.retain(|(i, _)| *i != id);
+        slots.slots.len() != len
+    }
+
+    /// Returns the number of slots.
+    pub fn slot_count(&self) -> usize {
+        self.slots.borrow().slots.len()
+    }
+
+    /// Returns true if the connection to the signal is valid.
+    pub fn is_connected(&self) -> bool {
+        self.connection.is_valid()
+    }
+}
+
+impl<Args> Drop for SignalMultiplexer<Args> {
+    fn drop(&mut self) {
+        self.connection.disconnect();
+    }
+}
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtCore/QThread>
+
+    // Receiver object for connections to a signal given by its signature. Like
+    // RustPropertyObserver below, it has a single dynamic slot dispatched in qt_metacall.
+    class RustSignalReceiver : public QObject {
+        /// Wrapper for `*mut dyn RustFuncType`
+        TraitObject function;
+        int callDepth = 0;
+
+    public:
+        explicit RustSignalReceiver(TraitObject function) : function(function) {}
+
+        ~RustSignalReceiver() {
+            rust!(RustSignalReceiver_destruct [
+                function: *mut RustFuncType as "TraitObject"
+            ] {
+                let _ = Box::from_raw(function);
+            });
+        }
+
+        static int slotIndex() {
+            return QObject::staticMetaObject.methodCount();
+        }
+
+        int qt_metacall(QMetaObject::Call c, int id, void **a) override {
+            if (c != QMetaObject::InvokeMetaMethod || id != slotIndex()) {
+                return QObject::qt_metacall(c, id, a);
+            }
+            auto function = this->function;
+            ++callDepth;
+            rust!(RustSignalReceiver_call [
+                function: *mut RustFuncType as "TraitObject",
+                a: *const *const c_void as "void **"
+            ] {
+                (*function)(a);
+            });
+            --callDepth;
+            return -1;
+        }
+
+        // Deletes the receiver, unless the slot is currently running or the receiver lives
+        // in another thread, in which case it is deleted later by its event loop.
+        void release() {
+            if (callDepth == 0 && thread() == QThread::currentThread()) {
+                delete this;
+            } else {
+                deleteLater();
+            }
+        }
+    };
+
+    struct RustSignalConnection {
+        QMetaObject::Connection connection;
+        QPointer<RustSignalReceiver> receiver;
+    };
+}}
+
+/// A signal of a C++ class, identified by its signature.
+///
+/// Unlike [`Signal`][], which needs a pointer to the signal member function and therefore a
+/// `cpp!` wrapper, this only needs the signature of the signal as encoded by Qt's `SIGNAL()`
+/// macro, for example `"2valueChanged(int)"`. The leading `2` is optional. The signal is
+/// looked up in the meta object of the sender when connecting.
+///
+/// Use the [`cpp_signal!`][] macro to declare functions returning such signals, and
+/// [`connect_cpp_signal`][] to connect them.
+///
+/// [`Signal`]: ./struct.Signal.html
+/// [`cpp_signal!`]: ../macro.cpp_signal.html
+/// [`connect_cpp_signal`]: ./fn.connect_cpp_signal.html
+pub struct CppSignal<Args> {
+    signature: &'static str,
+    phantom: std::marker::PhantomData<Args>,
+}
+
+impl<Args> CppSignal<Args> {
+    /// Creates a signal from its signature.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that number, types and order of arguments strictly matches between
+    /// the signal with this signature and `Args`.
+    pub const unsafe fn new(signature: &'static str) -> Self {
+        CppSignal { signature, phantom: std::marker::PhantomData }
+    }
+
+    /// Returns the signature of the signal, as given to `new`.
+    pub fn signature(&self) -> &'static str {
+        self.signature
+    }
+}
+
+// see module-level docs
+impl<Args> Clone for CppSignal<Args> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// see module-level docs
+impl<Args> Copy for CppSignal<Args> {}
+
+/// Declares functions returning a [`CppSignal`][] from the signature of a C++ signal.
+///
+/// ```
+/// use qmetaobject::*;
+///
+/// cpp_signal! {
+///     /// The QObject::objectNameChanged signal
+///     pub fn object_name_changed(QString) = "2objectNameChanged(QString)";
+/// }
+/// # fn main() {
+/// #     assert_eq!(object_name_changed().signature(), "2objectNameChanged(QString)");
+/// # }
+/// ```
+///
+/// [`CppSignal`]: ./connections/struct.CppSignal.html
+#[macro_export]
+macro_rules! cpp_signal {
+    ($($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ty),*) = $signature:literal;)*) => {$(
+        $(#[$attr])*
+        $vis fn $name() -> $crate::connections::CppSignal<fn($($arg),*)> {
+            unsafe { $crate::connections::CppSignal::new($signature) }
+        }
+    )*};
+}
+
+cpp_class!(
+    /// Handle returned by [`connect_cpp_signal`][] and [`subscribe_property_changes`][].
+    ///
+    /// Like [`ConnectionHandle`][], dropping it does not disconnect anything. But
+    /// [`disconnect`](#method.disconnect) also destroys the hidden receiver object, and the
+    /// slot with it.
+    ///
+    /// [`connect_cpp_signal`]: ./fn.connect_cpp_signal.html
+    /// [`subscribe_property_changes`]: ./fn.subscribe_property_changes.html
+    /// [`ConnectionHandle`]: ./struct.ConnectionHandle.html
+    pub unsafe struct CppSignalConnection as "RustSignalConnection"
+);
+
+impl CppSignalConnection {
+    /// Disconnect the slot and drop it.
+    ///
+    /// If called from within the slot itself, or from another thread than the one of the
+    /// sender, the slot is only dropped once the event loop of that thread runs.
+    pub fn disconnect(&mut self) {
+        cpp!(unsafe [self as "RustSignalConnection *"] {
+            QObject::disconnect(self->connection);
+            if (self->receiver) {
+                self->receiver->release();
+            }
+        })
+    }
+
+    /// Returns `true` if the connection is valid.
+    pub fn is_valid(&self) -> bool {
+        cpp!(unsafe [self as "const RustSignalConnection *"] -> bool as "bool" {
+            return self->connection;
+        })
+    }
+}
+
+/// Connect a signal given by its signature to a slot.
+///
+/// Same as [`connect`][], but for a signal declared with [`cpp_signal!`][]. A warning is
+/// printed and an invalid handle is returned if the sender has no such signal.
+///
+/// The connection goes through a hidden receiver object, which owns the slot. It is destroyed
+/// together with the sender, or when [`CppSignalConnection::disconnect`][] is called.
+///
+/// [`connect`]: ./fn.connect.html
+/// [`cpp_signal!`]: ../macro.cpp_signal.html
+/// [`CppSignalConnection::disconnect`]: ./struct.CppSignalConnection.html#method.disconnect
+pub unsafe fn connect_cpp_signal<Args, F: Slot<Args>>(
+    sender: *const c_void,
+    signal: CppSignal<Args>,
+    mut slot: F,
+) -> CppSignalConnection {
+    let signature = signal.signature.strip_prefix('2').unwrap_or(signal.signature);
+    let signature_ptr = signature.as_ptr();
+    let signature_len = signature.len();
+    let slot_closure = move |a: *const *const c_void| slot.apply(a);
+    let slot_closure_boxed: Box<dyn FnMut(*const *const c_void)> = Box::new(slot_closure);
+    let slot_closure_raw: *mut dyn FnMut(*const *const c_void) = Box::into_raw(slot_closure_boxed);
+
+    cpp!(unsafe [
+        sender as "QObject *",
+        signature_ptr as "const char *",
+        signature_len as "size_t",
+        slot_closure_raw as "TraitObject"
+    ] -> CppSignalConnection as "RustSignalConnection" {
+        auto receiver = new RustSignalReceiver(slot_closure_raw);
+        QByteArray normalized = QMetaObject::normalizedSignature(
+            QByteArray(signature_ptr, int(signature_len)).constData());
+        int index = sender ? sender->metaObject()->indexOfSignal(normalized.constData()) : -1;
+        if (index < 0) {
+            qWarning("QObject::connect: No such signal %s", normalized.constData());
+            delete receiver;
+            return {};
+        }
+        receiver->moveToThread(sender->thread());
+        QObject::connect(sender, &QObject::destroyed, receiver, [receiver] { delete receiver; });
+        RustSignalConnection result;
+        result.connection = QMetaObject::connect(sender, index, receiver,
+                                                 RustSignalReceiver::slotIndex(),
+                                                 Qt::DirectConnection);
+        result.receiver = receiver;
+        return result;
+    })
+}
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QMetaProperty>
+    #include <QtCore/QPointer>
+
+    // Receiver object with a single dynamic slot, without moc: the slot is the first method
+    // after the ones of QObject, and is dispatched by overriding qt_metacall.
+    class RustPropertyObserver : public QObject {
+        QPointer<QObject> object;
+        QMetaProperty property;
+        /// Wrapped Box<dyn Fn(QVariant)>
+        TraitObject callback;
+
+    public:
+        RustPropertyObserver(QObject *object, QMetaProperty property, TraitObject callback)
+            : object(object), property(property), callback(callback) {}
+
+        ~RustPropertyObserver() {
+            dropCallback(callback);
+        }
+
+        static int slotIndex() {
+            return QObject::staticMetaObject.methodCount();
+        }
+
+        static void dropCallback(TraitObject callback) {
+            rust!(RustPropertyObserver_dropCallback [
+                callback: *mut dyn Fn(QVariant) as "TraitObject"
+            ] {
+                let _ = Box::from_raw(callback);
+            });
+        }
+
+        int qt_metacall(QMetaObject::Call c, int id, void **a) override {
+            if (c != QMetaObject::InvokeMetaMethod || id != slotIndex()) {
+                return QObject::qt_metacall(c, id, a);
+            }
+            if (object) {
+                QVariant value = property.read(object);
+                auto callback = this->callback;
+                rust!(RustPropertyObserver_call [
+                    callback: *const dyn Fn(QVariant) as "TraitObject",
+                    value: QVariant as "QVariant"
+                ] {
+                    (*callback)(value)
+                });
+            }
+            return -1;
+        }
+    };
+
+    struct PropertyObserverHolder {
+        std::unique_ptr<RustPropertyObserver> observer;
+    };
+}}
+
+/// Calls a callback when a property of a QObject changes.
+///
+/// The notify signal of the property is looked up at run time from its meta object, so this
+/// works with any QObject, including the ones implemented in C++, as long as the property
+/// has a `NOTIFY` signal.
+pub enum PropertyChangeNotifier {}
+
+impl PropertyChangeNotifier {
+    /// Calls `callback` with the new value of the property `property_name` of the object
+    /// `obj` each time its notify signal is emitted, until the returned handle is dropped.
+    ///
+    /// Returns None if the object has no such property, or if the property has no notify
+    /// signal.
+    ///
+    /// # Safety
+    ///
+    /// `obj` must be a valid pointer to a QObject.
+    pub unsafe fn observe(
+        obj: *const c_void,
+        property_name: &str,
+        callback: impl Fn(QVariant) + 'static,
+    ) -> Option<PropertyObserverHandle> {
+        let property_name = CString::new(property_name).ok()?;
+        let property_name = property_name.as_ptr();
+        let callback: *mut dyn Fn(QVariant) = Box::into_raw(Box::new(callback));
+        let handle = cpp!(unsafe [
+            obj as "QObject *",
+            property_name as "const char *",
+            callback as "TraitObject"
+        ] -> PropertyObserverHandle as "PropertyObserverHolder" {
+            PropertyObserverHolder holder;
+            int index = obj ? obj->metaObject()->indexOfProperty(property_name) : -1;
+            QMetaProperty property = index >= 0 ? obj->metaObject()->property(index) : QMetaProperty();
+            if (!property.hasNotifySignal()) {
+                RustPropertyObserver::dropCallback(callback);
+                return holder;
+            }
+            holder.observer.reset(new RustPropertyObserver(obj, property, callback));
+            QMetaObject::connect(obj, property.notifySignalIndex(),
+                                 holder.observer.get(), RustPropertyObserver::slotIndex());
+            return holder;
+        });
+        if handle.is_null() {
+            None
+        } else {
+            Some(handle)
+        }
+    }
+}
+
+cpp_class!(
+    /// Handle returned by [`PropertyChangeNotifier::observe`][]. The callback is disconnected
+    /// and dropped when the handle is dropped.
+    ///
+    /// [`PropertyChangeNotifier::observe`]: ./enum.PropertyChangeNotifier.html#method.observe
+    pub unsafe struct PropertyObserverHandle as "PropertyObserverHolder"
+);
+
+impl PropertyObserverHandle {
+    fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const PropertyObserverHolder *"] -> bool as "bool" {
+            return !self->observer;
+        })
+    }
+}
+
+/// Connect the notify signal of the property `property_name` of `obj` to a slot without
+/// arguments.
+///
+/// The property and its notify signal are looked up at run time from the meta object of the
+/// object, so the type of the signal does not need to be known. Unlike
+/// [`PropertyChangeNotifier::observe`][], the value of the property is not read.
+///
+/// A warning is printed and an invalid handle is returned if the object has no such property,
+/// or if the property has no notify signal. Like [`connect_cpp_signal`][], the connection goes
+/// through a hidden receiver object which is destroyed together with the sender, or when the
+/// returned handle is disconnected.
+///
+/// # Safety
+///
+/// `obj` must be a valid pointer to a QObject.
+///
+/// [`PropertyChangeNotifier::observe`]: ./enum.PropertyChangeNotifier.html#method.observe
+/// [`connect_cpp_signal`]: ./fn.connect_cpp_signal.html
+pub unsafe fn subscribe_property_changes(
+    obj: *const c_void,
+    property_name: &str,
+    callback: impl Fn() + 'static,
+) -> CppSignalConnection {
+    let property_name = match CString::new(property_name) {
+        Ok(name) => name,
+        Err(_) => return CppSignalConnection::default(),
+    };
+    let property_name = property_name.as_ptr();
+    let slot_closure = move |_: *const *const c_void| callback();
+    let slot_closure_boxed: Box<dyn FnMut(*const *const c_void)> = Box::new(slot_closure);
+    let slot_closure_raw: *mut dyn FnMut(*const *const c_void) = Box::into_raw(slot_closure_boxed);
+
+    cpp!(unsafe [
+        obj as "QObject *",
+        property_name as "const char *",
+        slot_closure_raw as "TraitObject"
+    ] -> CppSignalConnection as "RustSignalConnection" {
+        auto receiver = new RustSignalReceiver(slot_closure_raw);
+        int index = obj ? obj->metaObject()->indexOfProperty(property_name) : -1;
+        QMetaProperty property = index >= 0 ? obj->metaObject()->property(index) : QMetaProperty();
+        if (!property.hasNotifySignal()) {
+            qWarning("subscribe_property_changes: No notify signal for property %s", property_name);
+            delete receiver;
+            return {};
+        }
+        receiver->moveToThread(obj->thread());
+        QObject::connect(obj, &QObject::destroyed, receiver, [receiver] { delete receiver; });
+        RustSignalConnection result;
+        result.connection = QMetaObject::connect(obj, property.notifySignalIndex(),
+                                                 receiver, RustSignalReceiver::slotIndex(),
+                                                 Qt::DirectConnection);
+        result.receiver = receiver;
+        return result;
+    })
+}
@@ -0,0 +1,120 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Watch the changes in the children of a QObject.
+//!
+//! Qt has no signal for a change in the list of children of an object, but sends a
+//! [`QChildEvent`][event] to the parent. [`watch_children`] turns these events into calls
+//! to a Rust closure.
+//!
+//! [event]: https://doc.qt.io/qt-5/qchildevent.html
+use cpp::{cpp, cpp_class};
+use std::os::raw::c_void;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QEvent>
+    #include <QtCore/QObject>
+
+    /// Event filter which forwards the QChildEvent of the watched object to a Rust closure.
+    struct RustChildWatcher : QObject {
+        /// Wrapped Box<dyn FnMut(ChildEventKind, *mut c_void)>
+        TraitObject callback;
+
+        RustChildWatcher(TraitObject callback) : callback(callback) {}
+
+        bool eventFilter(QObject *watched, QEvent *event) override {
+            int kind;
+            switch (event->type()) {
+                case QEvent::ChildAdded: kind = 0; break;
+                case QEvent::ChildRemoved: kind = 1; break;
+                case QEvent::ChildPolished: kind = 2; break;
+                default: return QObject::eventFilter(watched, event);
+            }
+            QObject *child = static_cast<QChildEvent *>(event)->child();
+            auto callback = this->callback;
+            rust!(RustChildWatcher_eventFilter [
+                callback: *mut dyn FnMut(ChildEventKind, *mut c_void) as "TraitObject",
+                kind: ChildEventKind as "int",
+                child: *mut c_void as "QObject *"
+            ] {
+                (*callback)(kind, child)
+            });
+            return QObject::eventFilter(watched, event);
+        }
+
+        ~RustChildWatcher() {
+            auto callback = this->callback;
+            rust!(RustChildWatcher_destructor [
+                callback: *mut dyn FnMut(ChildEventKind, *mut c_void) as "TraitObject"
+            ] {
+                let _ = Box::from_raw(callback);
+            });
+        }
+    };
+}}
+
+/// The kind of a [`QChildEvent`][event] received by the closure given to [`watch_children`].
+///
+/// [event]: https://doc.qt.io/qt-5/qchildevent.html
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChildEventKind {
+    /// A child was added (`QEvent::ChildAdded`)
+    Added,
+    /// A child was removed (`QEvent::ChildRemoved`)
+    Removed,
+    /// A child was polished (`QEvent::ChildPolished`)
+    Polished,
+}
+
+cpp_class!(
+    /// Handle returned by [`watch_children`].
+    ///
+    /// The closure is no longer called once the handle is dropped.
+    pub unsafe struct ChildWatchHandle as "std::unique_ptr<RustChildWatcher>"
+);
+
+/// Calls `callback` every time a child is added to, removed from, or polished in `obj`.
+///
+/// The second argument of the callback is the pointer to the C++ QObject of the child.
+/// Note that when a child is removed because it is being destroyed, the child is no longer
+/// fully constructed and should not be cast to a derived type.
+///
+/// Like [`connections::connect`](../connections/fn.connect.html), this takes a pointer to the
+/// C++ object so it can be used with any QObject: use `QObject::get_cpp_object` for an object
+/// defined in Rust, or the `cpp_ptr` function of the wrappers.
+///
+/// # Safety
+///
+/// `obj` must be a valid pointer to a QObject.
+pub unsafe fn watch_children<F>(obj: *mut c_void, callback: F) -> ChildWatchHandle
+where
+    F: FnMut(ChildEventKind, *mut c_void) + 'static,
+{
+    assert!(!obj.is_null(), "watch_children called on a null object");
+    let callback: Box<dyn FnMut(ChildEventKind, *mut c_void)> = Box::new(callback);
+    let callback = Box::into_raw(callback);
+    cpp!([
+        obj as "QObject *",
+        callback as "TraitObject"
+    ] -> ChildWatchHandle as "std::unique_ptr<RustChildWatcher>" {
+        auto watcher = std::unique_ptr<RustChildWatcher>(new RustChildWatcher(callback));
+        obj->installEventFilter(watcher.get());
+        return watcher;
+    })
+}
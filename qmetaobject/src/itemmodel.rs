@@ -16,8 +16,9 @@ OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 use std::collections::HashMap;
+use std::ops::{BitOr, BitOrAssign};
 
-use cpp::cpp;
+use cpp::{cpp, cpp_class};
 
 use crate::*;
 
@@ -60,6 +61,14 @@ pub trait QAbstractItemModel: QObject {
         HashMap::new()
     }
 
+    /// Refer to the Qt documentation of QAbstractItemModel::submit
+    fn submit(&mut self) -> bool {
+        true
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemModel::revert
+    fn revert(&mut self) {}
+
     /// Refer to the Qt documentation of QAbstractListModel::beginInsertRows
     fn begin_insert_rows(&self, parent: QModelIndex, first: i32, last: i32) {
         let obj = self.get_cpp_object();
@@ -274,5 +283,198 @@ cpp! {{
             });
             return base;
         }
+
+        bool submit() override {
+            return rust!(Rust_QAbstractItemModel_submit [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject"
+            ] -> bool as "bool" {
+                rust_object.borrow_mut().submit()
+            });
+        }
+
+        void revert() override {
+            rust!(Rust_QAbstractItemModel_revert [
+                rust_object: QObjectPinned<dyn QAbstractItemModel> as "TraitObject"
+            ] {
+                rust_object.borrow_mut().revert()
+            });
+        }
     };
 }}
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QMimeData>
+
+    struct QMimeDataHolder {
+        std::unique_ptr<QMimeData> data;
+
+        QMimeDataHolder() : data(new QMimeData) {}
+
+        /// Copies all the formats of `other`
+        explicit QMimeDataHolder(const QMimeData *other) : data(new QMimeData) {
+            const auto formats = other->formats();
+            for (const auto &format : formats)
+                data->setData(format, other->data(format));
+        }
+    };
+}}
+
+cpp_class!(
+    /// Wrapper for [`QMimeData`][class], the data of a drag and drop or of the clipboard,
+    /// in several formats.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qmimedata.html
+    pub unsafe struct QMimeData as "QMimeDataHolder"
+);
+
+impl QMimeData {
+    /// Creates a new empty QMimeData
+    pub fn new() -> QMimeData {
+        cpp!(unsafe [] -> QMimeData as "QMimeDataHolder" {
+            return QMimeDataHolder();
+        })
+    }
+
+    /// Returns a pointer to the C++ object. The pointer is of the type `QMimeData *` in C++.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QMimeDataHolder *"] -> *mut c_void as "QMimeData *" {
+            return self->data.get();
+        })
+    }
+
+    /// Gives up the ownership of the C++ object and returns a pointer to it.
+    pub fn into_raw(mut self) -> *mut c_void {
+        let this = &mut self;
+        cpp!(unsafe [this as "QMimeDataHolder *"] -> *mut c_void as "QMimeData *" {
+            return this->data.release();
+        })
+    }
+
+    /// Refer to the Qt documentation of QMimeData::formats
+    pub fn formats(&self) -> Vec<QString> {
+        let count = cpp!(unsafe [self as "QMimeDataHolder *"] -> usize as "size_t" {
+            return self->data->formats().size();
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [self as "QMimeDataHolder *", i as "size_t"] -> QString as "QString" {
+                    return self->data->formats().at(i);
+                })
+            })
+            .collect()
+    }
+
+    /// Refer to the Qt documentation of QMimeData::hasFormat
+    pub fn has_format(&self, mime_type: &str) -> bool {
+        let mime_type = QString::from(mime_type);
+        cpp!(unsafe [self as "QMimeDataHolder *", mime_type as "QString"] -> bool as "bool" {
+            return self->data->hasFormat(mime_type);
+        })
+    }
+
+    /// Refer to the Qt documentation of QMimeData::data
+    pub fn data(&self, mime_type: &str) -> QByteArray {
+        let mime_type = QString::from(mime_type);
+        cpp!(unsafe [self as "QMimeDataHolder *", mime_type as "QString"] -> QByteArray as "QByteArray" {
+            return self->data->data(mime_type);
+        })
+    }
+
+    /// Refer to the Qt documentation of QMimeData::setData
+    pub fn set_data(&mut self, mime_type: &str, data: QByteArray) {
+        let mime_type = QString::from(mime_type);
+        cpp!(unsafe [self as "QMimeDataHolder *", mime_type as "QString", data as "QByteArray"] {
+            self->data->setData(mime_type, data);
+        })
+    }
+
+    /// Refer to the Qt documentation of QMimeData::text
+    pub fn text(&self) -> QString {
+        cpp!(unsafe [self as "QMimeDataHolder *"] -> QString as "QString" {
+            return self->data->text();
+        })
+    }
+
+    /// Refer to the Qt documentation of QMimeData::setText
+    pub fn set_text(&mut self, text: QString) {
+        cpp!(unsafe [self as "QMimeDataHolder *", text as "QString"] {
+            self->data->setText(text);
+        })
+    }
+}
+
+impl Default for QMimeData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wrapper around [`QAbstractItemModel::LayoutChangeHint`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qabstractitemmodel.html#LayoutChangeHint-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutChangeHint {
+    NoLayoutChangeHint = 0,
+    VerticalSortHint = 1,
+    HorizontalSortHint = 2,
+}
+
+impl Default for LayoutChangeHint {
+    fn default() -> Self {
+        LayoutChangeHint::NoLayoutChangeHint
+    }
+}
+
+/// Wrapper around [`Qt::DropAction`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#DropAction-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropAction {
+    IgnoreAction = 0x0,
+    CopyAction = 0x1,
+    MoveAction = 0x2,
+    LinkAction = 0x4,
+    TargetMoveAction = 0x8002,
+}
+
+/// Wrapper around [`Qt::DropActions`][enum] flags.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#DropAction-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct DropActions(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl DropActions {
+    pub const IgnoreAction: DropActions = DropActions(0x0);
+    pub const CopyAction: DropActions = DropActions(0x1);
+    pub const MoveAction: DropActions = DropActions(0x2);
+    pub const LinkAction: DropActions = DropActions(0x4);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: DropActions) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for DropActions {
+    type Output = DropActions;
+    fn bitor(self, other: DropActions) -> DropActions {
+        DropActions(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for DropActions {
+    fn bitor_assign(&mut self, other: DropActions) {
+        self.0 |= other.0;
+    }
+}
+
+impl From<DropAction> for DropActions {
+    fn from(action: DropAction) -> DropActions {
+        DropActions(action as u32)
+    }
+}
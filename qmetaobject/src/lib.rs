@@ -105,6 +105,38 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
     If you want to keep pointer to reference, you can use [QPointer](struct.QPointer.html).
 
+    # Enums of objects
+
+    `#[derive(QObject)]` can also be used on an enum whose variants each hold a different
+    object in a `Box<RefCell<T>>`. The C++ object of the enum is the one of its current variant,
+    so it is seen by Qt and QML as that object. Its `meta_object()` is the one of a plain QObject
+    though, so [downcast_ref](fn.downcast_ref.html) never casts the enum to a variant type. Such
+    an enum cannot be registered to QML with [qml_register_type](fn.qml_register_type.html),
+    since the type to create would not be known.
+
+    ```
+    use qmetaobject::prelude::*;
+    use std::cell::RefCell;
+
+    #[derive(QObject, Default)]
+    struct Circle {
+        base: qt_base_class!(trait QObject),
+        radius: qt_property!(f64),
+    }
+
+    #[derive(QObject, Default)]
+    struct Square {
+        base: qt_base_class!(trait QObject),
+        side: qt_property!(f64),
+    }
+
+    #[derive(QObject)]
+    enum Shape {
+        Circle(Box<RefCell<Circle>>),
+        Square(Box<RefCell<Square>>),
+    }
+    ```
+
     # Threading
 
     The QML engine only runs in a single thread. And probably all the `QObject`s needs to be living
@@ -158,10 +190,10 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 pub use qmetaobject_impl::{qrc_internal, SimpleListItem};
 
 #[doc(hidden)]
-#[cfg(not(qt_6_0))]
+#[cfg(qt5)]
 pub use qmetaobject_impl::{QEnum, QGadget, QObject};
 #[doc(hidden)]
-#[cfg(qt_6_0)]
+#[cfg(qt6)]
 pub use qmetaobject_impl::{QEnum6 as QEnum, QGadget6 as QGadget, QObject6 as QObject};
 
 // In order to be able to use the lazy_static macro from the QObject custom derive, we re-export
@@ -173,7 +205,7 @@ pub use lazy_static::lazy_static;
 #[macro_export]
 macro_rules! qmetaobject_lazy_static { ($($t:tt)*) => { $crate::lazy_static!($($t)*) } }
 
-use std::cell::{RefCell, RefMut};
+use std::cell::{Cell, RefCell, RefMut};
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_void};
 
@@ -182,34 +214,71 @@ use cpp::{cpp, cpp_class};
 pub use qttypes;
 
 pub use crate::log::*;
+pub use animation::*;
 pub use connections::RustSignal;
-pub use connections::{connect, Signal, SignalInner};
+pub use connections::{
+    connect, connect_cpp_signal, connect_once, subscribe_property_changes, CppSignal,
+    CppSignalConnection, PropertyChangeNotifier, PropertyObserverHandle, Signal, SignalInner,
+    SignalMultiplexer, SlotId,
+};
 pub use future::*;
 pub use itemmodel::*;
 pub use listmodel::*;
+pub use painter::*;
+#[cfg(qt_5_12)]
+pub use qcbor::*;
+pub use qeventloop::*;
+pub use qiodevice::*;
+pub use qitemselectionmodel::*;
+pub use qlibrary::*;
 pub use qmetatype::*;
+pub use qstandarditemmodel::*;
 pub use qtdeclarative::*;
+pub use qtextstream::*;
 #[cfg(qt_5_7)]
 pub use qtquickcontrols2::*;
 pub use qttypes::*;
+#[cfg(feature = "object_registry")]
+pub use registry::ObjectRegistry;
 pub use tablemodel::*;
+pub use widgets::*;
 
+pub mod animation;
+pub mod app;
 pub mod connections;
 pub mod future;
+pub mod hierarchy;
 pub mod itemmodel;
 pub mod listmodel;
 pub mod log;
+#[cfg(feature = "network")]
+pub mod network;
+pub mod painter;
+#[cfg(qt_5_12)]
+pub mod qcbor;
+pub mod qeventloop;
+pub mod qformat;
+pub mod qiodevice;
+pub mod qitemselectionmodel;
+pub mod qlibrary;
 pub mod qmetatype;
 pub mod qrc;
+pub mod qstandarditemmodel;
 pub mod qtdeclarative;
+pub mod qtextstream;
 #[cfg(qt_5_7)]
 pub mod qtquickcontrols2;
+#[cfg(feature = "object_registry")]
+pub mod registry;
 pub mod scenegraph;
+#[cfg(feature = "svg")]
+pub mod svg;
 pub mod tablemodel;
 #[cfg(feature = "webengine")]
 #[cfg(not(any(qt_6_0, qt_6_1)))]
 #[cfg(not(all(target_os = "windows", not(target_env = "msvc"))))]
 pub mod webengine;
+pub mod widgets;
 
 /// Module intended for glob import.
 pub mod prelude {
@@ -279,6 +348,8 @@ pub struct QObjectDescriptor {
         extra_destruct: extern "C" fn(*mut c_void),
     ),
     pub get_rust_refcell: unsafe extern "C" fn(*mut c_void) -> *const RefCell<dyn QObject>,
+    pub parser_status_cast: i32,
+    pub value_source_cast: i32,
 }
 
 /// Trait that is implemented by the QObject custom derive macro
@@ -322,6 +393,18 @@ pub trait QObject {
     where
         Self: Sized;
 
+    /// Return the offset of the QQmlParserStatus interface in the C++ object, or -1
+    #[doc(hidden)]
+    fn parser_status_cast() -> i32
+    where
+        Self: Sized;
+
+    /// Return the offset of the QQmlPropertyValueSource interface in the C++ object, or -1
+    #[doc(hidden)]
+    fn value_source_cast() -> i32
+    where
+        Self: Sized;
+
     /// Return a rust object belonging to a C++ object
     unsafe fn get_from_cpp<'a>(p: *mut c_void) -> QObjectPinned<'a, Self>
     where
@@ -385,6 +468,57 @@ impl dyn QObject {
             }))
         }
     }
+
+    /// See Qt documentation for QObject::destroyed
+    ///
+    /// Same as `destroyed_signal`, but the argument is the pointer to the C++ QObject being
+    /// destroyed.
+    pub fn destroyed_with_object_signal() -> Signal<fn(*mut c_void)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner"  {
+                return &QObject::destroyed;
+            }))
+        }
+    }
+
+    /// Blocks the signals of this object until the returned guard is dropped, which restores
+    /// the previous state. Same as QSignalBlocker.
+    ///
+    /// Does nothing if the C++ object was not yet created.
+    pub fn block_signals(&self) -> SignalBlocker<'_> {
+        let self_ = self.get_cpp_object();
+        let previous = cpp!(unsafe [self_ as "QObject*"] -> bool as "bool" {
+            return self_ ? self_->blockSignals(true) : false;
+        });
+        SignalBlocker { object: self_, previous, _phantom: Default::default() }
+    }
+
+    /// See Qt documentation for QObject::signalsBlocked
+    pub fn signals_blocked(&self) -> bool {
+        let self_ = self.get_cpp_object();
+        cpp!(unsafe [self_ as "QObject*"] -> bool as "bool" {
+            return self_ && self_->signalsBlocked();
+        })
+    }
+}
+
+/// Guard returned by [`block_signals`](trait.QObject.html#method.block_signals), which
+/// restores the previous blocked state of the object when dropped.
+#[must_use = "the signals are unblocked when the SignalBlocker is dropped"]
+pub struct SignalBlocker<'a> {
+    object: *mut c_void,
+    previous: bool,
+    _phantom: std::marker::PhantomData<&'a dyn QObject>,
+}
+
+impl<'a> Drop for SignalBlocker<'a> {
+    fn drop(&mut self) {
+        let object = self.object;
+        let previous = self.previous;
+        cpp!(unsafe [object as "QObject*", previous as "bool"] {
+            if (object) object->blockSignals(previous);
+        })
+    }
 }
 
 cpp_class!(unsafe struct QPointerImpl as "QPointer<QObject>");
@@ -516,6 +650,17 @@ impl<'pin, T: QObject + ?Sized + 'pin> QObjectPinned<'pin, T> {
     pub fn as_ptr(&self) -> *mut T {
         self.0.as_ptr()
     }
+    /// Get the pointer to the C++ Object, or None if it was not yet created.
+    ///
+    /// Unlike `get_or_create_cpp_object`, this never creates the C++ object.
+    pub fn get_cpp_object(&self) -> Option<*mut c_void> {
+        let r = self.borrow().get_cpp_object();
+        if r.is_null() {
+            None
+        } else {
+            Some(r)
+        }
+    }
 }
 
 impl<'pin, T: QObject + ?Sized + 'pin> QObjectPinned<'pin, T> {
@@ -568,6 +713,56 @@ impl<T: QObject + ?Sized> QObjectBox<T> {
     }
 }
 
+/// A QObject which is only constructed when it is first needed.
+///
+/// A [`QObjectBox`](struct.QObjectBox.html) allocates the Rust object right away, and its C++
+/// object is created the first time it is used from Qt. A `LazyQObject` additionally defers the
+/// construction and allocation of the Rust object until the first call to
+/// [`as_pinned`](#method.as_pinned), for example when it is set as a context property of a
+/// `QmlEngine`. This saves memory for objects which might never be used.
+///
+/// Once initialized, the object is boxed and does not move in memory until the `LazyQObject`
+/// is dropped.
+pub struct LazyQObject<T: QObject> {
+    init: Cell<Option<Box<dyn FnOnce() -> T>>>,
+    object: RefCell<Option<Box<RefCell<T>>>>,
+}
+
+impl<T: QObject> LazyQObject<T> {
+    /// Creates a lazy object which will be constructed by calling `init`.
+    pub fn new(init: impl FnOnce() -> T + 'static) -> Self {
+        LazyQObject { init: Cell::new(Some(Box::new(init))), object: RefCell::new(None) }
+    }
+
+    /// Returns true if the Rust object was constructed.
+    pub fn is_initialized(&self) -> bool {
+        self.object.borrow().is_some()
+    }
+
+    /// Constructs the object if needed, and returns a pinned reference to it.
+    ///
+    /// The C++ object is created the first time the pinned reference is used from Qt, or by
+    /// calling `get_or_create_cpp_object` on it.
+    ///
+    /// Panics if called again from within the initialization function.
+    pub fn as_pinned(&self) -> QObjectPinned<'_, T> {
+        if !self.is_initialized() {
+            let init = self.init.take().expect("Recursive initialization of a LazyQObject");
+            let object = Box::new(RefCell::new(init()));
+            *self.object.borrow_mut() = Some(object);
+        }
+        let ptr: *const RefCell<T> = &**self.object.borrow().as_ref().unwrap();
+        // The box is never replaced or dropped before self, so it does not move in memory.
+        unsafe { QObjectPinned::new(&*ptr) }
+    }
+}
+
+impl<T: QObject + Default> Default for LazyQObject<T> {
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
 /// Create the C++ object and return a C++ pointer to a QObject.
 ///
 /// The ownership is given to CPP, the resulting QObject* ptr need to be used somewhere
@@ -584,6 +779,24 @@ pub fn into_leaked_cpp_ptr<T: QObject>(obj: T) -> *mut c_void {
 /// Trait that is implemented by the QGadget custom derive macro
 ///
 /// Do not implement this trait yourself, use `#[derive(QGadget)]`.
+///
+/// The derive macro also accepts tuple structs such as `struct Meters(f64);`. The first field
+/// is then exposed as a property called `value`, and its type must implement `PartialEq`,
+/// `Clone` and `Default`.
+///
+/// Fields that are not declared with `qt_property!` or `qt_method!` must have a type that
+/// implements [`QMetaType`]. Fields that are only used from Rust, such as a cache, can be
+/// excluded with the `#[qt_skip]` attribute:
+///
+/// ```
+/// # use qmetaobject::*;
+/// #[derive(QGadget, Clone, Default)]
+/// struct Gadget {
+///     name: qt_property!(QString),
+///     #[qt_skip]
+///     cache: std::collections::HashMap<String, u32>,
+/// }
+/// ```
 pub trait QGadget {
     /// Returns a pointer to a meta object
     fn meta_object(&self) -> *const QMetaObject;
@@ -597,6 +810,28 @@ pub trait QGadget {
 /// Trait that is implemented by the QEnum custom derive macro
 ///
 /// Do not implement this trait yourself, use `#[derive(QEnum)]`.
+///
+/// Like `Q_ENUM` in a C++ class, the `#[qt_enums(...)]` attribute on a struct deriving from
+/// QObject or QGadget lists enums that belong to that class. QML can then access their values
+/// through the class name, without registering the enums separately with `qml_register_enum`.
+///
+/// ```
+/// use qmetaobject::*;
+///
+/// #[derive(QEnum)]
+/// #[repr(u32)]
+/// enum Shape {
+///     Circle,
+///     Square,
+/// }
+///
+/// #[derive(QObject, Default)]
+/// #[qt_enums(Shape)]
+/// struct Canvas {
+///     base: qt_base_class!(trait QObject),
+///     shape: qt_property!(i32), // `Canvas.Square` in QML
+/// }
+/// ```
 pub trait QEnum {
     /// Returns a pointer to a meta object
     fn static_meta_object() -> *const QMetaObject
@@ -651,7 +886,8 @@ type StaticMetacallFunction = Option<
 >;
 
 /// Same as a C++ QMetaObject.
-#[doc(hidden)]
+///
+/// The meta object of a QObject can be obtained with [`get_meta_object`](fn.get_meta_object.html).
 #[repr(C)]
 pub struct QMetaObject {
     // fields are slightly renamed from Qt to match Rust code style
@@ -669,6 +905,140 @@ pub struct QMetaObject {
 unsafe impl Sync for QMetaObject {}
 unsafe impl Send for QMetaObject {}
 
+impl QMetaObject {
+    /// Returns the meta object of the type `T`.
+    pub fn of<T: QObject>() -> &'static QMetaObject {
+        unsafe { &*T::static_meta_object() }
+    }
+
+    /// Refer to the Qt documentation of QMetaObject::className
+    pub fn class_name(&self) -> &'static str {
+        let name = cpp!(unsafe [self as "const QMetaObject *"] -> *const c_char as "const char *" {
+            return self->className();
+        });
+        unsafe { CStr::from_ptr(name) }.to_str().unwrap_or_default()
+    }
+
+    /// Refer to the Qt documentation of QMetaObject::superClass
+    pub fn super_class(&self) -> Option<&'static QMetaObject> {
+        let sup = cpp!(unsafe [self as "const QMetaObject *"] -> *const QMetaObject as "const QMetaObject *" {
+            return self->superClass();
+        });
+        unsafe { sup.as_ref() }
+    }
+
+    /// Returns true if the class described by this meta object is the class described by
+    /// `other`, or inherits from it.
+    pub fn inherits(&self, other: &QMetaObject) -> bool {
+        cpp!(unsafe [self as "const QMetaObject *", other as "const QMetaObject *"] -> bool as "bool" {
+            for (auto m = self; m; m = m->superClass()) {
+                if (m == other)
+                    return true;
+            }
+            return false;
+        })
+    }
+
+    /// Refer to the Qt documentation of QMetaObject::propertyCount
+    ///
+    /// This includes the properties of the base classes.
+    pub fn property_count(&self) -> i32 {
+        cpp!(unsafe [self as "const QMetaObject *"] -> i32 as "int" {
+            return self->propertyCount();
+        })
+    }
+
+    /// Refer to the Qt documentation of QMetaObject::methodCount
+    ///
+    /// This includes the methods and signals of the base classes.
+    pub fn method_count(&self) -> i32 {
+        cpp!(unsafe [self as "const QMetaObject *"] -> i32 as "int" {
+            return self->methodCount();
+        })
+    }
+}
+
+/// Builds the null terminated array of related meta objects used by the custom derive for the
+/// `#[qt_enums(...)]` attribute. It is built once per type and never freed.
+#[doc(hidden)]
+pub fn related_meta_objects(related: &[*const QMetaObject]) -> *const c_void {
+    // Same as QMetaObject::SuperData
+    #[repr(C)]
+    struct SuperData {
+        direct: *const QMetaObject,
+        #[cfg(all(qt_6_0, target_os = "windows"))] // QT_NO_DATA_RELOCATION
+        indirect: Option<extern "C" fn() -> *const QMetaObject>,
+    }
+    let array: Vec<SuperData> = related
+        .iter()
+        .copied()
+        .chain(std::iter::once(std::ptr::null()))
+        .map(|direct| SuperData {
+            direct,
+            #[cfg(all(qt_6_0, target_os = "windows"))]
+            indirect: None,
+        })
+        .collect();
+    Box::leak(array.into_boxed_slice()).as_ptr() as *const c_void
+}
+
+/// Returns the meta object of a QObject.
+pub fn get_meta_object(obj: &dyn QObject) -> &'static QMetaObject {
+    unsafe { &*obj.meta_object() }
+}
+
+/// Returns the value of the property `name` of a C++ QObject, such as an item created by QML.
+///
+/// The property can be declared or dynamic. An invalid QVariant is returned if there is no
+/// such property. Refer to the Qt documentation of QObject::property
+///
+/// # Safety
+///
+/// `obj` must be a valid pointer to a QObject.
+pub unsafe fn qobject_property(obj: *const c_void, name: &str) -> QVariant {
+    let name = CString::new(name).unwrap();
+    let name = name.as_ptr();
+    cpp!([obj as "const QObject *", name as "const char *"] -> QVariant as "QVariant" {
+        return obj->property(name);
+    })
+}
+
+/// Sets the property `name` of a C++ QObject. Returns true if the object has a property of that
+/// name which could be written, and false if a dynamic property was set instead (or removed, if
+/// `value` is invalid). Refer to the Qt documentation of QObject::setProperty
+///
+/// # Safety
+///
+/// `obj` must be a valid pointer to a QObject.
+pub unsafe fn qobject_set_property(obj: *mut c_void, name: &str, value: QVariant) -> bool {
+    let name = CString::new(name).unwrap();
+    let name = name.as_ptr();
+    cpp!([obj as "QObject *", name as "const char *", value as "QVariant"] -> bool as "bool" {
+        return obj->setProperty(name, value);
+    })
+}
+
+/// Returns a reference to the object as the concrete type `T`, if it is a `T`.
+///
+/// The check compares the meta object of `obj` with the one of `T`. Since a type deriving
+/// from QObject in Rust cannot itself be derived from, the type matches exactly.
+pub fn downcast_ref<T: QObject>(obj: &dyn QObject) -> Option<&T> {
+    if obj.meta_object() == T::static_meta_object() {
+        Some(unsafe { &*(obj as *const dyn QObject as *const T) })
+    } else {
+        None
+    }
+}
+
+/// Same as [`downcast_ref`](fn.downcast_ref.html), for a mutable reference.
+pub fn downcast_mut<T: QObject>(obj: &mut dyn QObject) -> Option<&mut T> {
+    if obj.meta_object() == T::static_meta_object() {
+        Some(unsafe { &mut *(obj as *mut dyn QObject as *mut T) })
+    } else {
+        None
+    }
+}
+
 /// This macro must be used once as a type in a struct that derives from QObject.
 /// It is anotate from which QObject like trait it is supposed to derive.
 /// the field which it annotate will be an internal property holding a pointer
@@ -705,6 +1075,9 @@ macro_rules! qt_base_class {
 /// these are not mandatory and if no setter or no getter exist, it will set the field.
 /// `CONST` is also supported.
 ///
+/// `REQUIRED` marks the property as required (Qt 5.15): QML reports an error when an object of
+/// this type is created without setting this property.
+///
 /// `ALIAS` followed by an identifier allow to give a different name than the actual field name.
 ///
 /// ```
@@ -721,6 +1094,38 @@ macro_rules! qt_base_class {
 ///    fn set_foo(&mut self, val: u32) { self.foo = val; }
 /// }
 /// ```
+///
+/// `READ` and `WRITE` can also be followed by a field of the struct (`READ self.count`), or a
+/// path to a field of a nested struct (`READ self.settings.volume`), in which case that field
+/// is read or assigned directly instead of calling a method. Assigning through `WRITE` then
+/// emits the `NOTIFY` signal, as when no setter is given. If `READ` names a field and there is
+/// no `WRITE`, the property is written to that same field.
+///
+/// ```
+/// use qmetaobject::*;
+///
+/// #[derive(Default)]
+/// struct Settings {
+///     volume: u32,
+/// }
+///
+/// #[derive(QObject)]
+/// struct Player {
+///    base: qt_base_class!(trait QObject),
+///    settings: Settings,
+///    volume: qt_property!(u32; READ self.settings.volume WRITE self.settings.volume NOTIFY volume_changed),
+///    volume_changed: qt_signal!(),
+/// }
+/// ```
+///
+/// A property of type `Arc<Mutex<T>>` is exposed to QML as a property of type `T`, so its state
+/// can be shared with background threads. Reading it locks the mutex and clones the value, and
+/// writing it locks the mutex and assigns the value. Do not emit the `NOTIFY` signal while holding
+/// the lock, since bindings reading the property would then deadlock.
+///
+/// A property of type `RefCell<T>` or `Rc<RefCell<T>>`, where `T` is a QObject, is exposed to QML
+/// as a pointer to that object. Such properties should be `CONST`. With a `Rc`, the same object can
+/// also be shared with other Rust code, or between several objects.
 #[macro_export]
 macro_rules! qt_property {
     ($t:ty $(; $($rest:tt)*)*) => {
@@ -728,6 +1133,38 @@ macro_rules! qt_property {
     };
 }
 
+/// Declares a list property holding objects of type `T`, seen by QML as a `list<QtObject>`.
+///
+/// The field is a [`QQmlListProperty<T>`](struct.QQmlListProperty.html). Its content can
+/// be declared in QML, and accessed from Rust with the methods of `QQmlListProperty`.
+///
+/// To be used within a struct that derives from QObject
+///
+/// ```
+/// use qmetaobject::*;
+///
+/// #[derive(QObject, Default)]
+/// struct Entry {
+///     base: qt_base_class!(trait QObject),
+/// }
+///
+/// #[derive(QObject, Default)]
+/// struct Container {
+///     base: qt_base_class!(trait QObject),
+///     entries: qt_list_property!(Entry),
+/// }
+///
+/// fn count(c: &Container) -> usize {
+///     c.entries.len()
+/// }
+/// ```
+#[macro_export]
+macro_rules! qt_list_property {
+    ($t:ty) => {
+        $crate::QQmlListProperty<$t>
+    };
+}
+
 /// This macro can be used to declare a method which will become a meta method.
 ///
 /// Inside you can either declare the method signature, or write the full method.
@@ -774,10 +1211,16 @@ macro_rules! qt_method {
 ///    my_signal: qt_signal!(xx: u32, yy: String),
 /// }
 ///
-/// fn some_code(foo: &mut Foo) {
+/// fn some_code(foo: &Foo) {
 ///    foo.my_signal(42, "42".into()); // emits the signal
 /// }
 /// ```
+///
+/// The generated method takes `&self`, whatever the types of the arguments. Emitting a signal
+/// does not modify the Rust object: the arguments are passed by pointer to Qt, which calls the
+/// connected slots. So a signal can be emitted while other shared borrows of the object exist,
+/// for example through `RefCell::borrow`. Slots that need to modify the object must borrow it
+/// themselves.
 #[macro_export]
 macro_rules! qt_signal {
     ($( $name:ident : $ty:ty ),*) => { $crate::RustSignal<fn( $( $ty ),* )> };
@@ -1094,3 +1537,21 @@ pub const USER_ROLE: i32 = 0x0100;
 /// [`$CARGO_MANIFEST_DIR`]: https://doc.rust-lang.org/cargo/reference/environment-variables.html#environment-variables-cargo-sets-for-build-scripts
 pub use qmetaobject_impl::qrc_internal as qrc;
 // XXX: The line above re-exports the macro with proper documentation and doctests.
+
+/// Creates a [`QString`] using the same syntax as `format!`.
+///
+/// The text is appended to the QString as it is formatted, without an intermediate `String`.
+/// The arguments of plain `{}` placeholders that are a [`QString`] or a [`QByteArray`] are
+/// appended directly by Qt, so they are not converted to UTF-8 and back. Other arguments, and
+/// placeholders with a format spec such as `{:?}` or `{:>5}`, go through `std::fmt`.
+///
+/// ```
+/// use qmetaobject::{qformat, QByteArray, QString};
+///
+/// let name = QString::from("world");
+/// let greeting = QByteArray::from("Hello");
+/// assert_eq!(qformat!("{} {}! {:03}", greeting, name, 7), QString::from("Hello world! 007"));
+/// let count = 3;
+/// assert_eq!(qformat!("{count} items"), QString::from("3 items"));
+/// ```
+pub use qmetaobject_impl::qformat_internal as qformat;
@@ -16,9 +16,12 @@ OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
 use std::iter::FromIterator;
-use std::ops::Index;
+use std::ops::{Deref, DerefMut, Index, Range};
+use std::pin::Pin;
+use std::rc::Rc;
 
 use cpp::cpp;
 
@@ -47,9 +50,58 @@ pub trait QAbstractListModel: QObject {
         false
     }
     /// Refer to the Qt documentation of QAbstractListModel::roleNames
+    ///
+    /// Maps the role numbers passed to `data` and `set_data` to the names used in QML.
+    /// The roles do not need to be contiguous. Custom roles should start at `USER_ROLE`.
+    /// Models of `SimpleListItem` use [`SimpleListItem::role_names`].
     fn role_names(&self) -> HashMap<i32, QByteArray> {
         HashMap::new()
     }
+    /// Refer to the Qt documentation of QAbstractItemModel::submit
+    fn submit(&mut self) -> bool {
+        true
+    }
+    /// Refer to the Qt documentation of QAbstractItemModel::revert
+    fn revert(&mut self) {}
+    /// Refer to the Qt documentation of QAbstractItemModel::canFetchMore
+    fn can_fetch_more(&self) -> bool {
+        false
+    }
+    /// Refer to the Qt documentation of QAbstractItemModel::fetchMore
+    fn fetch_more(&mut self) {}
+    /// Refer to the Qt documentation of QAbstractItemModel::mimeTypes
+    ///
+    /// If this returns an empty list, the default implementation of Qt is used.
+    fn mime_types(&self) -> Vec<QString> {
+        Vec::new()
+    }
+    /// Refer to the Qt documentation of QAbstractItemModel::mimeData
+    ///
+    /// If this returns None, the default implementation of Qt is used.
+    fn mime_data(&self, _indexes: &[QModelIndex]) -> Option<QMimeData> {
+        None
+    }
+    /// Refer to the Qt documentation of QAbstractItemModel::dropMimeData
+    ///
+    /// If this returns None, the default implementation of Qt is used.
+    fn drop_mime_data(
+        &mut self,
+        _data: &QMimeData,
+        _action: DropAction,
+        _row: i32,
+        _column: i32,
+        _parent: QModelIndex,
+    ) -> Option<bool> {
+        None
+    }
+    /// Refer to the Qt documentation of QAbstractItemModel::supportedDragActions
+    fn supported_drag_actions(&self) -> DropActions {
+        self.supported_drop_actions()
+    }
+    /// Refer to the Qt documentation of QAbstractItemModel::supportedDropActions
+    fn supported_drop_actions(&self) -> DropActions {
+        DropActions::CopyAction
+    }
 
     /// Refer to the Qt documentation of QAbstractListModel::beginInsertRows
     fn begin_insert_rows(&mut self, first: i32, last: i32) {
@@ -89,6 +141,28 @@ pub trait QAbstractListModel: QObject {
             })
         }
     }
+    /// Refer to the Qt documentation of QAbstractListModel::beginMoveRows
+    ///
+    /// Returns false if Qt considers the move invalid, in which case `end_move_rows` must not be
+    /// called and the data must not be moved.
+    fn begin_move_rows(&mut self, first: i32, last: i32, destination: i32) -> bool {
+        let p = QModelIndex::default();
+        let obj = self.get_cpp_object();
+        unsafe {
+            cpp!([obj as "Rust_QAbstractListModel*", p as "QModelIndex", first as "int", last as "int", destination as "int"] -> bool as "bool" {
+                return !obj || obj->beginMoveRows(p, first, last, p, destination);
+            })
+        }
+    }
+    /// Refer to the Qt documentation of QAbstractListModel::endMoveRows
+    fn end_move_rows(&mut self) {
+        let obj = self.get_cpp_object();
+        unsafe {
+            cpp!([obj as "Rust_QAbstractListModel*"]{
+                if(obj) obj->endMoveRows();
+            })
+        }
+    }
     /// Refer to the Qt documentation of QAbstractListModel::beginResetModel
     fn begin_reset_model(&mut self) {
         let obj = self.get_cpp_object();
@@ -108,6 +182,66 @@ pub trait QAbstractListModel: QObject {
         }
     }
 
+    /// Refer to the Qt documentation of QAbstractListModel::layoutAboutToBeChanged
+    ///
+    /// Call this before re-ordering the items, for example when sorting them, and call
+    /// `layout_changed` after. For a list model, `parents` is usually empty.
+    /// `update_model_indexes` needs to be called between `layout_about_to_be_changed` and
+    /// `layout_changed` so the views keep their persistent indexes.
+    fn layout_about_to_be_changed(&mut self, parents: &[QModelIndex], hint: LayoutChangeHint) {
+        let obj = self.get_cpp_object();
+        let parents_ptr = parents.as_ptr();
+        let parents_len = parents.len();
+        unsafe {
+            cpp!([obj as "Rust_QAbstractListModel*", parents_ptr as "const QModelIndex *",
+                    parents_len as "size_t", hint as "QAbstractItemModel::LayoutChangeHint"] {
+                if (!obj) return;
+                QList<QPersistentModelIndex> list;
+                for (size_t i = 0; i < parents_len; ++i)
+                    list.append(parents_ptr[i]);
+                obj->layoutAboutToBeChanged(list, hint);
+            })
+        }
+    }
+    /// Refer to the Qt documentation of QAbstractListModel::changePersistentIndexList
+    ///
+    /// Calls `f` with each persistent index, and replaces it with the returned index.
+    fn update_model_indexes(&mut self, f: &mut dyn FnMut(QModelIndex) -> QModelIndex) {
+        let obj = self.get_cpp_object();
+        unsafe {
+            cpp!([obj as "Rust_QAbstractListModel*", f as "TraitObject"] {
+                if (!obj) return;
+                const auto list1 = obj->persistentIndexList();
+                auto list2 = list1;
+                for (QModelIndex &idx : list2) {
+                    rust!(Rust_QAbstractListModel_update_model_indexes [
+                        f: &mut dyn FnMut(QModelIndex) -> QModelIndex as "TraitObject",
+                        idx: &mut QModelIndex as "QModelIndex &"
+                    ] {
+                        *idx = f(*idx);
+                    });
+                }
+                obj->changePersistentIndexList(list1, list2);
+            })
+        }
+    }
+    /// Refer to the Qt documentation of QAbstractListModel::layoutChanged
+    fn layout_changed(&mut self, parents: &[QModelIndex], hint: LayoutChangeHint) {
+        let obj = self.get_cpp_object();
+        let parents_ptr = parents.as_ptr();
+        let parents_len = parents.len();
+        unsafe {
+            cpp!([obj as "Rust_QAbstractListModel*", parents_ptr as "const QModelIndex *",
+                    parents_len as "size_t", hint as "QAbstractItemModel::LayoutChangeHint"] {
+                if (!obj) return;
+                QList<QPersistentModelIndex> list;
+                for (size_t i = 0; i < parents_len; ++i)
+                    list.append(parents_ptr[i]);
+                obj->layoutChanged(list, hint);
+            })
+        }
+    }
+
     /// Refer to the Qt documentation of QAbstractListModel::dataChanged
     fn data_changed(&mut self, top_left: QModelIndex, bottom_right: QModelIndex) {
         let obj = self.get_cpp_object();
@@ -132,6 +266,7 @@ pub trait QAbstractListModel: QObject {
 cpp! {{
     #include <qmetaobject_rust.hpp>
     #include <QtCore/QAbstractListModel>
+    #include <vector>
 
     struct Rust_QAbstractListModel : RustObject<QAbstractListModel> {
 
@@ -139,6 +274,8 @@ cpp! {{
         using QAbstractListModel::endInsertRows;
         using QAbstractListModel::beginRemoveRows;
         using QAbstractListModel::endRemoveRows;
+        using QAbstractListModel::beginMoveRows;
+        using QAbstractListModel::endMoveRows;
         using QAbstractListModel::beginResetModel;
         using QAbstractListModel::endResetModel;
 
@@ -181,21 +318,248 @@ cpp! {{
             return base;
         }
 
+        bool canFetchMore(const QModelIndex &parent) const override {
+            if (parent.isValid())
+                return false;
+            return rust!(Rust_QAbstractListModel_canFetchMore[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject"]
+                    -> bool as "bool" {
+                rust_object.borrow().can_fetch_more()
+            });
+        }
+
+        void fetchMore(const QModelIndex &parent) override {
+            if (parent.isValid())
+                return;
+            rust!(Rust_QAbstractListModel_fetchMore[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject"] {
+                rust_object.borrow_mut().fetch_more()
+            });
+        }
+
+        QStringList mimeTypes() const override {
+            QStringList types;
+            rust!(Rust_QAbstractListModel_mimeTypes[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    types: *mut c_void as "QStringList&"] {
+                for t in rust_object.borrow().mime_types() {
+                    add_to_string_list(types, t);
+                }
+            });
+            return types.isEmpty() ? QAbstractListModel::mimeTypes() : types;
+        }
+
+        QMimeData *mimeData(const QModelIndexList &indexes) const override {
+            std::vector<QModelIndex> list(indexes.begin(), indexes.end());
+            auto indexes_ptr = list.data();
+            auto indexes_len = list.size();
+            QMimeData *data = rust!(Rust_QAbstractListModel_mimeData[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    indexes_ptr: *const QModelIndex as "const QModelIndex *", indexes_len: usize as "size_t"]
+                    -> *mut c_void as "QMimeData *" {
+                let indexes = if indexes_len == 0 { &[] } else { std::slice::from_raw_parts(indexes_ptr, indexes_len) };
+                rust_object.borrow().mime_data(indexes).map_or(std::ptr::null_mut(), QMimeData::into_raw)
+            });
+            return data ? data : QAbstractListModel::mimeData(indexes);
+        }
+
+        bool dropMimeData(const QMimeData *data, Qt::DropAction action, int row, int column, const QModelIndex &parent) override {
+            QMimeDataHolder copy(data);
+            auto copy_ptr = &copy;
+            // -1 if the default implementation should be used
+            int result = rust!(Rust_QAbstractListModel_dropMimeData[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject",
+                    copy_ptr: &QMimeData as "QMimeDataHolder *", action: DropAction as "Qt::DropAction",
+                    row: i32 as "int", column: i32 as "int", parent: QModelIndex as "QModelIndex"] -> i32 as "int" {
+                rust_object.borrow_mut().drop_mime_data(copy_ptr, action, row, column, parent).map_or(-1, i32::from)
+            });
+            return result < 0 ? QAbstractListModel::dropMimeData(data, action, row, column, parent) : result;
+        }
+
+        Qt::DropActions supportedDragActions() const override {
+            return Qt::DropActions(rust!(Rust_QAbstractListModel_supportedDragActions[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject"]
+                    -> DropActions as "int" {
+                rust_object.borrow().supported_drag_actions()
+            }));
+        }
+
+        Qt::DropActions supportedDropActions() const override {
+            return Qt::DropActions(rust!(Rust_QAbstractListModel_supportedDropActions[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject"]
+                    -> DropActions as "int" {
+                rust_object.borrow().supported_drop_actions()
+            }));
+        }
+
+        bool submit() override {
+            return rust!(Rust_QAbstractListModel_submit[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject"]
+                    -> bool as "bool" {
+                rust_object.borrow_mut().submit()
+            });
+        }
+
+        void revert() override {
+            rust!(Rust_QAbstractListModel_revert[rust_object : QObjectPinned<dyn QAbstractListModel> as "TraitObject"] {
+                rust_object.borrow_mut().revert()
+            });
+        }
+
         //QModelIndex index(int row, int column, const QModelIndex &parent) const override;
 
         //QModelIndex parent(const QModelIndex &child) const override;
     };
 }}
 
+fn add_to_string_list(list: *mut c_void, value: QString) {
+    cpp!(unsafe [list as "QStringList *", value as "QString"] {
+        list->append(std::move(value));
+    });
+}
+
+/// Notifies the views that the data of the given rows changed, for the given roles (or all the
+/// roles if `roles` is empty).
+///
+/// This emits the `dataChanged` signal, with indexes built from the row numbers.
+/// Nothing is emitted if `rows` is empty.
+pub fn data_changed_range(model: &dyn QAbstractListModel, rows: Range<i32>, roles: &[i32]) {
+    if rows.start >= rows.end {
+        return;
+    }
+    let obj = model.get_cpp_object();
+    let first = rows.start;
+    let last = rows.end - 1;
+    let roles_ptr = roles.as_ptr();
+    let roles_len = roles.len();
+    unsafe {
+        cpp!([obj as "Rust_QAbstractListModel*", first as "int", last as "int",
+              roles_ptr as "const int*", roles_len as "size_t"] {
+            if (!obj)
+                return;
+            QVector<int> roles;
+            roles.reserve(roles_len);
+            for (size_t i = 0; i < roles_len; ++i)
+                roles.append(roles_ptr[i]);
+            Q_EMIT obj->dataChanged(obj->index(first), obj->index(last), roles);
+        })
+    }
+}
+
+/// Notifies the views that the layout of the model is about to change, for example because the
+/// rows are going to be sorted. Must be followed by [`layout_changed`].
+///
+/// This is the same as calling [`QAbstractListModel::layout_about_to_be_changed`] with no parents
+/// and `LayoutChangeHint::NoLayoutChangeHint`, but only needs a shared reference to the model.
+///
+/// Refer to the Qt documentation of QAbstractItemModel::layoutAboutToBeChanged
+pub fn layout_about_to_be_changed(model: &dyn QAbstractListModel) {
+    let obj = model.get_cpp_object();
+    unsafe {
+        cpp!([obj as "Rust_QAbstractListModel*"] {
+            if (obj) Q_EMIT obj->layoutAboutToBeChanged();
+        })
+    }
+}
+
+/// Notifies the views that the layout of the model changed.
+///
+/// This is the same as calling [`QAbstractListModel::layout_changed`] with no parents and
+/// `LayoutChangeHint::NoLayoutChangeHint`, but only needs a shared reference to the model.
+///
+/// Refer to the Qt documentation of QAbstractItemModel::layoutChanged
+pub fn layout_changed(model: &dyn QAbstractListModel) {
+    let obj = model.get_cpp_object();
+    unsafe {
+        cpp!([obj as "Rust_QAbstractListModel*"] {
+            if (obj) Q_EMIT obj->layoutChanged();
+        })
+    }
+}
+
+/// Notifies the views that the whole content of the model changed, so they reload everything.
+///
+/// This calls beginResetModel and endResetModel. Prefer calling
+/// [`QAbstractListModel::begin_reset_model`] before changing the data and
+/// [`QAbstractListModel::end_reset_model`] after, when possible.
+pub fn model_reset(model: &dyn QAbstractListModel) {
+    let obj = model.get_cpp_object();
+    unsafe {
+        cpp!([obj as "Rust_QAbstractListModel*"] {
+            if (obj) {
+                obj->beginResetModel();
+                obj->endResetModel();
+            }
+        })
+    }
+}
+
+/// Calls beginResetModel, and returns a guard which calls endResetModel when dropped.
+///
+/// The model can be accessed through the guard to replace its data. The views must not access the
+/// model while the guard is alive, and all the QModelIndex (including the ones held by QML) are
+/// invalidated by the reset.
+///
+/// ```ignore
+/// {
+///     let mut model = model_reset_guard(&mut my_model);
+///     model.items = load_items();
+/// } // the views reload everything here
+/// ```
+pub fn model_reset_guard<M: QAbstractListModel + ?Sized>(model: &mut M) -> ModelResetGuard<'_, M> {
+    model.begin_reset_model();
+    ModelResetGuard { model }
+}
+
+/// Guard returned by [`model_reset_guard`], which calls endResetModel when dropped.
+#[must_use]
+pub struct ModelResetGuard<'a, M: QAbstractListModel + ?Sized> {
+    model: &'a mut M,
+}
+
+impl<'a, M: QAbstractListModel + ?Sized> Deref for ModelResetGuard<'a, M> {
+    type Target = M;
+    fn deref(&self) -> &M {
+        self.model
+    }
+}
+
+impl<'a, M: QAbstractListModel + ?Sized> DerefMut for ModelResetGuard<'a, M> {
+    fn deref_mut(&mut self) -> &mut M {
+        self.model
+    }
+}
+
+impl<'a, M: QAbstractListModel + ?Sized> Drop for ModelResetGuard<'a, M> {
+    fn drop(&mut self) {
+        self.model.end_reset_model();
+    }
+}
+
 /// A trait used in SimpleListModel.
 /// Can be derived with `#[derive(SimpleListModel)]`, in which case all the member of the struct
 /// get exposed. The public member needs to implement the QMetaType trait
+///
+/// Alternatively, the fields to expose can be annotated with `#[qt_list_role = "name"]`, in which
+/// case only these fields are exposed, with the given role name:
+///
+/// ```
+/// # use qmetaobject::*;
+/// #[derive(SimpleListItem)]
+/// struct Contact {
+///     #[qt_list_role = "displayName"]
+///     name: QString,
+///     #[qt_list_role = "phoneNumber"]
+///     phone: QString,
+///     // not exposed to QML
+///     id: u64,
+/// }
+/// ```
 pub trait SimpleListItem {
     /// Get the item in for the given role.
     /// Note that the role is, in a way, an index in the names() array.
     fn get(&self, role: i32) -> QVariant;
     /// Array of the role names.
     fn names() -> Vec<QByteArray>;
+    /// The role names, as returned by `QAbstractListModel::role_names` for the models.
+    ///
+    /// By default, the role `USER_ROLE + i` is mapped to `names()[i]`. This can be re-implemented
+    /// to use other role numbers, knowing that `get` is called with the role minus `USER_ROLE`.
+    fn role_names() -> HashMap<i32, QByteArray> {
+        Self::names().into_iter().enumerate().map(|(i, x)| (i as i32 + USER_ROLE, x)).collect()
+    }
 }
 
 /// A simple QAbstractListModel which just wrap a vector of items.
@@ -225,7 +589,7 @@ where
         }
     }
     fn role_names(&self) -> HashMap<i32, QByteArray> {
-        T::names().iter().enumerate().map(|(i, x)| (i as i32 + USER_ROLE, x.clone())).collect()
+        T::role_names()
     }
 }
 impl<T: SimpleListItem> SimpleListModel<T> {
@@ -243,6 +607,57 @@ impl<T: SimpleListItem> SimpleListModel<T> {
         self.values.remove(index);
         (self as &mut dyn QAbstractListModel).end_remove_rows();
     }
+    /// Moves the item at index `from` so that it ends up at index `to`, notifying the views
+    /// with a move rather than a removal and an insertion.
+    pub fn move_row(&mut self, from: usize, to: usize) {
+        assert!(from < self.values.len() && to < self.values.len(), "index out of bounds");
+        if from == to {
+            return;
+        }
+        // Qt wants the destination as the index before which the row is inserted, before the move
+        let destination = if to > from { to + 1 } else { to };
+        if (self as &mut dyn QAbstractListModel).begin_move_rows(
+            from as i32,
+            from as i32,
+            destination as i32,
+        ) {
+            let value = self.values.remove(from);
+            self.values.insert(to, value);
+            (self as &mut dyn QAbstractListModel).end_move_rows();
+        }
+    }
+    /// Swaps the items at index `a` and `b`, notifying the views with moves.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        if a == b {
+            return;
+        }
+        self.move_row(b, a);
+        if b > a + 1 {
+            self.move_row(a + 1, b);
+        }
+    }
+    /// Removes all the items for which `f` returns false.
+    ///
+    /// Consecutive removed items are notified as a single removal.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let keep: Vec<bool> = self.values.iter().map(&mut f).collect();
+        let mut end = keep.len();
+        while end > 0 {
+            if keep[end - 1] {
+                end -= 1;
+                continue;
+            }
+            let mut start = end - 1;
+            while start > 0 && !keep[start - 1] {
+                start -= 1;
+            }
+            (self as &mut dyn QAbstractListModel).begin_remove_rows(start as i32, end as i32 - 1);
+            self.values.drain(start..end);
+            (self as &mut dyn QAbstractListModel).end_remove_rows();
+            end = start;
+        }
+    }
     pub fn change_line(&mut self, index: usize, value: T) {
         self.values[index] = value;
         let idx = (self as &mut dyn QAbstractListModel).row_index(index as i32);
@@ -257,6 +672,45 @@ impl<T: SimpleListItem> SimpleListModel<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.values.iter()
     }
+    /// Returns a guard giving mutable access to the item at `index`, or None if out of bounds.
+    ///
+    /// The views are notified that the row changed when the guard is dropped, so the
+    /// delegates are updated in place instead of being re-created.
+    pub fn get_mut(&mut self, index: usize) -> Option<SimpleListItemMutGuard<'_, T>> {
+        if index < self.values.len() {
+            Some(SimpleListItemMutGuard { model: self, index })
+        } else {
+            None
+        }
+    }
+}
+
+/// Mutable access to an item of a SimpleListModel, returned by [`SimpleListModel::get_mut`].
+///
+/// Emits `dataChanged` for the row when dropped.
+pub struct SimpleListItemMutGuard<'a, T: SimpleListItem + 'static> {
+    model: &'a mut SimpleListModel<T>,
+    index: usize,
+}
+
+impl<'a, T: SimpleListItem> Deref for SimpleListItemMutGuard<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.model.values[self.index]
+    }
+}
+
+impl<'a, T: SimpleListItem> DerefMut for SimpleListItemMutGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.model.values[self.index]
+    }
+}
+
+impl<'a, T: SimpleListItem> Drop for SimpleListItemMutGuard<'a, T> {
+    fn drop(&mut self) {
+        let row = self.index as i32;
+        data_changed_range(&*self.model, row..row + 1, &[]);
+    }
 }
 
 impl<T> FromIterator<T> for SimpleListModel<T>
@@ -290,3 +744,369 @@ where
         &self.values[index]
     }
 }
+
+/// A QAbstractListModel which stores items indexed by date, for example the appointments of a
+/// calendar.
+///
+/// The model contains one row per item, sorted by date, and the items of the same date are in
+/// insertion order. The role `date` (`USER_ROLE`) contains the date of the item, and is followed
+/// by the roles of the `SimpleListItem`.
+///
+/// The `current_month` property is meant to be bound to the month displayed by the QML view,
+/// and is not otherwise used by the model.
+#[derive(QObject, Default)]
+#[QMetaObjectCrate = "super"]
+pub struct CalendarModel<T: SimpleListItem + 'static> {
+    #[qt_base_class = "QAbstractListModel"]
+    base: QObjectCppWrapper,
+    pub current_month: crate::qt_property!(QDate; NOTIFY current_month_changed),
+    pub current_month_changed: crate::qt_signal!(),
+    items: BTreeMap<QDate, Vec<T>>,
+    /// The dates which have items, in order, with the row of their first item.
+    first_rows: Vec<(QDate, usize)>,
+}
+
+impl<T> QAbstractListModel for CalendarModel<T>
+where
+    T: SimpleListItem,
+{
+    fn row_count(&self) -> i32 {
+        self.first_rows.last().map_or(0, |(date, first)| first + self.items[date].len()) as i32
+    }
+    fn data(&self, index: QModelIndex, role: i32) -> QVariant {
+        let idx = index.row();
+        if idx < 0 || idx >= self.row_count() {
+            return QVariant::default();
+        }
+        let idx = idx as usize;
+        let pos = match self.first_rows.binary_search_by(|(_, first)| first.cmp(&idx)) {
+            Ok(pos) => pos,
+            Err(pos) => pos - 1,
+        };
+        let (date, first) = &self.first_rows[pos];
+        if role == USER_ROLE {
+            date.clone().into()
+        } else {
+            self.items[date][idx - first].get(role - USER_ROLE - 1)
+        }
+    }
+    fn role_names(&self) -> HashMap<i32, QByteArray> {
+        std::iter::once((USER_ROLE, QByteArray::from("date")))
+            .chain(T::role_names().into_iter().map(|(role, name)| (role + 1, name)))
+            .collect()
+    }
+}
+
+impl<T: SimpleListItem> CalendarModel<T> {
+    /// Returns the position of the date in `first_rows`, or where it would be inserted.
+    fn date_position(&self, date: &QDate) -> Result<usize, usize> {
+        self.first_rows.binary_search_by(|(d, _)| d.cmp(date))
+    }
+
+    /// Adds an item at the given date, after the items already present at that date.
+    pub fn insert(&mut self, date: QDate, item: T) {
+        let position = self.date_position(&date);
+        let row = match position {
+            Ok(pos) => self.first_rows[pos].1 + self.items[&date].len(),
+            Err(pos) => self.first_rows.get(pos).map_or(self.row_count() as usize, |r| r.1),
+        };
+        (self as &mut dyn QAbstractListModel).begin_insert_rows(row as i32, row as i32);
+        let pos = match position {
+            Ok(pos) => pos,
+            Err(pos) => {
+                self.first_rows.insert(pos, (date.clone(), row));
+                pos
+            }
+        };
+        for (_, first) in &mut self.first_rows[pos + 1..] {
+            *first += 1;
+        }
+        self.items.entry(date).or_insert_with(Vec::new).push(item);
+        (self as &mut dyn QAbstractListModel).end_insert_rows();
+    }
+
+    /// Removes all the items at the given date, and returns them.
+    pub fn remove(&mut self, date: QDate) -> Vec<T> {
+        let pos = match self.date_position(&date) {
+            Ok(pos) => pos,
+            Err(_) => return Vec::new(),
+        };
+        let first = self.first_rows[pos].1;
+        let count = self.items[&date].len();
+        (self as &mut dyn QAbstractListModel)
+            .begin_remove_rows(first as i32, (first + count) as i32 - 1);
+        self.first_rows.remove(pos);
+        for (_, first) in &mut self.first_rows[pos..] {
+            *first -= count;
+        }
+        let removed = self.items.remove(&date).unwrap_or_default();
+        (self as &mut dyn QAbstractListModel).end_remove_rows();
+        removed
+    }
+
+    /// Returns the items at the given date.
+    pub fn items_for_date(&self, date: QDate) -> &[T] {
+        self.items.get(&date).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns an iterator over the dates which have items, in order.
+    pub fn dates(&self) -> impl Iterator<Item = &QDate> {
+        self.items.keys()
+    }
+
+    /// Sets the `current_month` property, and notifies QML if it changed.
+    pub fn set_current_month(&mut self, month: QDate) {
+        if self.current_month != month {
+            self.current_month = month;
+            self.current_month_changed();
+        }
+    }
+}
+
+type PageLoader<T> = Rc<dyn Fn(usize, usize) -> Pin<Box<dyn Future<Output = Vec<T>>>>>;
+
+/// A QAbstractListModel which loads its items page by page with an async function, as the
+/// views request them with `fetchMore`.
+///
+/// The function is called with the offset and the number of items to load, and its future is
+/// run with [`execute_async`](crate::future::execute_async). The loaded items are appended to
+/// the model. A page with fewer items than requested marks the end of the list.
+///
+/// The `is_loading` property is true while a page is being loaded, and can be used by QML to
+/// show a busy indicator.
+///
+/// ```ignore
+/// let model = AsyncPaginatedListModel::new(50, |offset, limit| async move {
+///     fetch_contacts_from_server(offset, limit).await
+/// });
+/// ```
+#[derive(QObject, Default)]
+#[QMetaObjectCrate = "super"]
+pub struct AsyncPaginatedListModel<T: SimpleListItem + 'static> {
+    #[qt_base_class = "QAbstractListModel"]
+    base: QObjectCppWrapper,
+    pub is_loading: crate::qt_property!(bool; NOTIFY is_loading_changed),
+    pub is_loading_changed: crate::qt_signal!(),
+    values: Vec<T>,
+    page_size: usize,
+    load_page: Option<PageLoader<T>>,
+    at_end: bool,
+    /// Incremented by reset(), so that the pages requested before are discarded.
+    generation: usize,
+}
+
+impl<T> QAbstractListModel for AsyncPaginatedListModel<T>
+where
+    T: SimpleListItem,
+{
+    fn row_count(&self) -> i32 {
+        self.values.len() as i32
+    }
+    fn data(&self, index: QModelIndex, role: i32) -> QVariant {
+        let idx = index.row();
+        if idx >= 0 && (idx as usize) < self.values.len() {
+            self.values[idx as usize].get(role - USER_ROLE)
+        } else {
+            QVariant::default()
+        }
+    }
+    fn role_names(&self) -> HashMap<i32, QByteArray> {
+        T::role_names()
+    }
+    fn can_fetch_more(&self) -> bool {
+        !self.at_end && !self.is_loading && self.load_page.is_some()
+    }
+    fn fetch_more(&mut self) {
+        if !self.can_fetch_more() {
+            return;
+        }
+        let future = (self.load_page.as_ref().unwrap())(self.values.len(), self.page_size);
+        let generation = self.generation;
+        let ptr = QPointer::from(&*self);
+        self.set_loading(true);
+        crate::future::execute_async(async move {
+            // The future is first polled from within fetch_more, while the model is borrowed.
+            YieldOnce(false).await;
+            let items = future.await;
+            if let Some(this) = ptr.as_pinned() {
+                this.borrow_mut().append_page(generation, items);
+            }
+        });
+    }
+}
+
+/// A future which is pending the first time it is polled, so that the code after it runs from
+/// the event loop.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut std::task::Context) -> std::task::Poll<()> {
+        if self.0 {
+            return std::task::Poll::Ready(());
+        }
+        self.0 = true;
+        ctx.waker().wake_by_ref();
+        std::task::Poll::Pending
+    }
+}
+
+impl<T: SimpleListItem> AsyncPaginatedListModel<T> {
+    /// Creates a new empty model which loads `page_size` items at a time with `load_page`.
+    pub fn new<F, Fut>(page_size: usize, load_page: F) -> Self
+    where
+        F: Fn(usize, usize) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        AsyncPaginatedListModel {
+            base: Default::default(),
+            is_loading: false,
+            is_loading_changed: Default::default(),
+            values: Vec::new(),
+            page_size,
+            load_page: Some(Rc::new(move |offset, limit| Box::pin(load_page(offset, limit)))),
+            at_end: false,
+            generation: 0,
+        }
+    }
+
+    /// Returns true if the last page was loaded.
+    pub fn at_end(&self) -> bool {
+        self.at_end
+    }
+
+    /// Removes all the items, so that they are loaded again from the first page.
+    /// The pages still loading are discarded.
+    pub fn reset(&mut self) {
+        (self as &mut dyn QAbstractListModel).begin_reset_model();
+        self.values.clear();
+        self.at_end = false;
+        self.generation += 1;
+        (self as &mut dyn QAbstractListModel).end_reset_model();
+        self.set_loading(false);
+    }
+
+    /// Returns an iterator over the items loaded so far.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    fn set_loading(&mut self, loading: bool) {
+        if self.is_loading != loading {
+            self.is_loading = loading;
+            self.is_loading_changed();
+        }
+    }
+
+    fn append_page(&mut self, generation: usize, items: Vec<T>) {
+        if generation != self.generation {
+            return;
+        }
+        self.at_end = items.len() < self.page_size;
+        if !items.is_empty() {
+            let first = self.values.len() as i32;
+            let last = first + items.len() as i32 - 1;
+            (self as &mut dyn QAbstractListModel).begin_insert_rows(first, last);
+            self.values.extend(items);
+            (self as &mut dyn QAbstractListModel).end_insert_rows();
+        }
+        self.set_loading(false);
+    }
+}
+
+impl<T> Index<usize> for AsyncPaginatedListModel<T>
+where
+    T: SimpleListItem,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.values[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(QObject, Default)]
+    #[QMetaObjectCrate = "super::super"]
+    struct Reorderable {
+        #[qt_base_class = "QAbstractListModel"]
+        base: QObjectCppWrapper,
+        values: Vec<i32>,
+    }
+
+    impl QAbstractListModel for Reorderable {
+        fn row_count(&self) -> i32 {
+            self.values.len() as i32
+        }
+        fn data(&self, index: QModelIndex, _role: i32) -> QVariant {
+            self.values.get(index.row() as usize).map_or_else(QVariant::default, |v| (*v).into())
+        }
+        fn mime_types(&self) -> Vec<QString> {
+            vec!["application/x-row".into()]
+        }
+        fn mime_data(&self, indexes: &[QModelIndex]) -> Option<QMimeData> {
+            let rows: Vec<String> = indexes.iter().map(|i| i.row().to_string()).collect();
+            let mut data = QMimeData::new();
+            data.set_data("application/x-row", QByteArray::from(rows.join(",").as_str()));
+            Some(data)
+        }
+        fn drop_mime_data(
+            &mut self,
+            data: &QMimeData,
+            action: DropAction,
+            row: i32,
+            _column: i32,
+            _parent: QModelIndex,
+        ) -> Option<bool> {
+            if !data.has_format("application/x-row") {
+                return None;
+            }
+            if action != DropAction::MoveAction {
+                return Some(false);
+            }
+            let from: usize = data.data("application/x-row").to_string().parse().unwrap();
+            let value = self.values.remove(from);
+            self.values.insert(row as usize, value);
+            Some(true)
+        }
+        fn supported_drop_actions(&self) -> DropActions {
+            DropActions::CopyAction | DropActions::MoveAction
+        }
+    }
+
+    #[test]
+    fn drag_and_drop_through_qabstractitemmodel() {
+        let model = RefCell::new(Reorderable { values: vec![1, 2, 3], ..Default::default() });
+        let obj = unsafe { QObjectPinned::new(&model).get_or_create_cpp_object() };
+
+        let actions = cpp!(unsafe [obj as "QAbstractItemModel *"] -> DropActions as "int" {
+            return obj->supportedDropActions();
+        });
+        assert_eq!(actions, DropActions::CopyAction | DropActions::MoveAction);
+        let actions = cpp!(unsafe [obj as "QAbstractItemModel *"] -> DropActions as "int" {
+            return obj->supportedDragActions();
+        });
+        assert_eq!(actions, DropActions::CopyAction | DropActions::MoveAction);
+
+        let moved = cpp!(unsafe [obj as "QAbstractItemModel *"] -> bool as "bool" {
+            std::unique_ptr<QMimeData> data(obj->mimeData({ obj->index(2, 0) }));
+            if (!data || data->formats() != QStringList{ QStringLiteral("application/x-row") })
+                return false;
+            return obj->dropMimeData(data.get(), Qt::MoveAction, 0, 0, QModelIndex());
+        });
+        assert!(moved);
+        assert_eq!(model.borrow().values, vec![3, 1, 2]);
+
+        // Not handled by the model: Qt's implementation rejects the unknown format
+        let dropped = cpp!(unsafe [obj as "QAbstractItemModel *"] -> bool as "bool" {
+            QMimeData data;
+            data.setText(QStringLiteral("foo"));
+            return obj->dropMimeData(&data, Qt::CopyAction, 0, 0, QModelIndex());
+        });
+        assert!(!dropped);
+        assert_eq!(model.borrow().values, vec![3, 1, 2]);
+    }
+}
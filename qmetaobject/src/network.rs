@@ -0,0 +1,479 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Bindings for some classes of the Qt Network module.
+//!
+//! This module requires the `network` feature.
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtNetwork/QLocalServer>
+    #include <QtNetwork/QLocalSocket>
+    #include <QtNetwork/QTcpSocket>
+
+    struct QTcpSocketHolder {
+        std::unique_ptr<QTcpSocket> socket;
+
+        QTcpSocketHolder() : socket(new QTcpSocket) {}
+    };
+
+    struct QLocalSocketHolder {
+        std::unique_ptr<QLocalSocket> socket;
+
+        QLocalSocketHolder() : socket(new QLocalSocket) {}
+        QLocalSocketHolder(QLocalSocket *s) : socket(s) {}
+    };
+
+    struct QLocalServerHolder {
+        std::unique_ptr<QLocalServer> server;
+
+        QLocalServerHolder() : server(new QLocalServer) {}
+    };
+}}
+
+/// Wrapper around [`QAbstractSocket::SocketError`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qabstractsocket.html#SocketError-enum
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SocketError {
+    ConnectionRefusedError = 0,
+    RemoteHostClosedError = 1,
+    HostNotFoundError = 2,
+    SocketAccessError = 3,
+    SocketResourceError = 4,
+    SocketTimeoutError = 5,
+    DatagramTooLargeError = 6,
+    NetworkError = 7,
+    AddressInUseError = 8,
+    SocketAddressNotAvailableError = 9,
+    UnsupportedSocketOperationError = 10,
+    UnfinishedSocketOperationError = 11,
+    ProxyAuthenticationRequiredError = 12,
+    SslHandshakeFailedError = 13,
+    ProxyConnectionRefusedError = 14,
+    ProxyConnectionClosedError = 15,
+    ProxyConnectionTimeoutError = 16,
+    ProxyNotFoundError = 17,
+    ProxyProtocolError = 18,
+    OperationError = 19,
+    SslInternalError = 20,
+    SslInvalidUserDataError = 21,
+    TemporaryError = 22,
+    UnknownSocketError = -1,
+}
+
+impl std::fmt::Display for SocketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for SocketError {}
+
+/// Wrapper around [`QAbstractSocket::SocketState`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qabstractsocket.html#SocketState-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SocketState {
+    UnconnectedState,
+    HostLookupState,
+    ConnectingState,
+    ConnectedState,
+    BoundState,
+    ListeningState,
+    ClosingState,
+}
+
+cpp_class!(
+    /// Wrapper around [`QTcpSocket`][class] class.
+    ///
+    /// Like all QObjects, the socket must be used from the thread it was created in, and needs
+    /// a running event loop.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtcpsocket.html
+    pub unsafe struct QTcpSocket as "QTcpSocketHolder"
+);
+
+impl QTcpSocket {
+    /// Creates a new, unconnected, socket
+    pub fn new() -> QTcpSocket {
+        QTcpSocket::default()
+    }
+
+    /// Returns a pointer to the underlying QTcpSocket, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QTcpSocketHolder *"] -> *mut c_void as "QTcpSocket *" {
+            return self->socket.get();
+        })
+    }
+
+    /// Starts connecting to the host. This function returns immediately, use
+    /// [`connect_to_host`](fn.connect_to_host.html) to wait for the result.
+    ///
+    /// Refer to the Qt documentation of QAbstractSocket::connectToHost
+    pub fn connect_to_host(&mut self, host: &str, port: u16) {
+        let host = QString::from(host);
+        cpp!(unsafe [self as "QTcpSocketHolder *", host as "QString", port as "quint16"] {
+            self->socket->connectToHost(host, port);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSocket::disconnectFromHost
+    pub fn disconnect_from_host(&mut self) {
+        cpp!(unsafe [self as "QTcpSocketHolder *"] {
+            self->socket->disconnectFromHost();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSocket::state
+    pub fn state(&self) -> SocketState {
+        cpp!(unsafe [self as "QTcpSocketHolder *"] -> SocketState as "QAbstractSocket::SocketState" {
+            return self->socket->state();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSocket::error
+    pub fn error(&self) -> SocketError {
+        cpp!(unsafe [self as "QTcpSocketHolder *"] -> SocketError as "QAbstractSocket::SocketError" {
+            return self->socket->error();
+        })
+    }
+
+    /// Refer to the Qt documentation of QIODevice::errorString
+    pub fn error_string(&self) -> QString {
+        cpp!(unsafe [self as "QTcpSocketHolder *"] -> QString as "QString" {
+            return self->socket->errorString();
+        })
+    }
+
+    /// Writes the data to the socket. Returns the number of bytes written, or -1 on error.
+    ///
+    /// Refer to the Qt documentation of QIODevice::write
+    pub fn write(&mut self, data: &[u8]) -> i64 {
+        let len = data.len() as i64;
+        let ptr = data.as_ptr();
+        cpp!(unsafe [self as "QTcpSocketHolder *", ptr as "const char *", len as "qint64"] -> i64 as "qint64" {
+            return self->socket->write(ptr, len);
+        })
+    }
+
+    /// Refer to the Qt documentation of QIODevice::readAll
+    pub fn read_all(&mut self) -> QByteArray {
+        cpp!(unsafe [self as "QTcpSocketHolder *"] -> QByteArray as "QByteArray" {
+            return self->socket->readAll();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSocket::connected
+    pub fn connected_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractSocket::connected;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAbstractSocket::disconnected
+    pub fn disconnected_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractSocket::disconnected;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QIODevice::readyRead
+    pub fn ready_read_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QIODevice::readyRead;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAbstractSocket::errorOccurred
+    /// (QAbstractSocket::error before Qt 5.15)
+    pub fn error_occurred_signal() -> Signal<fn(SocketError)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+            #if QT_VERSION >= QT_VERSION_CHECK(5, 15, 0)
+                return &QAbstractSocket::errorOccurred;
+            #else
+                return static_cast<void (QAbstractSocket::*)(QAbstractSocket::SocketError)>(&QAbstractSocket::error);
+            #endif
+            }))
+        }
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QLocalSocket`][class] class, for local inter-process communication
+    /// (Unix domain sockets or Windows named pipes).
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlocalsocket.html
+    pub unsafe struct QLocalSocket as "QLocalSocketHolder"
+);
+
+impl QLocalSocket {
+    /// Creates a new, unconnected, socket
+    pub fn new() -> QLocalSocket {
+        QLocalSocket::default()
+    }
+
+    /// Returns a pointer to the underlying QLocalSocket, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QLocalSocketHolder *"] -> *mut c_void as "QLocalSocket *" {
+            return self->socket.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalSocket::connectToServer
+    pub fn connect_to_server(&mut self, name: &str) {
+        let name = QString::from(name);
+        cpp!(unsafe [self as "QLocalSocketHolder *", name as "QString"] {
+            self->socket->connectToServer(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalSocket::disconnectFromServer
+    pub fn disconnect_from_server(&mut self) {
+        cpp!(unsafe [self as "QLocalSocketHolder *"] {
+            self->socket->disconnectFromServer();
+        })
+    }
+
+    /// Returns true if the socket is connected
+    ///
+    /// Refer to the Qt documentation of QLocalSocket::state
+    pub fn is_connected(&self) -> bool {
+        cpp!(unsafe [self as "QLocalSocketHolder *"] -> bool as "bool" {
+            return self->socket->state() == QLocalSocket::ConnectedState;
+        })
+    }
+
+    /// Writes the data to the socket. Returns the number of bytes written, or -1 on error.
+    ///
+    /// Refer to the Qt documentation of QIODevice::write
+    pub fn write(&mut self, data: &[u8]) -> i64 {
+        let len = data.len() as i64;
+        let ptr = data.as_ptr();
+        cpp!(unsafe [self as "QLocalSocketHolder *", ptr as "const char *", len as "qint64"] -> i64 as "qint64" {
+            return self->socket->write(ptr, len);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalSocket::flush
+    pub fn flush(&mut self) -> bool {
+        cpp!(unsafe [self as "QLocalSocketHolder *"] -> bool as "bool" {
+            return self->socket->flush();
+        })
+    }
+
+    /// Refer to the Qt documentation of QIODevice::readAll
+    pub fn read_all(&mut self) -> QByteArray {
+        cpp!(unsafe [self as "QLocalSocketHolder *"] -> QByteArray as "QByteArray" {
+            return self->socket->readAll();
+        })
+    }
+
+    /// Refer to the Qt documentation of QIODevice::errorString
+    pub fn error_string(&self) -> QString {
+        cpp!(unsafe [self as "QLocalSocketHolder *"] -> QString as "QString" {
+            return self->socket->errorString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalSocket::connected
+    pub fn connected_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLocalSocket::connected;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QLocalSocket::disconnected
+    pub fn disconnected_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLocalSocket::disconnected;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QIODevice::readyRead
+    pub fn ready_read_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QIODevice::readyRead;
+            }))
+        }
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QLocalServer`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlocalserver.html
+    pub unsafe struct QLocalServer as "QLocalServerHolder"
+);
+
+impl QLocalServer {
+    /// Creates a new server, not listening yet
+    pub fn new() -> QLocalServer {
+        QLocalServer::default()
+    }
+
+    /// Returns a pointer to the underlying QLocalServer, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QLocalServerHolder *"] -> *mut c_void as "QLocalServer *" {
+            return self->server.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalServer::listen
+    pub fn listen(&mut self, name: &str) -> bool {
+        let name = QString::from(name);
+        cpp!(unsafe [self as "QLocalServerHolder *", name as "QString"] -> bool as "bool" {
+            return self->server->listen(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalServer::close
+    pub fn close(&mut self) {
+        cpp!(unsafe [self as "QLocalServerHolder *"] {
+            self->server->close();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalServer::removeServer
+    pub fn remove_server(name: &str) -> bool {
+        let name = QString::from(name);
+        cpp!(unsafe [name as "QString"] -> bool as "bool" {
+            return QLocalServer::removeServer(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalServer::fullServerName
+    pub fn full_server_name(&self) -> QString {
+        cpp!(unsafe [self as "QLocalServerHolder *"] -> QString as "QString" {
+            return self->server->fullServerName();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLocalServer::errorString
+    pub fn error_string(&self) -> QString {
+        cpp!(unsafe [self as "QLocalServerHolder *"] -> QString as "QString" {
+            return self->server->errorString();
+        })
+    }
+
+    /// Returns the next pending connection, or None if there is none.
+    ///
+    /// The returned socket is owned by the caller.
+    ///
+    /// Refer to the Qt documentation of QLocalServer::nextPendingConnection
+    pub fn next_pending_connection(&mut self) -> Option<QLocalSocket> {
+        let mut found = false;
+        let socket = cpp!(unsafe [self as "QLocalServerHolder *", mut found as "bool"] -> QLocalSocket as "QLocalSocketHolder" {
+            QLocalSocket *s = self->server->nextPendingConnection();
+            if (!s)
+                return QLocalSocketHolder(nullptr);
+            found = true;
+            s->setParent(nullptr);
+            return QLocalSocketHolder(s);
+        });
+        if found {
+            Some(socket)
+        } else {
+            None
+        }
+    }
+
+    /// Refer to the Qt documentation of QLocalServer::newConnection
+    pub fn new_connection_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLocalServer::newConnection;
+            }))
+        }
+    }
+}
+
+enum Either<A, B> {
+    First(A),
+    Second(B),
+}
+
+/// Future resolving to the output of whichever of the two futures completes first.
+struct SelectFirst<A, B>(A, B);
+
+impl<A: Future + Unpin, B: Future + Unpin> Future for SelectFirst<A, B> {
+    type Output = Either<A::Output, B::Output>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        if let Poll::Ready(a) = Pin::new(&mut self.0).poll(cx) {
+            return Poll::Ready(Either::First(a));
+        }
+        if let Poll::Ready(b) = Pin::new(&mut self.1).poll(cx) {
+            return Poll::Ready(Either::Second(b));
+        }
+        Poll::Pending
+    }
+}
+
+fn select_first<A: Future + Unpin, B: Future + Unpin>(a: A, b: B) -> SelectFirst<A, B> {
+    SelectFirst(a, b)
+}
+
+/// Connects the socket to the given host, and waits until the connection is established or
+/// an error occurs, without blocking the event loop.
+///
+/// This is the asynchronous equivalent of `connectToHost` followed by `waitForConnected`.
+/// It needs to be run within a Qt event loop, for example with
+/// [`execute_async`](../future/fn.execute_async.html).
+pub async fn connect_to_host(
+    socket: &mut QTcpSocket,
+    host: &str,
+    port: u16,
+) -> Result<(), SocketError> {
+    let sender = socket.cpp_ptr();
+    let connected = unsafe { wait_on_signal(sender, QTcpSocket::connected_signal()) };
+    let error = unsafe { wait_on_signal(sender, QTcpSocket::error_occurred_signal()) };
+    socket.connect_to_host(host, port);
+    // Some errors are reported synchronously by connectToHost
+    match socket.state() {
+        SocketState::ConnectedState => return Ok(()),
+        SocketState::UnconnectedState => return Err(socket.error()),
+        _ => {}
+    }
+    match select_first(connected, error).await {
+        Either::First(()) => Ok(()),
+        Either::Second((error,)) => Err(error),
+    }
+}
@@ -0,0 +1,224 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::marker::PhantomData;
+use std::ops::{BitOr, BitOrAssign};
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtGui/QImage>
+    #include <QtGui/QPainter>
+    #include <QtGui/QPixmap>
+}}
+
+/// A type that can be painted on with a [`QPainter`], such as a [`QImage`].
+pub trait QPaintDevice {
+    /// Returns a pointer to the underlying QPaintDevice, or a null pointer if there is none.
+    fn paint_device_ptr(&mut self) -> *mut c_void;
+}
+
+impl QPaintDevice for QImage {
+    fn paint_device_ptr(&mut self) -> *mut c_void {
+        cpp!(unsafe [self as "QImage *"] -> *mut c_void as "QPaintDevice *" {
+            return self;
+        })
+    }
+}
+
+impl QPaintDevice for QPixmap {
+    fn paint_device_ptr(&mut self) -> *mut c_void {
+        cpp!(unsafe [self as "QPixmap *"] -> *mut c_void as "QPaintDevice *" {
+            return self;
+        })
+    }
+}
+
+/// Wrapper around [`QPainter::RenderHints`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qpainter.html#RenderHint-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct RenderHints(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl RenderHints {
+    pub const Antialiasing: RenderHints = RenderHints(0x01);
+    pub const TextAntialiasing: RenderHints = RenderHints(0x02);
+    pub const SmoothPixmapTransform: RenderHints = RenderHints(0x04);
+    pub const LosslessImageRendering: RenderHints = RenderHints(0x40);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: RenderHints) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for RenderHints {
+    type Output = RenderHints;
+    fn bitor(self, other: RenderHints) -> RenderHints {
+        RenderHints(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for RenderHints {
+    fn bitor_assign(&mut self, other: RenderHints) {
+        self.0 |= other.0;
+    }
+}
+
+cpp_class!(unsafe struct QPainterHolder as "std::unique_ptr<QPainter>");
+
+/// Wrapper around [`QPainter`][class] class.
+///
+/// The painter is active from [`begin`](#method.begin) until it is ended, either with
+/// [`end`](#method.end) or when it is dropped. It borrows the device for that time.
+///
+/// [class]: https://doc.qt.io/qt-5/qpainter.html
+pub struct QPainter<'a> {
+    painter: QPainterHolder,
+    _device: PhantomData<&'a mut dyn QPaintDevice>,
+}
+
+impl<'a> QPainter<'a> {
+    /// Begins painting on the device. Returns None if the device cannot be painted on.
+    ///
+    /// Refer to the Qt documentation of QPainter::begin
+    pub fn begin(device: &'a mut dyn QPaintDevice) -> Option<QPainter<'a>> {
+        let device = device.paint_device_ptr();
+        let painter = cpp!(unsafe [device as "QPaintDevice *"]
+                -> QPainterHolder as "std::unique_ptr<QPainter>" {
+            std::unique_ptr<QPainter> painter(new QPainter);
+            if (!device || !painter->begin(device))
+                return nullptr;
+            return painter;
+        });
+        let is_null = cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *"] -> bool as "bool" {
+            return !*painter;
+        });
+        if is_null {
+            None
+        } else {
+            Some(QPainter { painter, _device: PhantomData })
+        }
+    }
+
+    /// Ends painting, and returns false if it failed, for example because a file could not
+    /// be written.
+    ///
+    /// Refer to the Qt documentation of QPainter::end
+    pub fn end(self) -> bool {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *"] -> bool as "bool" {
+            return (*painter)->end();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::setRenderHints
+    pub fn set_render_hints(&mut self, hints: RenderHints) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", hints as "QPainter::RenderHints"] {
+            (*painter)->setRenderHints(hints);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::renderHints
+    pub fn render_hints(&self) -> RenderHints {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *"] -> RenderHints as "QPainter::RenderHints" {
+            return (*painter)->renderHints();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::setPen
+    pub fn set_pen(&mut self, pen: &QPen) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", pen as "const QPen *"] {
+            (*painter)->setPen(*pen);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::setBrush
+    pub fn set_brush(&mut self, brush: &QBrush) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", brush as "const QBrush *"] {
+            (*painter)->setBrush(*brush);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::setFont
+    pub fn set_font(&mut self, font: &QFont) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", font as "const QFont *"] {
+            (*painter)->setFont(*font);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::drawLine
+    pub fn draw_line(&mut self, line: QLineF) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", line as "QLineF"] {
+            (*painter)->drawLine(line);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::drawRect
+    pub fn draw_rect(&mut self, rect: QRectF) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", rect as "QRectF"] {
+            (*painter)->drawRect(rect);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::drawEllipse
+    pub fn draw_ellipse(&mut self, rect: QRectF) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", rect as "QRectF"] {
+            (*painter)->drawEllipse(rect);
+        })
+    }
+
+    /// Draws the text with its baseline starting at `pos`.
+    ///
+    /// Refer to the Qt documentation of QPainter::drawText
+    pub fn draw_text(&mut self, pos: QPointF, text: &str) {
+        let painter = &self.painter;
+        let text = QString::from(text);
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", pos as "QPointF", text as "QString"] {
+            (*painter)->drawText(pos, text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::drawImage
+    pub fn draw_image(&mut self, pos: QPointF, image: &QImage) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", pos as "QPointF", image as "const QImage *"] {
+            (*painter)->drawImage(pos, *image);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPainter::fillRect
+    pub fn fill_rect(&mut self, rect: QRectF, color: QColor) {
+        let painter = &self.painter;
+        cpp!(unsafe [painter as "const std::unique_ptr<QPainter> *", rect as "QRectF", color as "QColor"] {
+            (*painter)->fillRect(rect, color);
+        })
+    }
+}
@@ -0,0 +1,772 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+#if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+    #include <QtCore/QCborArray>
+    #include <QtCore/QCborMap>
+    #include <QtCore/QCborStreamReader>
+    #include <QtCore/QCborStreamWriter>
+    #include <QtCore/QCborValue>
+    #include <memory>
+
+    struct QCborStreamWriterHolder {
+        // Heap allocated because the writer keeps a pointer to it
+        std::unique_ptr<QByteArray> data;
+        std::unique_ptr<QCborStreamWriter> writer;
+
+        QCborStreamWriterHolder()
+            : data(new QByteArray)
+            , writer(new QCborStreamWriter(data.get()))
+        {}
+    };
+
+    struct QCborStreamReaderHolder {
+        std::unique_ptr<QCborStreamReader> reader;
+
+        QCborStreamReaderHolder(const QByteArray &data) : reader(new QCborStreamReader(data)) {}
+    };
+#else
+    // The CBOR classes were added in Qt 5.12. The Rust side of this module is only compiled
+    // with `qt_5_12`, but the C++ code always is, so provide placeholder types.
+    struct QCborPlaceholder {
+        bool operator==(const QCborPlaceholder &) const { return true; }
+        bool operator<(const QCborPlaceholder &) const { return false; }
+    };
+    struct QCborValue : QCborPlaceholder {};
+    struct QCborMap : QCborPlaceholder {};
+    struct QCborArray : QCborPlaceholder {};
+    struct QCborStreamWriterHolder {};
+    struct QCborStreamReaderHolder {
+        QCborStreamReaderHolder() = default;
+        QCborStreamReaderHolder(const QByteArray &) {}
+    };
+#endif
+}}
+
+cpp_class!(
+    /// Wrapper around [`QCborValue`][class] class.
+    ///
+    /// The default value is the `undefined` value.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcborvalue.html
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    pub unsafe struct QCborValue as "QCborValue"
+);
+
+impl QCborValue {
+    /// Returns the CBOR `null` value.
+    pub fn null() -> QCborValue {
+        cpp!(unsafe [] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(nullptr);
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Returns the CBOR `undefined` value.
+    pub fn undefined() -> QCborValue {
+        QCborValue::default()
+    }
+
+    /// Refer to the Qt documentation of QCborValue::isNull
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isNull();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborValue::isUndefined
+    pub fn is_undefined(&self) -> bool {
+        cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isUndefined();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborValue::isInvalid
+    pub fn is_invalid(&self) -> bool {
+        cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isInvalid();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Returns the boolean value, or None if this is not a boolean.
+    pub fn to_bool(&self) -> Option<bool> {
+        let mut is_bool = false;
+        let value = cpp!(unsafe [self as "const QCborValue *", mut is_bool as "bool"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            is_bool = self->isBool();
+            return self->toBool();
+        #else
+            return {};
+        #endif
+        });
+        if is_bool {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the integer value, or None if this is not an integer.
+    pub fn to_integer(&self) -> Option<i64> {
+        let mut is_integer = false;
+        let value = cpp!(unsafe [self as "const QCborValue *", mut is_integer as "bool"] -> i64 as "qint64" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            is_integer = self->isInteger();
+            return self->toInteger();
+        #else
+            return {};
+        #endif
+        });
+        if is_integer {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a floating point number, or None if this is neither a double nor
+    /// an integer.
+    pub fn to_double(&self) -> Option<f64> {
+        let mut is_number = false;
+        let value = cpp!(unsafe [self as "const QCborValue *", mut is_number as "bool"] -> f64 as "double" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            is_number = self->isDouble() || self->isInteger();
+            return self->toDouble();
+        #else
+            return {};
+        #endif
+        });
+        if is_number {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the string value, or None if this is not a string.
+    pub fn to_string(&self) -> Option<QString> {
+        let is_string = cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isString();
+        #else
+            return {};
+        #endif
+        });
+        if !is_string {
+            return None;
+        }
+        Some(cpp!(unsafe [self as "const QCborValue *"] -> QString as "QString" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toString();
+        #else
+            return {};
+        #endif
+        }))
+    }
+
+    /// Returns the byte array value, or None if this is not a byte array.
+    pub fn to_byte_array(&self) -> Option<QByteArray> {
+        let is_bytearray = cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isByteArray();
+        #else
+            return {};
+        #endif
+        });
+        if !is_bytearray {
+            return None;
+        }
+        Some(cpp!(unsafe [self as "const QCborValue *"] -> QByteArray as "QByteArray" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toByteArray();
+        #else
+            return {};
+        #endif
+        }))
+    }
+
+    /// Returns the map value, or None if this is not a map.
+    pub fn to_map(&self) -> Option<QCborMap> {
+        let is_map = cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isMap();
+        #else
+            return {};
+        #endif
+        });
+        if !is_map {
+            return None;
+        }
+        Some(cpp!(unsafe [self as "const QCborValue *"] -> QCborMap as "QCborMap" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toMap();
+        #else
+            return {};
+        #endif
+        }))
+    }
+
+    /// Returns the array value, or None if this is not an array.
+    pub fn to_array(&self) -> Option<QCborArray> {
+        let is_array = cpp!(unsafe [self as "const QCborValue *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isArray();
+        #else
+            return {};
+        #endif
+        });
+        if !is_array {
+            return None;
+        }
+        Some(cpp!(unsafe [self as "const QCborValue *"] -> QCborArray as "QCborArray" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toArray();
+        #else
+            return {};
+        #endif
+        }))
+    }
+
+    /// Refer to the Qt documentation of QCborValue::toVariant
+    pub fn to_qvariant(&self) -> QVariant {
+        cpp!(unsafe [self as "const QCborValue *"] -> QVariant as "QVariant" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toVariant();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborValue::fromVariant
+    pub fn from_qvariant(variant: &QVariant) -> QCborValue {
+        cpp!(unsafe [variant as "const QVariant *"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue::fromVariant(*variant);
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Decodes a single CBOR item.
+    ///
+    /// Returns None if the data is not valid CBOR.
+    ///
+    /// Refer to the Qt documentation of QCborValue::fromCbor
+    pub fn from_cbor(bytes: &[u8]) -> Option<QCborValue> {
+        let data = QByteArray::from(bytes);
+        let mut ok = false;
+        let value = cpp!(unsafe [data as "QByteArray", mut ok as "bool"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            QCborParserError error;
+            QCborValue value = QCborValue::fromCbor(data, &error);
+            ok = error.error == QCborError::NoError;
+            return value;
+        #else
+            return {};
+        #endif
+        });
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Refer to the Qt documentation of QCborValue::toCbor
+    pub fn to_cbor(&self) -> QByteArray {
+        cpp!(unsafe [self as "const QCborValue *"] -> QByteArray as "QByteArray" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toCbor();
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+
+impl From<bool> for QCborValue {
+    fn from(a: bool) -> QCborValue {
+        cpp!(unsafe [a as "bool"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+impl From<i64> for QCborValue {
+    fn from(a: i64) -> QCborValue {
+        cpp!(unsafe [a as "qint64"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+impl From<f64> for QCborValue {
+    fn from(a: f64) -> QCborValue {
+        cpp!(unsafe [a as "double"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+impl From<QByteArray> for QCborValue {
+    fn from(a: QByteArray) -> QCborValue {
+        cpp!(unsafe [a as "QByteArray"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+impl From<QString> for QCborValue {
+    fn from(a: QString) -> QCborValue {
+        cpp!(unsafe [a as "QString"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+impl<'a> From<&'a str> for QCborValue {
+    fn from(a: &'a str) -> QCborValue {
+        QString::from(a).into()
+    }
+}
+impl From<QCborMap> for QCborValue {
+    fn from(a: QCborMap) -> QCborValue {
+        cpp!(unsafe [a as "QCborMap"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+impl From<QCborArray> for QCborValue {
+    fn from(a: QCborArray) -> QCborValue {
+        cpp!(unsafe [a as "QCborArray"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborValue(a);
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QCborMap`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcbormap.html
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    pub unsafe struct QCborMap as "QCborMap"
+);
+
+impl QCborMap {
+    /// Refer to the Qt documentation of QCborMap::insert
+    pub fn insert(&mut self, key: impl Into<QCborValue>, value: impl Into<QCborValue>) {
+        let key = key.into();
+        let value = value.into();
+        cpp!(unsafe [self as "QCborMap *", key as "QCborValue", value as "QCborValue"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->insert(key, value);
+        #endif
+        })
+    }
+
+    /// Returns the value for the given key, or the `undefined` value if the key is not in the map.
+    ///
+    /// Refer to the Qt documentation of QCborMap::value
+    pub fn value(&self, key: impl Into<QCborValue>) -> QCborValue {
+        let key = key.into();
+        cpp!(unsafe [self as "const QCborMap *", key as "QCborValue"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->value(key);
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborMap::contains
+    pub fn contains(&self, key: impl Into<QCborValue>) -> bool {
+        let key = key.into();
+        cpp!(unsafe [self as "const QCborMap *", key as "QCborValue"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->contains(key);
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborMap::size
+    pub fn len(&self) -> usize {
+        cpp!(unsafe [self as "const QCborMap *"] -> usize as "size_t" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->size();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborMap::isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "const QCborMap *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isEmpty();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborMap::toVariantMap
+    pub fn to_qvariant_map(&self) -> QVariantMap {
+        cpp!(unsafe [self as "const QCborMap *"] -> QVariantMap as "QVariantMap" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toVariantMap();
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QCborArray`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcborarray.html
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    pub unsafe struct QCborArray as "QCborArray"
+);
+
+impl QCborArray {
+    /// Refer to the Qt documentation of QCborArray::append
+    pub fn push(&mut self, value: impl Into<QCborValue>) {
+        let value = value.into();
+        cpp!(unsafe [self as "QCborArray *", value as "QCborValue"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->append(value);
+        #endif
+        })
+    }
+
+    /// Returns the value at the given index, or the `undefined` value if the index is out of
+    /// bounds.
+    ///
+    /// Refer to the Qt documentation of QCborArray::at
+    pub fn at(&self, index: usize) -> QCborValue {
+        cpp!(unsafe [self as "const QCborArray *", index as "size_t"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->at(index);
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborArray::size
+    pub fn len(&self) -> usize {
+        cpp!(unsafe [self as "const QCborArray *"] -> usize as "size_t" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->size();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborArray::isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "const QCborArray *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->isEmpty();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QCborArray::toVariantList
+    pub fn to_qvariant_list(&self) -> QVariantList {
+        cpp!(unsafe [self as "const QCborArray *"] -> QVariantList as "QVariantList" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->toVariantList();
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QCborStreamWriter`][class] class which encodes to an in-memory buffer.
+    ///
+    /// Arrays and maps are started with [`start_array`](#method.start_array) or
+    /// [`start_map`](#method.start_map), followed by their elements (key and value alternating
+    /// for maps), and closed with [`end_array`](#method.end_array) or
+    /// [`end_map`](#method.end_map).
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcborstreamwriter.html
+    pub unsafe struct QCborStreamWriter as "QCborStreamWriterHolder"
+);
+
+impl QCborStreamWriter {
+    /// Creates a new writer with an empty buffer
+    pub fn new() -> QCborStreamWriter {
+        QCborStreamWriter::default()
+    }
+
+    /// Appends a boolean
+    pub fn append_bool(&mut self, value: bool) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", value as "bool"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->append(value);
+        #endif
+        })
+    }
+
+    /// Appends an integer
+    pub fn append_i64(&mut self, value: i64) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", value as "qint64"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->append(value);
+        #endif
+        })
+    }
+
+    /// Appends a floating point number
+    pub fn append_f64(&mut self, value: f64) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", value as "double"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->append(value);
+        #endif
+        })
+    }
+
+    /// Appends a text string
+    pub fn append_string(&mut self, value: &str) {
+        let value = QString::from(value);
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", value as "QString"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->append(value);
+        #endif
+        })
+    }
+
+    /// Appends a byte string
+    pub fn append_byte_array(&mut self, value: &QByteArray) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", value as "const QByteArray *"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->append(*value);
+        #endif
+        })
+    }
+
+    /// Appends the CBOR `null` value
+    pub fn append_null(&mut self) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->appendNull();
+        #endif
+        })
+    }
+
+    /// Appends the CBOR `undefined` value
+    pub fn append_undefined(&mut self) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->writer->appendUndefined();
+        #endif
+        })
+    }
+
+    /// Appends a whole QCborValue
+    pub fn append_value(&mut self, value: &QCborValue) {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", value as "const QCborValue *"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            value->toCbor(*self->writer);
+        #endif
+        })
+    }
+
+    /// Starts an array with the given number of elements, or of indeterminate length if None.
+    pub fn start_array(&mut self, len: Option<u64>) {
+        let (has_len, len) = (len.is_some(), len.unwrap_or(0));
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", has_len as "bool", len as "quint64"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            if (has_len)
+                self->writer->startArray(len);
+            else
+                self->writer->startArray();
+        #endif
+        })
+    }
+
+    /// Ends the array started with [`start_array`](#method.start_array).
+    /// Returns false if the number of elements does not match.
+    pub fn end_array(&mut self) -> bool {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->writer->endArray();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Starts a map with the given number of pairs, or of indeterminate length if None.
+    pub fn start_map(&mut self, len: Option<u64>) {
+        let (has_len, len) = (len.is_some(), len.unwrap_or(0));
+        cpp!(unsafe [self as "QCborStreamWriterHolder *", has_len as "bool", len as "quint64"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            if (has_len)
+                self->writer->startMap(len);
+            else
+                self->writer->startMap();
+        #endif
+        })
+    }
+
+    /// Ends the map started with [`start_map`](#method.start_map).
+    /// Returns false if the number of elements does not match.
+    pub fn end_map(&mut self) -> bool {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->writer->endMap();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Returns the encoded data written so far.
+    pub fn data(&self) -> QByteArray {
+        cpp!(unsafe [self as "QCborStreamWriterHolder *"] -> QByteArray as "QByteArray" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return *self->data;
+        #else
+            return {};
+        #endif
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QCborStreamReader`][class] class, reading a sequence of CBOR items
+    /// from an in-memory buffer.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcborstreamreader.html
+    pub unsafe struct QCborStreamReader as "QCborStreamReaderHolder"
+);
+
+impl QCborStreamReader {
+    /// Creates a reader for the given data
+    pub fn new(data: &[u8]) -> QCborStreamReader {
+        let data = QByteArray::from(data);
+        cpp!(unsafe [data as "QByteArray"] -> QCborStreamReader as "QCborStreamReaderHolder" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return QCborStreamReaderHolder(data);
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Appends more data to be read, for incremental decoding.
+    pub fn add_data(&mut self, data: &[u8]) {
+        let data = QByteArray::from(data);
+        cpp!(unsafe [self as "QCborStreamReaderHolder *", data as "QByteArray"] {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            self->reader->addData(data);
+            if (self->reader->lastError() == QCborError::EndOfFile)
+                self->reader->reparse();
+        #endif
+        })
+    }
+
+    /// Returns true if there is an item to read.
+    pub fn has_next(&self) -> bool {
+        cpp!(unsafe [self as "QCborStreamReaderHolder *"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            return self->reader->isValid();
+        #else
+            return {};
+        #endif
+        })
+    }
+
+    /// Reads the next complete item.
+    ///
+    /// Returns None when the end of the data was reached, or if the data is not valid CBOR.
+    pub fn read_value(&mut self) -> Option<QCborValue> {
+        let mut ok = false;
+        let value = cpp!(unsafe [self as "QCborStreamReaderHolder *", mut ok as "bool"] -> QCborValue as "QCborValue" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 12, 0)
+            if (!self->reader->isValid())
+                return QCborValue();
+            QCborValue value = QCborValue::fromCbor(*self->reader);
+            ok = self->reader->lastError() == QCborError::NoError;
+            return value;
+        #else
+            return {};
+        #endif
+        });
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
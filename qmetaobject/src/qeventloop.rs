@@ -0,0 +1,131 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::ops::{BitOr, BitOrAssign};
+
+use cpp::{cpp, cpp_class};
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QEventLoop>
+
+    struct QEventLoopHolder {
+        std::unique_ptr<QEventLoop> loop;
+
+        QEventLoopHolder() : loop(new QEventLoop) {}
+    };
+}}
+
+/// Wrapper around [`QEventLoop::ProcessEventsFlags`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qeventloop.html#ProcessEventsFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ProcessEventsFlags(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl ProcessEventsFlags {
+    pub const AllEvents: ProcessEventsFlags = ProcessEventsFlags(0x00);
+    pub const ExcludeUserInputEvents: ProcessEventsFlags = ProcessEventsFlags(0x01);
+    pub const ExcludeSocketNotifiers: ProcessEventsFlags = ProcessEventsFlags(0x02);
+    pub const WaitForMoreEvents: ProcessEventsFlags = ProcessEventsFlags(0x04);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: ProcessEventsFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ProcessEventsFlags {
+    type Output = ProcessEventsFlags;
+    fn bitor(self, other: ProcessEventsFlags) -> ProcessEventsFlags {
+        ProcessEventsFlags(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ProcessEventsFlags {
+    fn bitor_assign(&mut self, other: ProcessEventsFlags) {
+        self.0 |= other.0;
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QEventLoop`][class] class, to run a nested event loop.
+    ///
+    /// An application object (e.g. from [`QmlEngine::new`](../struct.QmlEngine.html#method.new))
+    /// must exist in the process.
+    ///
+    /// All the functions take `&self`, so the loop can be stopped with [`exit`](#method.exit)
+    /// or [`quit`](#method.quit) from a callback while [`exec`](#method.exec) is running.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qeventloop.html
+    pub unsafe struct QEventLoop as "QEventLoopHolder"
+);
+
+impl QEventLoop {
+    /// Creates a new event loop
+    pub fn new() -> QEventLoop {
+        QEventLoop::default()
+    }
+
+    /// Enters the event loop until [`exit`](#method.exit) is called, and returns the value
+    /// passed to it.
+    ///
+    /// Refer to the Qt documentation of QEventLoop::exec
+    pub fn exec(&self) -> i32 {
+        cpp!(unsafe [self as "QEventLoopHolder *"] -> i32 as "int" {
+            return self->loop->exec();
+        })
+    }
+
+    /// Refer to the Qt documentation of QEventLoop::exit
+    pub fn exit(&self, return_code: i32) {
+        cpp!(unsafe [self as "QEventLoopHolder *", return_code as "int"] {
+            self->loop->exit(return_code);
+        })
+    }
+
+    /// Refer to the Qt documentation of QEventLoop::quit
+    pub fn quit(&self) {
+        cpp!(unsafe [self as "QEventLoopHolder *"] {
+            self->loop->quit();
+        })
+    }
+
+    /// Refer to the Qt documentation of QEventLoop::isRunning
+    pub fn is_running(&self) -> bool {
+        cpp!(unsafe [self as "QEventLoopHolder *"] -> bool as "bool" {
+            return self->loop->isRunning();
+        })
+    }
+
+    /// Processes pending events. Returns true if any event was processed.
+    ///
+    /// Refer to the Qt documentation of QEventLoop::processEvents
+    pub fn process_events(&self, flags: ProcessEventsFlags) -> bool {
+        cpp!(unsafe [self as "QEventLoopHolder *", flags as "QEventLoop::ProcessEventsFlags"] -> bool as "bool" {
+            return self->loop->processEvents(flags);
+        })
+    }
+
+    /// Refer to the Qt documentation of QEventLoop::wakeUp
+    pub fn wakeup(&self) {
+        cpp!(unsafe [self as "QEventLoopHolder *"] {
+            self->loop->wakeUp();
+        })
+    }
+}
@@ -0,0 +1,87 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Internal items used from the code generated by the `qformat!` procedural macro.
+//!
+//! An argument of a `{}` placeholder is appended with
+//! `(&QFormatArg(&arg)).append_to(&mut out)`. Method resolution picks [`AppendQString`][] for
+//! strings that Qt can append directly, and falls back to the `Display` implementation through
+//! [`AppendDisplay`][] (which is implemented for a reference, so it comes second) otherwise.
+#![doc(hidden)]
+
+use std::fmt::Display;
+
+use cpp::cpp;
+
+use crate::{QByteArray, QString};
+
+cpp! {{
+    #include <QtCore/QString>
+}}
+
+pub struct QFormatArg<'a, T: ?Sized>(pub &'a T);
+
+pub trait AppendQString {
+    fn append_to(&self, out: &mut QString);
+}
+
+fn append_qstring(out: &mut QString, s: &QString) {
+    cpp!(unsafe [out as "QString *", s as "const QString *"] {
+        out->append(*s);
+    })
+}
+
+fn append_qbytearray(out: &mut QString, s: &QByteArray) {
+    cpp!(unsafe [out as "QString *", s as "const QByteArray *"] {
+        out->append(QString::fromUtf8(*s));
+    })
+}
+
+impl AppendQString for QFormatArg<'_, QString> {
+    fn append_to(&self, out: &mut QString) {
+        append_qstring(out, self.0)
+    }
+}
+
+impl AppendQString for QFormatArg<'_, &QString> {
+    fn append_to(&self, out: &mut QString) {
+        append_qstring(out, *self.0)
+    }
+}
+
+impl AppendQString for QFormatArg<'_, QByteArray> {
+    fn append_to(&self, out: &mut QString) {
+        append_qbytearray(out, self.0)
+    }
+}
+
+impl AppendQString for QFormatArg<'_, &QByteArray> {
+    fn append_to(&self, out: &mut QString) {
+        append_qbytearray(out, *self.0)
+    }
+}
+
+pub trait AppendDisplay {
+    fn append_to(&self, out: &mut QString);
+}
+
+impl<T: Display + ?Sized> AppendDisplay for &QFormatArg<'_, T> {
+    fn append_to(&self, out: &mut QString) {
+        // Writing to a QString never fails
+        std::fmt::Write::write_fmt(out, format_args!("{}", self.0)).unwrap();
+    }
+}
@@ -0,0 +1,209 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::ops::{BitOr, BitOrAssign};
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QBuffer>
+
+    struct QBufferHolder {
+        std::unique_ptr<QBuffer> buffer;
+
+        QBufferHolder() : buffer(new QBuffer) {}
+    };
+}}
+
+/// Wrapper around [`QIODevice::OpenMode`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qiodevice.html#OpenModeFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct OpenMode(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl OpenMode {
+    pub const NotOpen: OpenMode = OpenMode(0x0000);
+    pub const ReadOnly: OpenMode = OpenMode(0x0001);
+    pub const WriteOnly: OpenMode = OpenMode(0x0002);
+    pub const ReadWrite: OpenMode = OpenMode(0x0003);
+    pub const Append: OpenMode = OpenMode(0x0004);
+    pub const Truncate: OpenMode = OpenMode(0x0008);
+    pub const Text: OpenMode = OpenMode(0x0010);
+    pub const Unbuffered: OpenMode = OpenMode(0x0020);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: OpenMode) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for OpenMode {
+    type Output = OpenMode;
+    fn bitor(self, other: OpenMode) -> OpenMode {
+        OpenMode(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for OpenMode {
+    fn bitor_assign(&mut self, other: OpenMode) {
+        self.0 |= other.0;
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QBuffer`][class] class, an in-memory `QIODevice`.
+    ///
+    /// `QBuffer` implements `std::io::Read`, `std::io::Write` and `std::io::Seek`.
+    /// The buffer needs to be opened before it can be used.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qbuffer.html
+    pub unsafe struct QBuffer as "QBufferHolder"
+);
+
+impl QBuffer {
+    /// Creates an empty, closed, buffer
+    pub fn new() -> QBuffer {
+        QBuffer::default()
+    }
+
+    /// Refer to the Qt documentation of QBuffer::setData
+    pub fn set_data(&mut self, data: QByteArray) {
+        cpp!(unsafe [self as "QBufferHolder *", data as "QByteArray"] {
+            self->buffer->setData(data);
+        })
+    }
+
+    /// Refer to the Qt documentation of QBuffer::data
+    pub fn data(&self) -> &QByteArray {
+        unsafe {
+            &*cpp!([self as "QBufferHolder *"] -> *const QByteArray as "const QByteArray *" {
+                return &self->buffer->data();
+            })
+        }
+    }
+
+    /// Refer to the Qt documentation of QBuffer::open
+    pub fn open(&mut self, mode: OpenMode) -> bool {
+        cpp!(unsafe [self as "QBufferHolder *", mode as "QIODevice::OpenMode"] -> bool as "bool" {
+            return self->buffer->open(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QIODevice::isOpen
+    pub fn is_open(&self) -> bool {
+        cpp!(unsafe [self as "QBufferHolder *"] -> bool as "bool" {
+            return self->buffer->isOpen();
+        })
+    }
+
+    /// Refer to the Qt documentation of QBuffer::close
+    pub fn close(&mut self) {
+        cpp!(unsafe [self as "QBufferHolder *"] {
+            self->buffer->close();
+        })
+    }
+
+    /// Refer to the Qt documentation of QBuffer::pos
+    pub fn pos(&self) -> u64 {
+        cpp!(unsafe [self as "QBufferHolder *"] -> u64 as "qint64" {
+            return self->buffer->pos();
+        })
+    }
+
+    /// Returns a pointer to the underlying QBuffer, to be passed to Qt APIs expecting
+    /// a `QIODevice *`.
+    pub fn cpp_ptr(&mut self) -> *mut c_void {
+        cpp!(unsafe [self as "QBufferHolder *"] -> *mut c_void as "QBuffer *" {
+            return self->buffer.get();
+        })
+    }
+
+    fn error(&self) -> std::io::Error {
+        let message: String = cpp!(unsafe [self as "QBufferHolder *"] -> QString as "QString" {
+            return self->buffer->errorString();
+        })
+        .into();
+        std::io::Error::new(std::io::ErrorKind::Other, message)
+    }
+}
+
+impl Read for QBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len() as i64;
+        let ptr = buf.as_mut_ptr();
+        let read = cpp!(unsafe [self as "QBufferHolder *", ptr as "char *", len as "qint64"] -> i64 as "qint64" {
+            return self->buffer->read(ptr, len);
+        });
+        if read < 0 {
+            Err(self.error())
+        } else {
+            Ok(read as usize)
+        }
+    }
+}
+
+impl Write for QBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len() as i64;
+        let ptr = buf.as_ptr();
+        let written = cpp!(unsafe [self as "QBufferHolder *", ptr as "const char *", len as "qint64"] -> i64 as "qint64" {
+            return self->buffer->write(ptr, len);
+        });
+        if written < 0 {
+            Err(self.error())
+        } else {
+            Ok(written as usize)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for QBuffer {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let size = cpp!(unsafe [self as "QBufferHolder *"] -> i64 as "qint64" {
+            return self->buffer->size();
+        });
+        let new_pos = match pos {
+            SeekFrom::Start(p) => p as i64,
+            SeekFrom::Current(d) => self.pos() as i64 + d,
+            SeekFrom::End(d) => size + d,
+        };
+        if new_pos < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            ));
+        }
+        let ok = cpp!(unsafe [self as "QBufferHolder *", new_pos as "qint64"] -> bool as "bool" {
+            return self->buffer->seek(new_pos);
+        });
+        if ok {
+            Ok(new_pos as u64)
+        } else {
+            Err(self.error())
+        }
+    }
+}
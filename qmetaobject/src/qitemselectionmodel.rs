@@ -0,0 +1,193 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::ops::{BitOr, BitOrAssign};
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QItemSelectionModel>
+
+    struct QItemSelectionModelHolder {
+        std::unique_ptr<QItemSelectionModel> selection_model;
+
+        QItemSelectionModelHolder(QAbstractItemModel *model) : selection_model(new QItemSelectionModel(model)) {}
+    };
+}}
+
+/// Wrapper around [`QItemSelectionModel::SelectionFlags`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qitemselectionmodel.html#SelectionFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct SelectionCommand(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl SelectionCommand {
+    pub const NoUpdate: SelectionCommand = SelectionCommand(0x0000);
+    pub const Clear: SelectionCommand = SelectionCommand(0x0001);
+    pub const Select: SelectionCommand = SelectionCommand(0x0002);
+    pub const Deselect: SelectionCommand = SelectionCommand(0x0004);
+    pub const Toggle: SelectionCommand = SelectionCommand(0x0008);
+    pub const Current: SelectionCommand = SelectionCommand(0x0010);
+    pub const Rows: SelectionCommand = SelectionCommand(0x0020);
+    pub const Columns: SelectionCommand = SelectionCommand(0x0040);
+    pub const SelectCurrent: SelectionCommand = SelectionCommand(0x0012);
+    pub const ToggleCurrent: SelectionCommand = SelectionCommand(0x0018);
+    pub const ClearAndSelect: SelectionCommand = SelectionCommand(0x0003);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: SelectionCommand) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for SelectionCommand {
+    type Output = SelectionCommand;
+    fn bitor(self, other: SelectionCommand) -> SelectionCommand {
+        SelectionCommand(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for SelectionCommand {
+    fn bitor_assign(&mut self, other: SelectionCommand) {
+        self.0 |= other.0;
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QItemSelectionModel`][class] class.
+    ///
+    /// The selection model keeps track of the selected items of a model. The model must outlive
+    /// the selection model.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qitemselectionmodel.html
+    pub unsafe struct QItemSelectionModel as "QItemSelectionModelHolder"
+);
+
+impl QItemSelectionModel {
+    /// Creates a selection model for the given model.
+    ///
+    /// The C++ object of the model must already exist, so the model needs to be pinned.
+    pub fn new(model: &dyn QAbstractItemModel) -> QItemSelectionModel {
+        let model = model.get_cpp_object();
+        assert!(!model.is_null(), "The model must be pinned before creating a selection model");
+        unsafe { QItemSelectionModel::from_model_ptr(model) }
+    }
+
+    /// Creates a selection model for any C++ model, such as the one returned by
+    /// [`QStandardItemModel::cpp_ptr`](struct.QStandardItemModel.html#method.cpp_ptr).
+    ///
+    /// # Safety
+    ///
+    /// `model` must be a valid pointer to a QAbstractItemModel.
+    pub unsafe fn from_model_ptr(model: *mut c_void) -> QItemSelectionModel {
+        cpp!([model as "QAbstractItemModel *"] -> QItemSelectionModel as "QItemSelectionModelHolder" {
+            return QItemSelectionModelHolder(model);
+        })
+    }
+
+    /// Returns a pointer to the underlying QItemSelectionModel, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *"] -> *mut c_void as "QItemSelectionModel *" {
+            return self->selection_model.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::select
+    pub fn select(&mut self, index: &QModelIndex, command: SelectionCommand) {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *", index as "const QModelIndex *", command as "QItemSelectionModel::SelectionFlags"] {
+            self->selection_model->select(*index, command);
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::select
+    pub fn select_range(&mut self, selection: &QItemSelection, command: SelectionCommand) {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *", selection as "const QItemSelection *", command as "QItemSelectionModel::SelectionFlags"] {
+            self->selection_model->select(*selection, command);
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::setCurrentIndex
+    pub fn set_current_index(&mut self, index: &QModelIndex, command: SelectionCommand) {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *", index as "const QModelIndex *", command as "QItemSelectionModel::SelectionFlags"] {
+            self->selection_model->setCurrentIndex(*index, command);
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::clearSelection
+    pub fn clear_selection(&mut self) {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *"] {
+            self->selection_model->clearSelection();
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::selection
+    pub fn selection(&self) -> QItemSelection {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *"] -> QItemSelection as "QItemSelection" {
+            return self->selection_model->selection();
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::selectedIndexes
+    pub fn selected_indexes(&self) -> Vec<QModelIndex> {
+        self.selection().indexes()
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::isSelected
+    pub fn is_selected(&self, index: &QModelIndex) -> bool {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *", index as "const QModelIndex *"] -> bool as "bool" {
+            return self->selection_model->isSelected(*index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::hasSelection
+    pub fn has_selection(&self) -> bool {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *"] -> bool as "bool" {
+            return self->selection_model->hasSelection();
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::currentIndex
+    pub fn current_index(&self) -> QModelIndex {
+        cpp!(unsafe [self as "QItemSelectionModelHolder *"] -> QModelIndex as "QModelIndex" {
+            return self->selection_model->currentIndex();
+        })
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::selectionChanged
+    pub fn selection_changed_signal() -> Signal<fn(QItemSelection, QItemSelection)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QItemSelectionModel::selectionChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QItemSelectionModel::currentChanged
+    pub fn current_changed_signal() -> Signal<fn(QModelIndex, QModelIndex)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QItemSelectionModel::currentChanged;
+            }))
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QLibrary>
+    #include <QtCore/QPluginLoader>
+
+    struct QLibraryHolder {
+        std::unique_ptr<QLibrary> library;
+
+        QLibraryHolder(const QString &fileName)
+            : library(new QLibrary(fileName))
+        {}
+    };
+
+    struct QPluginLoaderHolder {
+        std::unique_ptr<QPluginLoader> loader;
+
+        QPluginLoaderHolder(const QString &fileName)
+            : loader(new QPluginLoader(fileName))
+        {}
+    };
+}}
+
+cpp_class!(
+    /// Wrapper for [`QLibrary`][class] class, which loads shared libraries at runtime.
+    ///
+    /// Dropping the `QLibrary` does not unload the library, call [`unload`](#method.unload)
+    /// explicitly if needed.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlibrary.html
+    pub unsafe struct QLibrary as "QLibraryHolder"
+);
+
+impl QLibrary {
+    /// Creates a QLibrary for the given file name.
+    ///
+    /// Refer to the Qt documentation of QLibrary::QLibrary(const QString &fileName)
+    pub fn new(filename: &str) -> QLibrary {
+        let filename = QString::from(filename);
+        cpp!(unsafe [filename as "QString"] -> QLibrary as "QLibraryHolder" {
+            return QLibraryHolder(filename);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLibrary::load
+    pub fn load(&mut self) -> bool {
+        cpp!(unsafe [self as "QLibraryHolder *"] -> bool as "bool" {
+            return self->library->load();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLibrary::isLoaded
+    pub fn is_loaded(&self) -> bool {
+        cpp!(unsafe [self as "QLibraryHolder *"] -> bool as "bool" {
+            return self->library->isLoaded();
+        })
+    }
+
+    /// Returns the address of the exported symbol, or a null pointer if the symbol could not
+    /// be resolved. The library is loaded if needed.
+    ///
+    /// Refer to the Qt documentation of QLibrary::resolve
+    pub fn resolve(&mut self, symbol: &str) -> *mut c_void {
+        let symbol = match CString::new(symbol) {
+            Ok(symbol) => symbol,
+            Err(_) => return std::ptr::null_mut(),
+        };
+        let symbol = symbol.as_ptr();
+        cpp!(unsafe [self as "QLibraryHolder *", symbol as "const char *"] -> *mut c_void as "void *" {
+            return reinterpret_cast<void *>(self->library->resolve(symbol));
+        })
+    }
+
+    /// Refer to the Qt documentation of QLibrary::unload
+    pub fn unload(&mut self) -> bool {
+        cpp!(unsafe [self as "QLibraryHolder *"] -> bool as "bool" {
+            return self->library->unload();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLibrary::errorString
+    pub fn error_string(&self) -> QString {
+        cpp!(unsafe [self as "QLibraryHolder *"] -> QString as "QString" {
+            return self->library->errorString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLibrary::fileName
+    pub fn file_name(&self) -> QString {
+        cpp!(unsafe [self as "QLibraryHolder *"] -> QString as "QString" {
+            return self->library->fileName();
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper for [`QPluginLoader`][class] class, which loads Qt plugins at runtime.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qpluginloader.html
+    pub unsafe struct QPluginLoader as "QPluginLoaderHolder"
+);
+
+impl QPluginLoader {
+    /// Creates a QPluginLoader for the given file name.
+    ///
+    /// Refer to the Qt documentation of QPluginLoader::QPluginLoader(const QString &fileName)
+    pub fn new(filename: &str) -> QPluginLoader {
+        let filename = QString::from(filename);
+        cpp!(unsafe [filename as "QString"] -> QPluginLoader as "QPluginLoaderHolder" {
+            return QPluginLoaderHolder(filename);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPluginLoader::load
+    pub fn load(&mut self) -> bool {
+        cpp!(unsafe [self as "QPluginLoaderHolder *"] -> bool as "bool" {
+            return self->loader->load();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPluginLoader::isLoaded
+    pub fn is_loaded(&self) -> bool {
+        cpp!(unsafe [self as "QPluginLoaderHolder *"] -> bool as "bool" {
+            return self->loader->isLoaded();
+        })
+    }
+
+    /// Returns a pointer to the root QObject of the plugin, loading the plugin if needed.
+    /// Returns None if the plugin could not be loaded.
+    ///
+    /// Refer to the Qt documentation of QPluginLoader::instance
+    pub fn instance(&mut self) -> Option<*mut c_void> {
+        let obj = cpp!(unsafe [self as "QPluginLoaderHolder *"] -> *mut c_void as "QObject *" {
+            return self->loader->instance();
+        });
+        if obj.is_null() {
+            None
+        } else {
+            Some(obj)
+        }
+    }
+
+    /// Casts the root object of the plugin to the interface with the given IID, in the same
+    /// way as `qobject_cast` does for interfaces declared with `Q_DECLARE_INTERFACE`.
+    /// Returns None if the plugin could not be loaded or does not implement the interface.
+    ///
+    /// The returned pointer points to the C++ interface, it is only valid while the plugin is
+    /// loaded.
+    ///
+    /// Refer to the Qt documentation of QObject::qt_metacast and Q_DECLARE_INTERFACE
+    pub fn instance_interface(&mut self, iid: &str) -> Option<*mut c_void> {
+        let obj = self.instance()?;
+        let iid = CString::new(iid).ok()?;
+        let iid = iid.as_ptr();
+        let interface = cpp!(unsafe [obj as "QObject *", iid as "const char *"] -> *mut c_void as "void *" {
+            // The cast is done by name, so it also works for objects and interfaces
+            // which live in another shared library
+            return obj->qt_metacast(iid);
+        });
+        if interface.is_null() {
+            None
+        } else {
+            Some(interface)
+        }
+    }
+
+    /// Refer to the Qt documentation of QPluginLoader::unload
+    pub fn unload(&mut self) -> bool {
+        cpp!(unsafe [self as "QPluginLoaderHolder *"] -> bool as "bool" {
+            return self->loader->unload();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPluginLoader::errorString
+    pub fn error_string(&self) -> QString {
+        cpp!(unsafe [self as "QPluginLoaderHolder *"] -> QString as "QString" {
+            return self->loader->errorString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPluginLoader::metaData
+    pub fn meta_data(&self) -> QJsonObject {
+        cpp!(unsafe [self as "QPluginLoaderHolder *"] -> QJsonObject as "QJsonObject" {
+            return self->loader->metaData();
+        })
+    }
+}
@@ -15,6 +15,10 @@ NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FO
 OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
+use std::ptr::NonNull;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
 use cpp::cpp;
 
 use super::*;
@@ -118,7 +122,6 @@ fn register_metatype_common<T: QMetaType>(
 ) -> i32 {
     use std::any::TypeId;
     use std::collections::{HashMap, HashSet};
-    use std::sync::Mutex;
 
     lazy_static! {
         static ref HASHMAP: Mutex<HashMap<TypeId, (i32, HashSet<CString>)>> =
@@ -406,6 +409,19 @@ pub trait QMetaType: Clone + Default + 'static {
     const CONVERSION_FROM_STRING: Option<fn(&QString) -> Self> = None;
 }
 
+/// Registers a Rust type to the Qt meta type system, and returns its id.
+///
+/// This is the equivalent of `qRegisterMetaType<T>()`. Once registered, values of the type can
+/// be stored in a QVariant with `QMetaType::to_qvariant` and passed as arguments of signals.
+/// The [`QMetaType`] trait provides what Qt needs: the name, and the construction, copy and
+/// destruction through `Default`, `Clone` and `Drop`. Types deriving from QGadget implement it
+/// automatically.
+///
+/// Registering the same type several times returns the same id.
+pub fn register_meta_type<T: QMetaType>() -> i32 {
+    T::register(None)
+}
+
 #[doc(hidden)]
 #[cfg(qt_6_0)]
 /// Return the internal pointer to `QtPrivate::QMetaTypeInterface`
@@ -421,6 +437,56 @@ pub fn qmetatype_interface_ptr<T: PropertyType>(name: &CStr) -> *const c_void {
     })
 }
 
+/// Returns the meta object of the type registered with the given name, if any.
+///
+/// For QObject types, the name is the name of the pointer type (e.g. `"QObject*"`).
+/// Objects deriving from QObject and gadgets have their meta type registered by
+/// `qml_register_type` or `QMetaType::register`.
+///
+/// Wrapper around `QMetaType::metaObjectForType`.
+pub fn meta_object_for_type_name(name: &str) -> Option<&'static QMetaObject> {
+    let name = CString::new(name).ok()?;
+    let name = name.as_ptr();
+    let meta = cpp!(unsafe [name as "const char *"] -> *const QMetaObject as "const QMetaObject *" {
+    #if QT_VERSION >= QT_VERSION_CHECK(6,0,0)
+        return QMetaType::fromName(name).metaObject();
+    #else
+        return QMetaType::metaObjectForType(QMetaType::type(name));
+    #endif
+    });
+    unsafe { meta.as_ref() }
+}
+
+/// Creates a new object of the type described by this meta object, using the constructor
+/// declared with `Q_INVOKABLE` whose parameters match the types of `args`.
+///
+/// Returns a pointer to the new QObject, which is owned by the caller, or None if there is no
+/// matching constructor. At most 10 arguments are supported.
+///
+/// Objects implemented in Rust with `#[derive(QObject)]` have no invokable constructor, so
+/// this is meant to instantiate C++ classes, for example from a plugin.
+///
+/// Wrapper around `QMetaObject::newInstance`.
+pub fn meta_object_new_instance(meta: &QMetaObject, args: &[QVariant]) -> Option<NonNull<c_void>> {
+    if args.len() > 10 {
+        return None;
+    }
+    let meta: *const QMetaObject = meta;
+    let args_ptr = args.as_ptr();
+    let args_len = args.len();
+    let obj = cpp!(unsafe [
+        meta as "const QMetaObject *",
+        args_ptr as "const QVariant *",
+        args_len as "size_t"
+    ] -> *mut c_void as "QObject *" {
+        QGenericArgument a[10];
+        for (size_t i = 0; i < args_len; ++i)
+            a[i] = QGenericArgument(args_ptr[i].typeName(), args_ptr[i].constData());
+        return meta->newInstance(a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7], a[8], a[9]);
+    });
+    NonNull::new(obj)
+}
+
 /// QGadget are automatically QMetaType
 impl<T: QGadget> QMetaType for T
 where
@@ -471,6 +537,7 @@ qdeclare_builtin_metatype! {QDate => 14}
 qdeclare_builtin_metatype! {QTime => 15}
 qdeclare_builtin_metatype! {QDateTime => 16}
 qdeclare_builtin_metatype! {QUrl => 17}
+qdeclare_builtin_metatype! {QRect => 19}
 qdeclare_builtin_metatype! {QRectF => 20}
 qdeclare_builtin_metatype! {QSize => 21}
 qdeclare_builtin_metatype! {QSizeF => 22}
@@ -488,7 +555,9 @@ impl QMetaType for QVariant {
     }
 }
 qdeclare_builtin_metatype! {QModelIndex => 42}
+qdeclare_builtin_metatype! {QFont => if cfg!(qt_6_0) { 0x1000 } else { 64 }}
 qdeclare_builtin_metatype! {QPixmap => if cfg!(qt_6_0) { 0x1001 } else { 65 }}
+qdeclare_builtin_metatype! {QIcon => if cfg!(qt_6_0) { 0x1005 } else { 69 }}
 qdeclare_builtin_metatype! {QColor => if cfg!(qt_6_0) { 0x1003 } else { 67 }}
 qdeclare_builtin_metatype! {QImage => if cfg!(qt_6_0) { 0x1006 } else { 70 }}
 
@@ -509,6 +578,14 @@ pub trait PropertyType {
     // Note: this is &mut self because of the lazy initialization of the QObject* for the QObject impl
     unsafe fn pass_to_qt(&mut self, a: *mut c_void);
     unsafe fn read_from_qt(a: *const c_void) -> Self;
+    /// Called when the property is written to without a setter. The default implementation
+    /// replaces the value with the one from `read_from_qt`.
+    unsafe fn write_from_qt(&mut self, a: *const c_void)
+    where
+        Self: Sized,
+    {
+        *self = Self::read_from_qt(a);
+    }
 }
 
 impl<T: QMetaType> PropertyType for T
@@ -532,6 +609,39 @@ where
     }
 }
 
+/// A property of type `Arc<Mutex<T>>` is seen by QML as a property of type `T`, so its value
+/// can be shared with other threads. Reading the property locks the mutex and clones the value;
+/// writing it locks the mutex and assigns the value, without replacing the `Arc`.
+///
+/// The mutex is locked while Qt reads or writes the property. Do not emit the `NOTIFY` signal
+/// while holding the lock: a binding re-evaluated by the signal reads the property on the same
+/// thread, which would deadlock.
+impl<T> PropertyType for Arc<Mutex<T>>
+where
+    T: QMetaType,
+{
+    fn register_type(_name: &CStr) -> i32 {
+        <T as QMetaType>::register(None)
+    }
+
+    unsafe fn pass_to_qt(&mut self, a: *mut c_void) {
+        let r = a as *mut T;
+        if !r.is_null() {
+            *r = self.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        }
+    }
+
+    unsafe fn read_from_qt(a: *const c_void) -> Self {
+        let r = a as *const T;
+        Arc::new(Mutex::new((*r).clone()))
+    }
+
+    unsafe fn write_from_qt(&mut self, a: *const c_void) {
+        let r = a as *const T;
+        *self.lock().unwrap_or_else(|e| e.into_inner()) = (*r).clone();
+    }
+}
+
 impl<T> PropertyType for RefCell<T>
 where
     T: QObject,
@@ -551,6 +661,29 @@ where
     }
 }
 
+/// A property of type `Rc<RefCell<T>>` is seen by QML as a pointer to the QObject `T`, like a
+/// property of type `RefCell<T>`, but the object can also be shared with other Rust code.
+/// The C++ object is created on first access.
+impl<T> PropertyType for Rc<RefCell<T>>
+where
+    T: QObject,
+{
+    fn register_type(_name: &CStr) -> i32 {
+        register_metatype_qobject::<T>()
+    }
+
+    unsafe fn pass_to_qt(&mut self, a: *mut c_void) {
+        // The content of a Rc never moves
+        let pinned = QObjectPinned::new(&**self);
+        let r = a as *mut *const c_void;
+        *r = pinned.get_or_create_cpp_object()
+    }
+
+    unsafe fn read_from_qt(_a: *const c_void) -> Self {
+        panic!("Cannot write into an Object property");
+    }
+}
+
 impl<T> PropertyType for QPointer<T>
 where
     T: QObject,
@@ -0,0 +1,288 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Bindings for QStandardItemModel and QStandardItem.
+use std::marker::PhantomData;
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtGui/QStandardItemModel>
+
+    struct QStandardItemHolder {
+        std::unique_ptr<QStandardItem> item;
+
+        QStandardItemHolder() : item(new QStandardItem) {}
+    };
+
+    struct QStandardItemModelHolder {
+        std::unique_ptr<QStandardItemModel> model;
+
+        QStandardItemModelHolder() : model(new QStandardItemModel) {}
+        QStandardItemModelHolder(int rows, int columns) : model(new QStandardItemModel(rows, columns)) {}
+    };
+}}
+
+cpp_class!(
+    /// Wrapper around [`QStandardItem`][class] class, which is not yet inserted in a model.
+    ///
+    /// Once inserted in a [`QStandardItemModel`](struct.QStandardItemModel.html), the model
+    /// takes ownership of the item, which can then be accessed through a
+    /// [`QStandardItemRef`](struct.QStandardItemRef.html).
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qstandarditem.html
+    pub unsafe struct QStandardItem as "QStandardItemHolder"
+);
+
+impl QStandardItem {
+    /// Creates an empty item
+    pub fn new() -> QStandardItem {
+        QStandardItem::default()
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::text
+    pub fn text(&self) -> QString {
+        cpp!(unsafe [self as "QStandardItemHolder *"] -> QString as "QString" {
+            return self->item->text();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::setText
+    pub fn set_text(&mut self, text: QString) {
+        cpp!(unsafe [self as "QStandardItemHolder *", text as "QString"] {
+            self->item->setText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::data
+    pub fn data(&self, role: i32) -> QVariant {
+        cpp!(unsafe [self as "QStandardItemHolder *", role as "int"] -> QVariant as "QVariant" {
+            return self->item->data(role);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::setData
+    pub fn set_data(&mut self, value: QVariant, role: i32) {
+        cpp!(unsafe [self as "QStandardItemHolder *", value as "QVariant", role as "int"] {
+            self->item->setData(value, role);
+        })
+    }
+
+    /// Gives up the ownership of the item, to be used by the functions transferring it to a model.
+    fn into_raw(mut self) -> *mut c_void {
+        let holder = &mut self;
+        cpp!(unsafe [holder as "QStandardItemHolder *"] -> *mut c_void as "QStandardItem *" {
+            return holder->item.release();
+        })
+    }
+}
+
+impl From<QString> for QStandardItem {
+    fn from(text: QString) -> Self {
+        let mut item = QStandardItem::new();
+        item.set_text(text);
+        item
+    }
+}
+
+impl From<&str> for QStandardItem {
+    fn from(text: &str) -> Self {
+        QString::from(text).into()
+    }
+}
+
+/// A reference to a [`QStandardItem`][class] owned by a
+/// [`QStandardItemModel`](struct.QStandardItemModel.html).
+///
+/// [class]: https://doc.qt.io/qt-5/qstandarditem.html
+#[derive(Clone, Copy)]
+pub struct QStandardItemRef<'a> {
+    item: *mut c_void,
+    _model: PhantomData<&'a QStandardItemModel>,
+}
+
+impl<'a> QStandardItemRef<'a> {
+    fn from_raw(item: *mut c_void) -> Option<Self> {
+        if item.is_null() {
+            None
+        } else {
+            Some(QStandardItemRef { item, _model: PhantomData })
+        }
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::text
+    pub fn text(&self) -> QString {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *"] -> QString as "QString" {
+            return item->text();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::setText
+    pub fn set_text(&self, text: QString) {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *", text as "QString"] {
+            item->setText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::data
+    pub fn data(&self, role: i32) -> QVariant {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *", role as "int"] -> QVariant as "QVariant" {
+            return item->data(role);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::setData
+    pub fn set_data(&self, value: QVariant, role: i32) {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *", value as "QVariant", role as "int"] {
+            item->setData(value, role);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::row
+    pub fn row(&self) -> i32 {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *"] -> i32 as "int" {
+            return item->row();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::column
+    pub fn column(&self) -> i32 {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *"] -> i32 as "int" {
+            return item->column();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItem::index
+    pub fn index(&self) -> QModelIndex {
+        let item = self.item;
+        cpp!(unsafe [item as "QStandardItem *"] -> QModelIndex as "QModelIndex" {
+            return item->index();
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QStandardItemModel`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qstandarditemmodel.html
+    pub unsafe struct QStandardItemModel as "QStandardItemModelHolder"
+);
+
+impl QStandardItemModel {
+    /// Creates a model with the given number of empty rows and columns.
+    pub fn new(rows: i32, columns: i32) -> QStandardItemModel {
+        cpp!(unsafe [rows as "int", columns as "int"] -> QStandardItemModel as "QStandardItemModelHolder" {
+            return QStandardItemModelHolder(rows, columns);
+        })
+    }
+
+    /// Returns a pointer to the underlying QStandardItemModel, which can be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html), or given to a view.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QStandardItemModelHolder *"] -> *mut c_void as "QStandardItemModel *" {
+            return self->model.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItemModel::item
+    pub fn item(&self, row: i32, column: i32) -> Option<QStandardItemRef<'_>> {
+        QStandardItemRef::from_raw(
+            cpp!(unsafe [self as "QStandardItemModelHolder *", row as "int", column as "int"]
+                    -> *mut c_void as "QStandardItem *" {
+                return self->model->item(row, column);
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QStandardItemModel::setItem
+    pub fn set_item(&mut self, row: i32, column: i32, item: QStandardItem) {
+        let item = item.into_raw();
+        cpp!(unsafe [self as "QStandardItemModelHolder *", row as "int", column as "int", item as "QStandardItem *"] {
+            self->model->setItem(row, column, item);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItemModel::appendRow
+    pub fn append_row(&mut self, items: Vec<QStandardItem>) {
+        let mut row = QList_QStandardItem::default();
+        for item in items {
+            let item = item.into_raw();
+            cpp!(unsafe [mut row as "QList<QStandardItem *>", item as "QStandardItem *"] {
+                row.append(item);
+            })
+        }
+        cpp!(unsafe [self as "QStandardItemModelHolder *", row as "QList<QStandardItem *>"] {
+            self->model->appendRow(row);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItemModel::rowCount
+    pub fn row_count(&self) -> i32 {
+        cpp!(unsafe [self as "QStandardItemModelHolder *"] -> i32 as "int" {
+            return self->model->rowCount();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItemModel::columnCount
+    pub fn column_count(&self) -> i32 {
+        cpp!(unsafe [self as "QStandardItemModelHolder *"] -> i32 as "int" {
+            return self->model->columnCount();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStandardItemModel::clear
+    pub fn clear(&mut self) {
+        cpp!(unsafe [self as "QStandardItemModelHolder *"] {
+            self->model->clear();
+        })
+    }
+
+    /// Returns the items whose text exactly matches `text`, in the first column.
+    ///
+    /// Refer to the Qt documentation of QStandardItemModel::findItems
+    pub fn find_items(&self, text: &str) -> Vec<QStandardItemRef<'_>> {
+        let text = QString::from(text);
+        let found = cpp!(unsafe [self as "QStandardItemModelHolder *", text as "QString"]
+                -> QList_QStandardItem as "QList<QStandardItem *>" {
+            return self->model->findItems(text);
+        });
+        let len = cpp!(unsafe [found as "QList<QStandardItem *>"] -> usize as "size_t" {
+            return found.size();
+        });
+        (0..len)
+            .filter_map(|i| {
+                QStandardItemRef::from_raw(
+                    cpp!(unsafe [found as "QList<QStandardItem *>", i as "size_t"]
+                            -> *mut c_void as "QStandardItem *" {
+                        return found.at(i);
+                    }),
+                )
+            })
+            .collect()
+    }
+}
+
+cpp_class!(unsafe struct QList_QStandardItem as "QList<QStandardItem *>");
@@ -51,10 +51,20 @@ cpp! {{
         std::unique_ptr<QQmlApplicationEngine> engine;
         std::unique_ptr<QQuickView> view;
 
+        /// Names of the properties set on the root context, in order.
+        QStringList contextPropertyNames;
+
         QmlEngineHolder(int &argc, char **argv)
             : app(new QApplication(argc, argv))
             , engine(new QQmlApplicationEngine())
         {}
+
+        template <typename T>
+        void setContextProperty(const QString &name, const T &value) {
+            engine->rootContext()->setContextProperty(name, value);
+            if (!contextPropertyNames.contains(name))
+                contextPropertyNames.append(name);
+        }
     };
 
 // Equivalent with QMetaObject::inherits(), for 5.6 and lower.
@@ -78,6 +88,39 @@ cpp! {{
 #endif
 }}
 
+cpp! {{
+    // Owns a Box<dyn Fn(&QQmlError)> given to QmlEngine::on_warning
+    struct RustWarningCallback {
+        TraitObject callback;
+
+        explicit RustWarningCallback(TraitObject callback) : callback(callback) {}
+        RustWarningCallback(const RustWarningCallback &) = delete;
+        RustWarningCallback &operator=(const RustWarningCallback &) = delete;
+
+        void operator()(const QList<QQmlError> &warnings) const {
+            auto callback = this->callback;
+            for (const auto &warning : warnings) {
+                auto warning_ptr = &warning;
+                rust!(RustWarningCallback_call [
+                    callback: *const dyn Fn(&QQmlError) as "TraitObject",
+                    warning_ptr: &QQmlError as "const QQmlError *"
+                ] {
+                    (*callback)(warning_ptr)
+                });
+            }
+        }
+
+        ~RustWarningCallback() {
+            auto callback = this->callback;
+            rust!(RustWarningCallback_drop [
+                callback: *mut dyn Fn(&QQmlError) as "TraitObject"
+            ] {
+                let _ = Box::from_raw(callback);
+            });
+        }
+    };
+}}
+
 cpp_class!(
     /// Wrap a Qt Application and a QmlEngine
     ///
@@ -169,7 +212,7 @@ impl QmlEngine {
     /// Sets a property for this QML context (calls QQmlEngine::rootContext()->setContextProperty)
     pub fn set_property(&mut self, name: QString, value: QVariant) {
         cpp!(unsafe [self as "QmlEngineHolder *", name as "QString", value as "QVariant"] {
-            self->engine->rootContext()->setContextProperty(name, value);
+            self->setContextProperty(name, value);
         })
     }
 
@@ -183,10 +226,37 @@ impl QmlEngine {
     ) {
         let obj_ptr = obj.get_or_create_cpp_object();
         cpp!(unsafe [self as "QmlEngineHolder *", name as "QString", obj_ptr as "QObject *"] {
-            self->engine->rootContext()->setContextProperty(name, obj_ptr);
+            self->setContextProperty(name, obj_ptr);
+        })
+    }
+
+    /// Returns the value of a property of the root context, or an invalid QVariant if there is
+    /// none. A property set with `set_object_property` contains a pointer to the object.
+    ///
+    /// Refer to the Qt documentation of QQmlContext::contextProperty
+    pub fn get_context_property(&self, name: &str) -> QVariant {
+        let name = QString::from(name);
+        cpp!(unsafe [self as "QmlEngineHolder *", name as "QString"] -> QVariant as "QVariant" {
+            return self->engine->rootContext()->contextProperty(name);
         })
     }
 
+    /// Returns the names of the properties set on the root context with `set_property`,
+    /// `set_object_property` or a `QmlEngineBuilder`, in the order they were first set.
+    pub fn context_object_names(&self) -> Vec<String> {
+        let count = cpp!(unsafe [self as "QmlEngineHolder *"] -> usize as "size_t" {
+            return self->contextPropertyNames.size();
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [self as "QmlEngineHolder *", i as "size_t"] -> QString as "QString" {
+                    return self->contextPropertyNames.at(i);
+                })
+                .to_string()
+            })
+            .collect()
+    }
+
     pub fn invoke_method(&mut self, name: QByteArray, args: &[QVariant]) -> QVariant {
         let args_size = args.len();
         let args_ptr = args.as_ptr();
@@ -231,6 +301,22 @@ impl QmlEngine {
         })
     }
 
+    /// Returns the JavaScript global object of the engine, to which properties visible to all
+    /// the scripts can be added. Refer to the Qt documentation of QJSEngine::globalObject
+    pub fn global_object(&self) -> QJSValue {
+        cpp!(unsafe [self as "QmlEngineHolder *"] -> QJSValue as "QJSValue" {
+            return self->engine->globalObject();
+        })
+    }
+
+    /// Converts a QVariant to a JavaScript value of this engine. Lists and maps become arrays
+    /// and objects. Refer to the Qt documentation of QJSEngine::toScriptValue
+    pub fn to_script_value(&self, value: QVariant) -> QJSValue {
+        cpp!(unsafe [self as "QmlEngineHolder *", value as "QVariant"] -> QJSValue as "QJSValue" {
+            return self->engine->toScriptValue(value);
+        })
+    }
+
     /// Adds an import path for this QML engine (calls QQmlEngine::addImportPath)
     pub fn add_import_path(&mut self, path: QString) {
         cpp!(unsafe [self as "QmlEngineHolder *", path as "QString"] {
@@ -238,6 +324,19 @@ impl QmlEngine {
         })
     }
 
+    /// Calls `callback` for each warning reported by the engine, for example errors in bindings.
+    ///
+    /// The warnings are still printed, unless `QQmlEngine::setOutputWarningsToStandardError` is
+    /// set to false. Refer to the Qt documentation of QQmlEngine::warnings
+    pub fn on_warning(&mut self, callback: impl Fn(&QQmlError) + 'static) {
+        let callback: *mut dyn Fn(&QQmlError) = Box::into_raw(Box::new(callback));
+        cpp!(unsafe [self as "QmlEngineHolder *", callback as "TraitObject"] {
+            auto shared = std::make_shared<RustWarningCallback>(callback);
+            QObject::connect(self->engine.get(), &QQmlEngine::warnings, self->engine.get(),
+                             [shared](const QList<QQmlError> &warnings) { (*shared)(warnings); });
+        })
+    }
+
     /// Returns a pointer to the C++ object. The pointer is of the type `QQmlEngine *` in C++.
     pub fn cpp_ptr(&self) -> *mut c_void {
         cpp!(unsafe [self as "QmlEngineHolder *"] -> *mut c_void as "QQmlEngine *" {
@@ -246,6 +345,85 @@ impl QmlEngine {
     }
 }
 
+/// Value of a context property given to a `QmlEngineBuilder`
+enum ContextPropertyValue<'a> {
+    /// The C++ object of a `QObjectPinned<'a, T>`
+    Object(*mut c_void, std::marker::PhantomData<&'a ()>),
+    Variant(QVariant),
+}
+
+/// Builder for a [`QmlEngine`](struct.QmlEngine.html), which lists at construction time the
+/// objects and values available to QML as properties of the root context.
+///
+/// The builder borrows the objects given to [`context_object`](#method.context_object) for
+/// the lifetime `'a`, so they cannot be dropped before the engine is built.
+///
+/// ```no_run
+/// # use qmetaobject::*;
+/// #[derive(QObject, Default)]
+/// struct Backend {
+///     base: qt_base_class!(trait QObject),
+/// }
+///
+/// let backend = QObjectBox::new(Backend::default());
+/// let mut engine = QmlEngineBuilder::new()
+///     .context_object("backend", backend.pinned())
+///     .context_property("appName", QString::from("Demo").into())
+///     .build();
+/// engine.load_file("main.qml".into());
+/// engine.exec();
+/// ```
+#[derive(Default)]
+pub struct QmlEngineBuilder<'a> {
+    properties: Vec<(QString, ContextPropertyValue<'a>)>,
+}
+
+impl<'a> QmlEngineBuilder<'a> {
+    /// Creates a builder without any context property
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exposes `obj` to QML under the given name, like `QmlEngine::set_object_property`.
+    ///
+    /// This creates the C++ object.
+    pub fn context_object<T: QObject + Sized>(
+        mut self,
+        name: &str,
+        obj: QObjectPinned<'a, T>,
+    ) -> Self {
+        let obj_ptr = obj.get_or_create_cpp_object();
+        self.properties
+            .push((name.into(), ContextPropertyValue::Object(obj_ptr, std::marker::PhantomData)));
+        self
+    }
+
+    /// Exposes `value` to QML under the given name, like `QmlEngine::set_property`.
+    pub fn context_property(mut self, name: &str, value: QVariant) -> Self {
+        self.properties.push((name.into(), ContextPropertyValue::Variant(value)));
+        self
+    }
+
+    /// Creates the engine, with all the context properties set.
+    ///
+    /// Same as `QmlEngine::new`, this panics if another engine exists.
+    pub fn build(self) -> QmlEngine {
+        let mut engine = QmlEngine::new();
+        for (name, value) in self.properties {
+            match value {
+                ContextPropertyValue::Object(obj_ptr, _) => {
+                    let engine = &mut engine;
+                    cpp!(unsafe [engine as "QmlEngineHolder *", name as "QString", obj_ptr as "QObject *"] {
+                        engine->setContextProperty(name, obj_ptr);
+                    })
+                }
+                ContextPropertyValue::Variant(value) => engine.set_property(name, value),
+            }
+        }
+        engine
+    }
+}
+
 /// Bindings to a QQuickView
 pub struct QQuickView {
     engine: QmlEngine,
@@ -379,6 +557,20 @@ impl QmlComponent {
         })
     }
 
+    /// Refer to the Qt documentation of QQmlComponent::errors
+    pub fn errors(&self) -> Vec<QQmlError> {
+        let count = cpp!(unsafe [self as "QQmlComponentHolder *"] -> usize as "size_t" {
+            return self->component->errors().size();
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [self as "QQmlComponentHolder *", i as "size_t"] -> QQmlError as "QQmlError" {
+                    return self->component->errors().at(i);
+                })
+            })
+            .collect()
+    }
+
     /// See Qt documentation for QQmlComponent::statusChanged
     pub fn status_changed_signal() -> Signal<fn(status: ComponentStatus)> {
         unsafe {
@@ -389,6 +581,334 @@ impl QmlComponent {
     }
 }
 
+/// See QQmlIncubator::IncubationMode
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IncubationMode {
+    Asynchronous,
+    AsynchronousIfNested,
+    Synchronous,
+}
+
+/// See QQmlIncubator::Status
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IncubationStatus {
+    Null,
+    Ready,
+    Loading,
+    Error,
+}
+
+cpp! {{
+    /// QQmlIncubator which forwards setInitialState and statusChanged to Rust closures.
+    struct RustQmlIncubator : QQmlIncubator {
+        /// Wrapped Box<dyn FnMut(*mut c_void)>, called at most once, if any.
+        TraitObject initial_state = {};
+        /// Wrapped Box<dyn Fn(IncubationStatus)>, if any.
+        TraitObject status_changed = {};
+
+        explicit RustQmlIncubator(IncubationMode mode) : QQmlIncubator(mode) {}
+
+        void setInitialState(QObject *object) override {
+            auto initial_state = this->initial_state;
+            this->initial_state = {};
+            if (!initial_state.isValid())
+                return;
+            rust!(RustQmlIncubator_setInitialState [
+                initial_state: *mut dyn FnMut(*mut c_void) as "TraitObject",
+                object: *mut c_void as "QObject *"
+            ] {
+                let mut initial_state = Box::from_raw(initial_state);
+                initial_state(object);
+            });
+        }
+
+        void statusChanged(Status status) override {
+            if (!status_changed.isValid())
+                return;
+            auto status_changed = this->status_changed;
+            rust!(RustQmlIncubator_statusChanged [
+                status_changed: *const dyn Fn(IncubationStatus) as "TraitObject",
+                status: IncubationStatus as "QQmlIncubator::Status"
+            ] {
+                (*status_changed)(status)
+            });
+        }
+
+        void setInitialStateFunction(TraitObject f) {
+            auto old = initial_state;
+            initial_state = f;
+            if (old.isValid()) {
+                rust!(RustQmlIncubator_dropInitialState [
+                    old: *mut dyn FnMut(*mut c_void) as "TraitObject"
+                ] {
+                    let _ = Box::from_raw(old);
+                });
+            }
+        }
+
+        void setStatusChangedFunction(TraitObject f) {
+            auto old = status_changed;
+            status_changed = f;
+            if (old.isValid()) {
+                rust!(RustQmlIncubator_dropStatusChanged [
+                    old: *mut dyn Fn(IncubationStatus) as "TraitObject"
+                ] {
+                    let _ = Box::from_raw(old);
+                });
+            }
+        }
+
+        ~RustQmlIncubator() {
+            // Abort any incubation while the closures are still alive.
+            clear();
+            setInitialStateFunction({});
+            setStatusChangedFunction({});
+        }
+    };
+
+    struct QQmlIncubatorHolder {
+        std::unique_ptr<RustQmlIncubator> incubator;
+
+        QQmlIncubatorHolder(QQmlIncubator::IncubationMode mode)
+            : incubator(new RustQmlIncubator(mode))
+        {}
+    };
+}}
+
+cpp_class!(
+    /// Wrapper for QQmlIncubator, used to create objects from a QmlComponent
+    /// asynchronously with QmlComponent::create_async.
+    ///
+    /// The incubator must outlive the incubation. Dropping it while it is loading
+    /// aborts the incubation. Once it is ready, the created object is not owned
+    /// by the incubator.
+    pub unsafe struct QQmlIncubator as "QQmlIncubatorHolder"
+);
+
+impl QQmlIncubator {
+    /// Creates a new incubator with the given incubation mode.
+    pub fn new(mode: IncubationMode) -> QQmlIncubator {
+        cpp!(unsafe [mode as "QQmlIncubator::IncubationMode"] -> QQmlIncubator as "QQmlIncubatorHolder" {
+            return QQmlIncubatorHolder(mode);
+        })
+    }
+
+    /// Returns a pointer to the underlying QQmlIncubator.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QQmlIncubatorHolder *"] -> *mut c_void as "QQmlIncubator *" {
+            return self->incubator.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::status
+    pub fn status(&self) -> IncubationStatus {
+        cpp!(unsafe [self as "QQmlIncubatorHolder *"] -> IncubationStatus as "QQmlIncubator::Status" {
+            return self->incubator->status();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::object
+    ///
+    /// Returns None unless the status is Ready. The returned QObject is owned by the caller.
+    pub fn object(&self) -> Option<*mut c_void> {
+        let obj = cpp!(unsafe [self as "QQmlIncubatorHolder *"] -> *mut c_void as "QObject *" {
+            return self->incubator->object();
+        });
+        if obj.is_null() {
+            None
+        } else {
+            Some(obj)
+        }
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::errors
+    pub fn errors(&self) -> Vec<QQmlError> {
+        let count = cpp!(unsafe [self as "QQmlIncubatorHolder *"] -> usize as "size_t" {
+            return self->incubator->errors().size();
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [self as "QQmlIncubatorHolder *", i as "size_t"] -> QQmlError as "QQmlError" {
+                    return self->incubator->errors().at(i);
+                })
+            })
+            .collect()
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::forceCompletion
+    pub fn force_completion(&mut self) {
+        cpp!(unsafe [self as "QQmlIncubatorHolder *"] {
+            self->incubator->forceCompletion();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlIncubator::clear
+    pub fn clear(&mut self) {
+        cpp!(unsafe [self as "QQmlIncubatorHolder *"] {
+            self->incubator->clear();
+        })
+    }
+
+    /// Sets a function called with the created object before its bindings are
+    /// evaluated, like overriding QQmlIncubator::setInitialState.
+    ///
+    /// The function is called at most once; it must be set before calling
+    /// QmlComponent::create_async.
+    pub fn set_initial_state<F: FnOnce(*mut c_void) + 'static>(&mut self, f: F) {
+        let mut f = Some(f);
+        let f: Box<dyn FnMut(*mut c_void)> = Box::new(move |obj| {
+            if let Some(f) = f.take() {
+                f(obj)
+            }
+        });
+        let f = Box::into_raw(f);
+        cpp!(unsafe [self as "QQmlIncubatorHolder *", f as "TraitObject"] {
+            self->incubator->setInitialStateFunction(f);
+        })
+    }
+
+    /// Sets a function called when the status changes, like overriding
+    /// QQmlIncubator::statusChanged. Replaces any previously set function.
+    pub fn on_status_changed<F: Fn(IncubationStatus) + 'static>(&mut self, f: F) {
+        let f: *mut dyn Fn(IncubationStatus) = Box::into_raw(Box::new(f));
+        cpp!(unsafe [self as "QQmlIncubatorHolder *", f as "TraitObject"] {
+            self->incubator->setStatusChangedFunction(f);
+        })
+    }
+}
+
+impl QmlComponent {
+    /// Performs QQmlComponent::create with an incubator
+    ///
+    /// The result is available through the incubator once its status becomes Ready.
+    pub fn create_async(&mut self, incubator: &mut QQmlIncubator) {
+        cpp!(unsafe [self as "QQmlComponentHolder *", incubator as "QQmlIncubatorHolder *"] {
+            self->component->create(*incubator->incubator);
+        })
+    }
+}
+
+struct IncubatorFuture<'a>(&'a mut QQmlIncubator);
+
+impl<'a> std::future::Future for IncubatorFuture<'a> {
+    type Output = Result<*mut c_void, Vec<QQmlError>>;
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut std::task::Context,
+    ) -> std::task::Poll<Self::Output> {
+        use std::task::Poll;
+        let incubator = &mut *self.0;
+        match incubator.status() {
+            IncubationStatus::Loading => {
+                let waker = ctx.waker().clone();
+                incubator.on_status_changed(move |_| waker.wake_by_ref());
+                Poll::Pending
+            }
+            IncubationStatus::Ready => Poll::Ready(incubator.object().ok_or_else(|| {
+                vec![QQmlError::new("The incubator is ready but did not create an object".into())]
+            })),
+            IncubationStatus::Null | IncubationStatus::Error => {
+                Poll::Ready(Err(incubator.errors()))
+            }
+        }
+    }
+}
+
+/// Creates an object from the component asynchronously, using a QQmlIncubator.
+///
+/// The returned future must be polled from the Qt thread, for example with
+/// [`execute_async`](crate::future::execute_async). It resolves to the created object,
+/// which is owned by the caller, or to the errors reported by the incubator. If the component
+/// is not ready, the future resolves immediately to the errors of the component.
+///
+/// ```ignore
+/// execute_async(async move {
+///     match create_component_async(&mut component).await {
+///         Ok(obj) => { /* ... */ }
+///         Err(errors) => errors.iter().for_each(|e| eprintln!("{}", e.to_string())),
+///     }
+/// });
+/// ```
+pub async fn create_component_async(
+    component: &mut QmlComponent,
+) -> Result<*mut c_void, Vec<QQmlError>> {
+    match component.status() {
+        ComponentStatus::Ready => {}
+        ComponentStatus::Error => return Err(component.errors()),
+        ComponentStatus::Null | ComponentStatus::Loading => {
+            return Err(vec![QQmlError::new("The component is not ready".into())])
+        }
+    }
+    let mut incubator = QQmlIncubator::new(IncubationMode::Asynchronous);
+    component.create_async(&mut incubator);
+    IncubatorFuture(&mut incubator).await
+}
+
+/// Returns the attached object of the QML type `attacher` for the C++ object `obj`, for example
+/// the object holding the properties set with `Keys.enabled: false` in QML.
+///
+/// If the object has no such attached object yet, it is created if `create` is true, and None
+/// is returned otherwise. Its properties can be read with [`qobject_property`].
+/// Refer to the Qt documentation of qmlAttachedPropertiesObject
+///
+/// # Safety
+///
+/// `obj` must be a valid pointer to a QObject.
+#[cfg(qt_5_14)]
+pub unsafe fn qml_attached_properties_object(
+    obj: *mut c_void,
+    attacher: &QMetaObject,
+    create: bool,
+) -> Option<*mut c_void> {
+    let attacher: *const QMetaObject = attacher;
+    let attached = cpp!([obj as "QObject *", attacher as "const QMetaObject *", create as "bool"]
+            -> *mut c_void as "QObject *" {
+        auto func = qmlAttachedPropertiesFunction(obj, attacher);
+        return func ? qmlAttachedPropertiesObject(obj, func, create) : nullptr;
+    });
+    if attached.is_null() {
+        None
+    } else {
+        Some(attached)
+    }
+}
+
+/// Returns the value of the attached property `name` set in QML on `obj`, or None if it was not
+/// set or cannot be converted to `V`.
+///
+/// `attacher` is the name of the C++ class which has the attached properties, for example
+/// `QQuickKeysAttached` to read `enabled` for `Keys.enabled: false`. The class must have been
+/// registered to QML.
+#[cfg(qt_5_14)]
+pub fn get_attached_property<V: QMetaType, T: QObject + ?Sized>(
+    obj: &T,
+    attacher: &str,
+    name: &str,
+) -> Option<V> {
+    let obj = obj.get_cpp_object();
+    if obj.is_null() {
+        return None;
+    }
+    let attacher = CString::new(format!("{}*", attacher)).ok()?;
+    let attacher = attacher.as_ptr();
+    let attacher = cpp!(unsafe [attacher as "const char *"] -> *const QMetaObject as "const QMetaObject *" {
+    #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+        return QMetaType::fromName(attacher).metaObject();
+    #else
+        return QMetaType::metaObjectForType(QMetaType::type(attacher));
+    #endif
+    });
+    if attacher.is_null() {
+        return None;
+    }
+    unsafe {
+        let attached = qml_attached_properties_object(obj, &*attacher, false)?;
+        V::from_qvariant(qobject_property(attached, name))
+    }
+}
+
 /// Register the given type as a QML type
 ///
 /// Refer to the Qt documentation for qmlRegisterType.
@@ -422,6 +942,8 @@ pub fn qml_register_type<T: QObject + Default + Sized>(
     let creator_fn: extern "C" fn(c: *mut c_void, #[cfg(qt_6_0)] _: *mut c_void) = creator_fn::<T>;
 
     let size = T::cpp_size();
+    let parser_status_cast = T::parser_status_cast();
+    let value_source_cast = T::value_source_cast();
 
     let type_id = <RefCell<T> as PropertyType>::register_type(Default::default());
 
@@ -433,7 +955,9 @@ pub fn qml_register_type<T: QObject + Default + Sized>(
         meta_object as "const QMetaObject *",
         creator_fn as "CreatorFunction",
         size as "size_t",
-        type_id as "int"
+        type_id as "int",
+        parser_status_cast as "int",
+        value_source_cast as "int"
     ] {
         // BEGIN: From QML_GETTYPENAMES
         // FIXME: list type?
@@ -445,10 +969,6 @@ pub fn qml_register_type<T: QObject + Default + Sized>(
         listName[listLen+nameLen+1] = '\0';*/
         // END
 
-        int parserStatusCast = meta_object && qmeta_inherits(meta_object, &QQuickItem::staticMetaObject)
-            ? QQmlPrivate::StaticCastSelector<QQuickItem, QQmlParserStatus>::cast()
-            : -1;
-
         QQmlPrivate::RegisterType api = {
             /*version*/ 0,
 
@@ -481,8 +1001,8 @@ pub fn qml_register_type<T: QObject + Default + Sized>(
             /*attachedPropertiesFunction*/ nullptr,
             /*attachedPropertiesMetaObject*/ nullptr,
 
-            /*parserStatusCast*/ parserStatusCast,
-            /*valueSourceCast*/ -1,
+            /*parserStatusCast*/ parser_status_cast,
+            /*valueSourceCast*/ value_source_cast,
             /*valueInterceptorCast*/ -1,
 
             /*extensionObjectCreate*/ nullptr,
@@ -494,6 +1014,24 @@ pub fn qml_register_type<T: QObject + Default + Sized>(
     })
 }
 
+/// Register the given type as a QML type for all the minor versions from `version_minor_from`
+/// to `version_minor_to` (inclusive)
+///
+/// This allows to use the type with any of the `import uri major.minor` statements of the range.
+/// See [`qml_register_type`].
+pub fn qml_register_type_range<T: QObject + Default + Sized>(
+    uri: &CStr,
+    version_major: u32,
+    version_minor_from: u32,
+    version_minor_to: u32,
+    qml_name: &CStr,
+) {
+    assert!(version_minor_from <= version_minor_to, "Invalid range of minor versions");
+    for version_minor in version_minor_from..=version_minor_to {
+        qml_register_type::<T>(uri, version_major, version_minor, qml_name);
+    }
+}
+
 /// Alias for type of `QQmlPrivate::RegisterSingletonType::qobjectApi` callback
 /// and its C++ counterpart.
 type QmlRegisterSingletonTypeCallback =
@@ -696,6 +1234,81 @@ pub fn qml_register_enum<T: QEnum>(
         })
 }
 
+/// Register the given gadget as a QML value type, so that it can be used as the type of a
+/// property declared in QML (`property myGadget thing`), without the overhead of a QObject.
+///
+/// The meta type of the gadget is always registered, which is enough to pass the gadget to QML
+/// and to access its properties and methods. Declaring properties of a named value type in QML
+/// is only supported since Qt 6, so with Qt 5 the name and the version are ignored. Qt requires
+/// the names of value types to start with a lowercase letter.
+///
+/// This is the equivalent of the `QML_VALUE_TYPE` macro in C++.
+pub fn qml_register_value_type<T: QGadget + Clone + Default + 'static>(
+    uri: &CStr,
+    version_major: u32,
+    version_minor: u32,
+    qml_name: &CStr,
+) {
+    let type_id = <T as QMetaType>::register(None);
+    if cfg!(not(qt_6_0)) {
+        return;
+    }
+
+    let uri_ptr = uri.as_ptr();
+    let qml_name_ptr = qml_name.as_ptr();
+    let meta_object = T::static_meta_object();
+    let size = std::mem::size_of::<T>();
+
+    cpp!(unsafe [
+        qml_name_ptr as "char *",
+        uri_ptr as "char *",
+        version_major as "int",
+        version_minor as "int",
+        meta_object as "const QMetaObject *",
+        size as "size_t",
+        type_id as "int"
+    ] {
+    #if QT_VERSION >= QT_VERSION_CHECK(6,0,0)
+        QQmlPrivate::RegisterType api = {
+            /*version*/ 0,
+            /*typeId*/ QMetaType(type_id),
+            /*listId*/ {},
+            /*objectSize*/ int(size),
+            /*create*/ nullptr,
+            /* userdata */ nullptr,
+            /*noCreationReason*/ QStringLiteral("Value types cannot be created as objects"),
+            /* createValueType */ nullptr,
+
+            /*uri*/ uri_ptr,
+            /*version*/ QTypeRevision::fromVersion(version_major, version_minor),
+            /*elementName*/ qml_name_ptr,
+            /*metaObject*/ meta_object,
+
+            /*attachedPropertiesFunction*/ nullptr,
+            /*attachedPropertiesMetaObject*/ nullptr,
+
+            /*parserStatusCast*/ -1,
+            /*valueSourceCast*/ -1,
+            /*valueInterceptorCast*/ -1,
+
+            /*extensionObjectCreate*/ nullptr,
+            /*extensionMetaObject*/ nullptr,
+            /*customParser*/ nullptr,
+            /*revision*/ {}
+        };
+        QQmlPrivate::qmlregister(QQmlPrivate::TypeRegistration, &api);
+    #else
+        Q_UNUSED(qml_name_ptr);
+        Q_UNUSED(uri_ptr);
+        Q_UNUSED(version_major);
+        Q_UNUSED(version_minor);
+        Q_UNUSED(meta_object);
+        Q_UNUSED(size);
+        Q_UNUSED(type_id);
+    #endif
+    })
+}
+
 /// A QObject-like trait to inherit from QQuickItem.
 ///
 /// Work in progress
@@ -852,27 +1465,205 @@ impl<'a> dyn QQuickItem + 'a {
     }
 }
 
-/// Only a specific subset of [`QEvent::Type`][qt] enum.
+/// A QObject-like trait to inherit from QObject and [`QQmlParserStatus`][qt].
 ///
-/// [qt]: https://doc.qt.io/qt-5/qevent.html#Type-enum
-#[repr(u32)]
-#[non_exhaustive]
-pub enum QMouseEventType {
-    MouseButtonPress = 2,
-    MouseButtonRelease = 3,
-    // FIXME: WIP
-    //MouseButtonDblClick = 4,
-    MouseMove = 5,
-}
-
-/// A reference to a [`QMouseEvent`][qt] instance.
+/// When the type is registered with `qml_register_type` and created from QML, `class_begin` is
+/// called before the properties are initialized, and `component_complete` once all of them
+/// have been set by the QML engine. (Similar to `Component.onCompleted` in QML.)
 ///
-/// [qt]: https://doc.qt.io/qt-5/qmouseevent.html
-#[repr(transparent)]
-#[derive(Clone, Copy)]
-pub struct QMouseEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
-
-impl<'a> QMouseEvent<'a> {
+/// ```
+/// # use qmetaobject::*;
+/// #[derive(QObject, Default)]
+/// struct Connection {
+///     base: qt_base_class!(trait QQmlParserStatus),
+///     host: qt_property!(QString),
+///     port: qt_property!(u32),
+/// }
+///
+/// impl QQmlParserStatus for Connection {
+///     fn component_complete(&mut self) {
+///         // host and port are both set here
+///     }
+/// }
+/// ```
+///
+/// [qt]: https://doc.qt.io/qt-5/qqmlparserstatus.html
+pub trait QQmlParserStatus: QObject {
+    #[doc(hidden)] // implementation detail for the QObject custom derive
+    fn get_object_description() -> &'static QObjectDescriptor
+    where
+        Self: Sized,
+    {
+        unsafe {
+            &*cpp!([]-> *const QObjectDescriptor as "RustQObjectDescriptor const*" {
+                return RustQObjectDescriptor::instance<Rust_QQmlParserStatus>();
+            })
+        }
+    }
+
+    /// Refer to the Qt documentation of QQmlParserStatus::classBegin
+    fn class_begin(&mut self) {}
+
+    /// Refer to the Qt documentation of QQmlParserStatus::componentComplete
+    fn component_complete(&mut self) {}
+}
+
+cpp! {{
+    #include <qmetaobject_rust.hpp>
+    #include <QtQml/QQmlParserStatus>
+
+    struct Rust_QQmlParserStatus : RustObject<QObject>, QQmlParserStatus {
+        void classBegin() override {
+            rust!(Rust_QQmlParserStatus_classBegin[
+                rust_object: QObjectPinned<dyn QQmlParserStatus> as "TraitObject"
+            ] {
+                rust_object.borrow_mut().class_begin();
+            });
+        }
+
+        void componentComplete() override {
+            rust!(Rust_QQmlParserStatus_componentComplete[
+                rust_object: QObjectPinned<dyn QQmlParserStatus> as "TraitObject"
+            ] {
+                rust_object.borrow_mut().component_complete();
+            });
+        }
+    };
+}}
+
+cpp_class!(
+    /// Wrapper around [`QQmlProperty`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qqmlproperty.html
+    pub unsafe struct QQmlProperty as "QQmlProperty"
+);
+
+impl QQmlProperty {
+    /// Wrapper around [`isValid()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qqmlproperty.html#isValid
+    pub fn is_valid(&self) -> bool {
+        cpp!(unsafe [self as "const QQmlProperty *"] -> bool as "bool" {
+            return self->isValid();
+        })
+    }
+
+    /// Wrapper around [`name()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qqmlproperty.html#name
+    pub fn name(&self) -> QString {
+        cpp!(unsafe [self as "const QQmlProperty *"] -> QString as "QString" {
+            return self->name();
+        })
+    }
+
+    /// Wrapper around [`object()`][method] method.
+    ///
+    /// Returns the pointer to the C++ QObject which has this property.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qqmlproperty.html#object
+    pub fn object(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QQmlProperty *"] -> *mut c_void as "QObject *" {
+            return self->object();
+        })
+    }
+
+    /// Wrapper around [`read()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qqmlproperty.html#read
+    pub fn read(&self) -> QVariant {
+        cpp!(unsafe [self as "const QQmlProperty *"] -> QVariant as "QVariant" {
+            return self->read();
+        })
+    }
+
+    /// Wrapper around [`write(const QVariant &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qqmlproperty.html#write
+    pub fn write(&self, value: QVariant) -> bool {
+        cpp!(unsafe [self as "const QQmlProperty *", value as "QVariant"] -> bool as "bool" {
+            return self->write(value);
+        })
+    }
+}
+
+/// A QObject-like trait to inherit from QObject and [`QQmlPropertyValueSource`][qt].
+///
+/// Such a type can be used in QML with the `<Type> on <property>` syntax, in which case
+/// `set_target` is called with the property when the object is created.
+///
+/// ```
+/// # use qmetaobject::*;
+/// #[derive(QObject, Default)]
+/// struct ConstantSource {
+///     base: qt_base_class!(trait QQmlPropertyValueSource),
+///     target: Option<QQmlProperty>,
+/// }
+///
+/// impl QQmlPropertyValueSource for ConstantSource {
+///     fn set_target(&mut self, property: QQmlProperty) {
+///         property.write(QVariant::from(42));
+///         self.target = Some(property);
+///     }
+/// }
+/// ```
+///
+/// [qt]: https://doc.qt.io/qt-5/qqmlpropertyvaluesource.html
+pub trait QQmlPropertyValueSource: QObject {
+    #[doc(hidden)] // implementation detail for the QObject custom derive
+    fn get_object_description() -> &'static QObjectDescriptor
+    where
+        Self: Sized,
+    {
+        unsafe {
+            &*cpp!([]-> *const QObjectDescriptor as "RustQObjectDescriptor const*" {
+                return RustQObjectDescriptor::instance<Rust_QQmlPropertyValueSource>();
+            })
+        }
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyValueSource::setTarget
+    fn set_target(&mut self, property: QQmlProperty);
+}
+
+cpp! {{
+    #include <qmetaobject_rust.hpp>
+    #include <QtQml/QQmlProperty>
+    #include <QtQml/QQmlPropertyValueSource>
+
+    struct Rust_QQmlPropertyValueSource : RustObject<QObject>, QQmlPropertyValueSource {
+        void setTarget(const QQmlProperty &property) override {
+            rust!(Rust_QQmlPropertyValueSource_setTarget[
+                rust_object: QObjectPinned<dyn QQmlPropertyValueSource> as "TraitObject",
+                property: &QQmlProperty as "const QQmlProperty *"
+            ] {
+                rust_object.borrow_mut().set_target(property.clone());
+            });
+        }
+    };
+}}
+
+/// Only a specific subset of [`QEvent::Type`][qt] enum.
+///
+/// [qt]: https://doc.qt.io/qt-5/qevent.html#Type-enum
+#[repr(u32)]
+#[non_exhaustive]
+pub enum QMouseEventType {
+    MouseButtonPress = 2,
+    MouseButtonRelease = 3,
+    // FIXME: WIP
+    //MouseButtonDblClick = 4,
+    MouseMove = 5,
+}
+
+/// A reference to a [`QMouseEvent`][qt] instance.
+///
+/// [qt]: https://doc.qt.io/qt-5/qmouseevent.html
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct QMouseEvent<'a>(*const c_void, std::marker::PhantomData<&'a u32>);
+
+impl<'a> QMouseEvent<'a> {
     /// Returns the type of event
     pub fn event_type(self) -> QMouseEventType {
         cpp!(unsafe [self as "QMouseEvent *"] -> QMouseEventType as "int" {
@@ -887,6 +1678,57 @@ impl<'a> QMouseEvent<'a> {
     }
 }
 
+cpp_class!(
+    /// Wrapper for QQmlError, an error or warning reported by the QML engine
+    pub unsafe struct QQmlError as "QQmlError"
+);
+
+impl QQmlError {
+    /// Creates an error with the given description, and no location.
+    pub fn new(description: QString) -> QQmlError {
+        cpp!(unsafe [description as "QString"] -> QQmlError as "QQmlError" {
+            QQmlError error;
+            error.setDescription(description);
+            return error;
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlError::description
+    pub fn description(&self) -> QString {
+        cpp!(unsafe [self as "const QQmlError *"] -> QString as "QString" {
+            return self->description();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlError::url
+    pub fn url(&self) -> QUrl {
+        cpp!(unsafe [self as "const QQmlError *"] -> QUrl as "QUrl" {
+            return self->url();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlError::line
+    pub fn line(&self) -> i32 {
+        cpp!(unsafe [self as "const QQmlError *"] -> i32 as "int" {
+            return self->line();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlError::column
+    pub fn column(&self) -> i32 {
+        cpp!(unsafe [self as "const QQmlError *"] -> i32 as "int" {
+            return self->column();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlError::toString
+    pub fn to_string(&self) -> QString {
+        cpp!(unsafe [self as "const QQmlError *"] -> QString as "QString" {
+            return self->toString();
+        })
+    }
+}
+
 cpp_class!(
     /// Wrapper for QJSValue
     pub unsafe struct QJSValue as "QJSValue"
@@ -917,6 +1759,59 @@ impl QJSValue {
         })
     }
 
+    /// Refer to the Qt documentation of QJSValue::isError
+    pub fn is_error(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isError();
+        })
+    }
+
+    /// Refer to the Qt documentation of QJSValue::isCallable
+    pub fn is_callable(&self) -> bool {
+        cpp!(unsafe [self as "const QJSValue *"] -> bool as "bool" {
+            return self->isCallable();
+        })
+    }
+
+    /// Refer to the Qt documentation of QJSValue::property
+    pub fn property(&self, name: &str) -> QJSValue {
+        let name = QString::from(name);
+        cpp!(unsafe [self as "const QJSValue *", name as "QString"] -> QJSValue as "QJSValue" {
+            return self->property(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QJSValue::setProperty
+    pub fn set_property(&mut self, name: &str, value: QJSValue) {
+        let name = QString::from(name);
+        cpp!(unsafe [self as "QJSValue *", name as "QString", value as "QJSValue"] {
+            self->setProperty(name, value);
+        })
+    }
+
+    /// Calls this value as a function. Returns the error thrown by the function, if any.
+    ///
+    /// Refer to the Qt documentation of QJSValue::call
+    pub fn call(&self, args: &[QJSValue]) -> Result<QJSValue, QJSError> {
+        let args_size = args.len();
+        let args_ptr = args.as_ptr();
+        let result = cpp!(unsafe [
+            self as "const QJSValue *",
+            args_size as "size_t",
+            args_ptr as "const QJSValue *"
+        ] -> QJSValue as "QJSValue" {
+            QJSValueList args;
+            for (size_t i = 0; i < args_size; ++i)
+                args.append(args_ptr[i]);
+            return self->call(args);
+        });
+        if result.is_error() {
+            Err(QJSError(result))
+        } else {
+            Ok(result)
+        }
+    }
+
     pub fn to_qobject<'a, T: QObject + 'a>(&'a self) -> Option<QObjectPinned<'a, T>> {
         let mo = T::static_meta_object();
         let obj = cpp!(unsafe [
@@ -934,6 +1829,22 @@ impl QJSValue {
     }
 }
 
+/// A JavaScript error, as returned by [`QJSValue::call`].
+#[derive(Clone)]
+pub struct QJSError(pub QJSValue);
+
+impl QJSError {
+    /// Returns the `name` of the error, for example `TypeError`.
+    pub fn name(&self) -> QString {
+        self.0.property("name").to_string()
+    }
+
+    /// Returns the `message` of the error.
+    pub fn message(&self) -> QString {
+        self.0.property("message").to_string()
+    }
+}
+
 impl From<QString> for QJSValue {
     fn from(a: QString) -> QJSValue {
         cpp!(unsafe [a as "QString"] -> QJSValue as "QJSValue" {
@@ -1023,6 +1934,31 @@ mod qjsvalue_tests {
 ///     }
 /// }
 /// ```
+///
+/// The `uri` is passed as a `&CStr` because that is what `qml_register_type` and friends
+/// expect; use `uri.to_str()` if a `&str` is needed.
+///
+/// ## Building the plugin
+///
+/// Qt loads QML plugins as shared libraries, so the crate needs to be built as a `cdylib`:
+///
+/// ```toml
+/// [lib]
+/// name = "myplugin"
+/// crate-type = ["cdylib"]
+/// ```
+///
+/// The resulting library (e.g. `libmyplugin.so`) must then be placed in a directory of the QML
+/// import path matching the module URI (for example `MyModule/` for `import MyModule 1.0`),
+/// next to a `qmldir` file declaring it:
+///
+/// ```text
+/// module MyModule
+/// plugin myplugin
+/// ```
+///
+/// Only one struct using `qt_plugin!` may exist per crate, as it defines the
+/// `qt_plugin_instance` and `qt_plugin_query_metadata` entry points that Qt looks up.
 pub trait QQmlExtensionPlugin: QObject {
     #[doc(hidden)] // implementation detail for the QObject custom derive
     fn get_object_description() -> &'static QObjectDescriptor
@@ -1055,3 +1991,312 @@ cpp! {{
         }
     };
 }}
+
+cpp! {{
+    #include <QtQml/QQmlPropertyMap>
+
+    struct QQmlPropertyMapHolder {
+        std::unique_ptr<QQmlPropertyMap> map;
+
+        QQmlPropertyMapHolder() : map(new QQmlPropertyMap) {}
+    };
+}}
+
+cpp_class!(
+    /// Wrapper around [`QQmlPropertyMap`][class] class.
+    ///
+    /// An object whose properties are the keys of the map, which QML can read, write and bind
+    /// to. Expose it to QML with `QmlEngine::set_property` and [`to_qvariant`](#method.to_qvariant).
+    /// The map must outlive the QML objects using it.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qqmlpropertymap.html
+    pub unsafe struct QQmlPropertyMap as "QQmlPropertyMapHolder"
+);
+
+impl QQmlPropertyMap {
+    /// Creates an empty property map.
+    pub fn new() -> QQmlPropertyMap {
+        Default::default()
+    }
+
+    /// Returns a pointer to the underlying QQmlPropertyMap, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *"] -> *mut c_void as "QQmlPropertyMap *" {
+            return self->map.get();
+        })
+    }
+
+    /// Returns a QVariant containing a pointer to the map, to be set as a property of the
+    /// QML context.
+    pub fn to_qvariant(&self) -> QVariant {
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *"] -> QVariant as "QVariant" {
+            return QVariant::fromValue(static_cast<QObject *>(self->map.get()));
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::insert
+    pub fn insert(&mut self, key: &str, value: QVariant) {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *", key as "QString", value as "QVariant"] {
+            self->map->insert(key, value);
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::value
+    pub fn value(&self, key: &str) -> QVariant {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *", key as "QString"] -> QVariant as "QVariant" {
+            return self->map->value(key);
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::contains
+    pub fn contains(&self, key: &str) -> bool {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *", key as "QString"] -> bool as "bool" {
+            return self->map->contains(key);
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::keys
+    pub fn keys(&self) -> Vec<QString> {
+        let mut keys = Vec::new();
+        let keys_ptr = &mut keys;
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *", keys_ptr as "void *"] {
+            for (const QString &key : self->map->keys()) {
+                rust!(QQmlPropertyMap_keys_push [
+                    keys_ptr: &mut Vec<QString> as "void *",
+                    key: &QString as "const QString &"
+                ] {
+                    keys_ptr.push(key.clone());
+                });
+            }
+        });
+        keys
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::clear
+    ///
+    /// This clears the value of the key, the key itself stays in the map.
+    pub fn clear(&mut self, key: &str) {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *", key as "QString"] {
+            self->map->clear(key);
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::count
+    pub fn len(&self) -> usize {
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *"] -> usize as "size_t" {
+            return self->map->count();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "QQmlPropertyMapHolder *"] -> bool as "bool" {
+            return self->map->isEmpty();
+        })
+    }
+
+    /// Refer to the Qt documentation of QQmlPropertyMap::valueChanged
+    ///
+    /// The signal is only emitted when a value is changed from QML, not by `insert`.
+    pub fn value_changed_signal() -> Signal<fn(QString, QVariant)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner"  {
+                return &QQmlPropertyMap::valueChanged;
+            }))
+        }
+    }
+}
+
+cpp! {{
+    #include <QtQml/QQmlListProperty>
+
+    // Callbacks of the QQmlListProperty<QObject> passed to QML for a qt_list_property!.
+    // The data of the list property points to the ListData of the Rust QQmlListProperty.
+    struct RustQmlListProperty {
+    #if QT_VERSION < QT_VERSION_CHECK(6,0,0)
+        using Size = int;
+    #else
+        using Size = qsizetype;
+    #endif
+
+        static void append(QQmlListProperty<QObject> *p, QObject *o) {
+            void *data = p->data;
+            auto meta = rust!(RustQmlListProperty_metaObject [
+                data: &ListData as "void *"
+            ] -> *const QMetaObject as "const QMetaObject *" {
+                data.meta_object
+            });
+            if (!o || !o->metaObject()->inherits(meta)) {
+                qWarning("Cannot append an object of type %s to a list of %s",
+                         o ? o->metaObject()->className() : "null", meta->className());
+                return;
+            }
+            QPointer<QObject> item(o);
+            rust!(RustQmlListProperty_append [
+                data: &mut ListData as "void *",
+                item: &crate::QPointerImpl as "const QPointer<QObject> *"
+            ] {
+                data.items.push(item.clone());
+            });
+        }
+
+        static Size count(QQmlListProperty<QObject> *p) {
+            void *data = p->data;
+            return Size(rust!(RustQmlListProperty_count [
+                data: &ListData as "void *"
+            ] -> usize as "size_t" {
+                data.items.len()
+            }));
+        }
+
+        static QObject *at(QQmlListProperty<QObject> *p, Size i) {
+            void *data = p->data;
+            size_t index = size_t(i);
+            return rust!(RustQmlListProperty_at [
+                data: &ListData as "void *",
+                index: usize as "size_t"
+            ] -> *mut c_void as "QObject *" {
+                data.items.get(index).map_or(std::ptr::null_mut(), list_item_ptr)
+            });
+        }
+
+        static void clear(QQmlListProperty<QObject> *p) {
+            void *data = p->data;
+            rust!(RustQmlListProperty_clear [data: &mut ListData as "void *"] {
+                data.items.clear();
+            });
+        }
+    };
+}}
+
+/// Returns the C++ object of an item of a list property, or null if it was deleted.
+fn list_item_ptr(item: &crate::QPointerImpl) -> *mut c_void {
+    cpp!(unsafe [item as "const QPointer<QObject> *"] -> *mut c_void as "QObject *" {
+        return item->data();
+    })
+}
+
+/// Data of a QQmlListProperty, which does not depend on the type of the items.
+#[derive(Default)]
+struct ListData {
+    /// Guarded pointers to the C++ objects, null once they are deleted
+    items: Vec<crate::QPointerImpl>,
+    /// Meta object of the type of the items, set when the list is passed to Qt
+    meta_object: *const QMetaObject,
+}
+
+/// A list of objects that QML can fill, like the `data` or `children` properties of an Item.
+///
+/// Declare it in a struct deriving from QObject with the [`qt_list_property!`] macro. The
+/// objects declared in QML inside of the list property are appended to the list, as long as
+/// they inherit from `T`.
+///
+/// ```
+/// use qmetaobject::*;
+///
+/// #[derive(QObject, Default)]
+/// struct Entry {
+///     base: qt_base_class!(trait QObject),
+///     name: qt_property!(QString),
+/// }
+///
+/// #[derive(QObject, Default)]
+/// struct Container {
+///     base: qt_base_class!(trait QObject),
+///     entries: qt_list_property!(Entry),
+/// }
+/// ```
+///
+/// The list does not own the objects. Objects created by QML are owned by their parent; once
+/// an object is deleted, its entry stays in the list but `at` returns None for it.
+///
+/// [`qt_list_property!`]: ../macro.qt_list_property.html
+pub struct QQmlListProperty<T: QObject> {
+    data: ListData,
+    _phantom: std::marker::PhantomData<*mut T>,
+}
+
+impl<T: QObject> Default for QQmlListProperty<T> {
+    fn default() -> Self {
+        QQmlListProperty { data: Default::default(), _phantom: Default::default() }
+    }
+}
+
+impl<T: QObject> QQmlListProperty<T> {
+    /// Appends an object to the list. The C++ object is created if it was not already.
+    pub fn append(&mut self, item: QObjectPinned<T>) {
+        let ptr = item.get_or_create_cpp_object();
+        self.data.items.push(
+            cpp!(unsafe [ptr as "QObject *"] -> crate::QPointerImpl as "QPointer<QObject>" {
+                return ptr;
+            }),
+        );
+    }
+
+    /// Returns the number of objects in the list.
+    pub fn len(&self) -> usize {
+        self.data.items.len()
+    }
+
+    /// Returns true if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.items.is_empty()
+    }
+
+    /// Returns the object at the given position, or None if the index is out of bounds or if
+    /// the object was deleted.
+    pub fn at(&self, index: usize) -> Option<QObjectPinned<T>> {
+        self.cpp_ptr_at(index).map(|x| unsafe { T::get_from_cpp(x) })
+    }
+
+    /// Returns the pointer to the C++ object at the given position, or None if the index is
+    /// out of bounds or if the object was deleted.
+    pub fn cpp_ptr_at(&self, index: usize) -> Option<*mut c_void> {
+        self.data.items.get(index).map(list_item_ptr).filter(|x| !x.is_null())
+    }
+
+    /// Removes all the objects from the list. The objects are not deleted.
+    pub fn clear(&mut self) {
+        self.data.items.clear();
+    }
+
+    /// Internal function used from the code generated by the QObject derive macro, to pass
+    /// the list to Qt with the object which owns it.
+    #[doc(hidden)]
+    pub unsafe fn pass_to_qt_with_owner(&mut self, owner: *mut c_void, a: *mut c_void) {
+        self.data.meta_object = T::static_meta_object();
+        let data = &mut self.data as *mut ListData;
+        cpp!(unsafe [owner as "QObject *", data as "void *", a as "QQmlListProperty<QObject> *"] {
+            if (a) {
+                *a = QQmlListProperty<QObject>(owner, data,
+                    &RustQmlListProperty::append, &RustQmlListProperty::count,
+                    &RustQmlListProperty::at, &RustQmlListProperty::clear);
+            }
+        })
+    }
+}
+
+impl<T: QObject> PropertyType for QQmlListProperty<T> {
+    fn register_type(_name: &CStr) -> i32 {
+        cpp!(unsafe [] -> i32 as "int" {
+        #if QT_VERSION < QT_VERSION_CHECK(6,0,0)
+            return qRegisterMetaType<QQmlListProperty<QObject>>("QQmlListProperty<QObject>");
+        #else
+            return QMetaType::fromType<QQmlListProperty<QObject>>().id();
+        #endif
+        })
+    }
+
+    unsafe fn pass_to_qt(&mut self, a: *mut c_void) {
+        self.pass_to_qt_with_owner(std::ptr::null_mut(), a);
+    }
+
+    unsafe fn read_from_qt(_a: *const c_void) -> Self {
+        panic!("Cannot write into a list property");
+    }
+}
@@ -0,0 +1,184 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::marker::PhantomData;
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QTextStream>
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    #include <QtCore/QStringConverter>
+#else
+    #include <QtCore/QTextCodec>
+#endif
+
+    struct QTextStreamHolder {
+        std::unique_ptr<QString> owned_string;
+        std::unique_ptr<QTextStream> stream;
+    };
+}}
+
+cpp_class!(
+    unsafe struct QTextStreamHolder as "QTextStreamHolder"
+);
+
+/// Wrapper around [`QTextStream`][class] class, operating on a `QString` or a `QByteArray`.
+///
+/// The stream borrows the string or the byte array it operates on.
+///
+/// [class]: https://doc.qt.io/qt-5/qtextstream.html
+pub struct QTextStream<'a> {
+    holder: QTextStreamHolder,
+    _phantom: PhantomData<&'a mut ()>,
+}
+
+impl QTextStream<'static> {
+    /// Creates a stream writing to an internal string, which can be retrieved with
+    /// [`to_string_output`](#method.to_string_output).
+    pub fn new() -> Self {
+        let holder = cpp!(unsafe [] -> QTextStreamHolder as "QTextStreamHolder" {
+            QTextStreamHolder h;
+            h.owned_string.reset(new QString);
+            h.stream.reset(new QTextStream(h.owned_string.get(), QIODevice::ReadWrite));
+            return h;
+        });
+        QTextStream { holder, _phantom: PhantomData }
+    }
+}
+
+impl<'a> QTextStream<'a> {
+    /// Creates a stream operating on the given string.
+    ///
+    /// Refer to the Qt documentation of QTextStream::QTextStream(QString *, QIODevice::OpenMode)
+    pub fn from_string(s: &'a mut QString) -> Self {
+        let holder = cpp!(unsafe [s as "QString *"] -> QTextStreamHolder as "QTextStreamHolder" {
+            QTextStreamHolder h;
+            h.stream.reset(new QTextStream(s, QIODevice::ReadWrite));
+            return h;
+        });
+        QTextStream { holder, _phantom: PhantomData }
+    }
+
+    /// Creates a stream operating on the given byte array.
+    ///
+    /// Refer to the Qt documentation of QTextStream::QTextStream(QByteArray *, QIODevice::OpenMode)
+    pub fn from_byte_array(b: &'a mut QByteArray) -> Self {
+        let holder = cpp!(unsafe [b as "QByteArray *"] -> QTextStreamHolder as "QTextStreamHolder" {
+            QTextStreamHolder h;
+            h.stream.reset(new QTextStream(b, QIODevice::ReadWrite));
+            return h;
+        });
+        QTextStream { holder, _phantom: PhantomData }
+    }
+
+    /// Reads one line of text, without the trailing end-of-line characters.
+    /// Returns None at the end of the stream.
+    ///
+    /// Refer to the Qt documentation of QTextStream::readLine
+    pub fn read_line(&mut self) -> Option<QString> {
+        if self.at_end() {
+            return None;
+        }
+        let holder = &mut self.holder;
+        Some(cpp!(unsafe [holder as "QTextStreamHolder *"] -> QString as "QString" {
+            return holder->stream->readLine();
+        }))
+    }
+
+    /// Refer to the Qt documentation of QTextStream::readAll
+    pub fn read_all(&mut self) -> QString {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "QTextStreamHolder *"] -> QString as "QString" {
+            return holder->stream->readAll();
+        })
+    }
+
+    /// Writes the string to the stream.
+    ///
+    /// Refer to the Qt documentation of QTextStream::operator<<(const QString &)
+    pub fn write(&mut self, s: &str) {
+        let s = QString::from(s);
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "QTextStreamHolder *", s as "QString"] {
+            *holder->stream << s;
+        })
+    }
+
+    /// Flushes any buffered data to the underlying string or byte array.
+    ///
+    /// Refer to the Qt documentation of QTextStream::flush
+    pub fn flush(&mut self) {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "QTextStreamHolder *"] {
+            holder->stream->flush();
+        })
+    }
+
+    /// Sets the encoding used when operating on a byte array (e.g. "UTF-8" or "ISO-8859-1").
+    /// Returns false if the encoding is not supported.
+    ///
+    /// Refer to the Qt documentation of QTextStream::setCodec (Qt 5) or
+    /// QTextStream::setEncoding (Qt 6)
+    pub fn set_codec(&mut self, codec: &str) -> bool {
+        let codec = QByteArray::from(codec);
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "QTextStreamHolder *", codec as "QByteArray"] -> bool as "bool" {
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            auto encoding = QStringConverter::encodingForName(codec.constData());
+            if (!encoding)
+                return false;
+            holder->stream->setEncoding(*encoding);
+            return true;
+        #else
+            QTextCodec *c = QTextCodec::codecForName(codec);
+            if (!c)
+                return false;
+            holder->stream->setCodec(c);
+            return true;
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QTextStream::atEnd
+    pub fn at_end(&self) -> bool {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "QTextStreamHolder *"] -> bool as "bool" {
+            return holder->stream->atEnd();
+        })
+    }
+
+    /// Flushes the stream and returns a copy of the string it operates on, or an empty
+    /// string if the stream operates on a byte array.
+    pub fn to_string_output(&self) -> QString {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "QTextStreamHolder *"] -> QString as "QString" {
+            holder->stream->flush();
+            QString *s = holder->stream->string();
+            return s ? *s : QString();
+        })
+    }
+}
+
+impl Default for QTextStream<'static> {
+    fn default() -> Self {
+        QTextStream::new()
+    }
+}
@@ -0,0 +1,76 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Lookup of the Rust objects backing C++ QObjects.
+//!
+//! This module is only available with the `object_registry` feature.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::rc::{Rc, Weak};
+
+use crate::{QObject, QObjectPinned};
+
+thread_local! {
+    static REGISTRY: RefCell<HashMap<*const c_void, Weak<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// A thread-local registry mapping C++ QObject pointers to the `Rc<RefCell<T>>` which back them.
+///
+/// This allows finding the Rust object behind a `QObject*` received from Qt, for example in the
+/// arguments of a signal.
+///
+/// Only the objects registered with [`ObjectRegistry::register`] can be found: an object which
+/// is not in an `Rc` cannot be shared, so `get_or_create_cpp_object` does not register it.
+///
+/// The registry only holds weak references: an object is no longer found once all its `Rc`
+/// are dropped, which is also when its C++ object is destroyed. Objects are registered for the
+/// thread which registered them, which must be the thread of the object.
+pub enum ObjectRegistry {}
+
+impl ObjectRegistry {
+    /// Creates the C++ object of `obj` if needed, and registers it.
+    ///
+    /// Returns the pointer to the C++ object.
+    pub fn register<T: QObject + 'static>(obj: &Rc<RefCell<T>>) -> *mut c_void {
+        // Safety: the content of a Rc never moves
+        let ptr = unsafe { QObjectPinned::new(&**obj).get_or_create_cpp_object() };
+        let any: Rc<dyn Any> = obj.clone();
+        REGISTRY.with(|r| {
+            let mut r = r.borrow_mut();
+            r.retain(|_, weak| weak.strong_count() > 0);
+            r.insert(ptr as *const c_void, Rc::downgrade(&any));
+        });
+        ptr
+    }
+
+    /// Removes the object with this C++ pointer from the registry.
+    pub fn unregister(ptr: *const c_void) {
+        REGISTRY.with(|r| r.borrow_mut().remove(&ptr));
+    }
+
+    /// Returns the object of type `T` registered for this C++ pointer.
+    ///
+    /// Returns None if no object was registered for this pointer, if it was dropped, or if
+    /// it is not of type `T`.
+    pub fn lookup<T: QObject + 'static>(ptr: *const c_void) -> Option<Rc<RefCell<T>>> {
+        let any = REGISTRY.with(|r| r.borrow().get(&ptr).and_then(Weak::upgrade))?;
+        any.downcast::<RefCell<T>>().ok()
+    }
+}
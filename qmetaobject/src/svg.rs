@@ -0,0 +1,164 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Bindings for some classes of the Qt SVG module.
+//!
+//! This module requires the `svg` feature.
+use std::marker::PhantomData;
+
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtSvg/QSvgGenerator>
+}}
+
+cpp_class!(
+    unsafe struct QSvgGeneratorHolder as "std::unique_ptr<QSvgGenerator>"
+);
+
+/// Wrapper around [`QSvgGenerator`][class] class.
+///
+/// The SVG document is produced by painting on the generator with [`QPainter::begin`], and
+/// is written when the painter is ended. The generator borrows its output device, if any.
+///
+/// [class]: https://doc.qt.io/qt-5/qsvggenerator.html
+pub struct QSvgGenerator<'a> {
+    holder: QSvgGeneratorHolder,
+    _phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> QSvgGenerator<'a> {
+    /// Creates a generator without output file or device
+    pub fn new() -> Self {
+        let holder = cpp!(unsafe [] -> QSvgGeneratorHolder as "std::unique_ptr<QSvgGenerator>" {
+            return std::unique_ptr<QSvgGenerator>(new QSvgGenerator);
+        });
+        QSvgGenerator { holder, _phantom: PhantomData }
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::setFileName
+    pub fn set_file_name(&mut self, path: &str) {
+        let holder = &mut self.holder;
+        let path = QString::from(path);
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *", path as "QString"] {
+            (*holder)->setFileName(path);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::fileName
+    pub fn file_name(&self) -> QString {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "const std::unique_ptr<QSvgGenerator> *"] -> QString as "QString" {
+            return (*holder)->fileName();
+        })
+    }
+
+    /// Writes the document to the buffer instead of a file.
+    ///
+    /// Refer to the Qt documentation of QSvgGenerator::setOutputDevice
+    pub fn set_output_device(&mut self, device: &'a mut QBuffer) {
+        let holder = &mut self.holder;
+        let device = device.cpp_ptr();
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *", device as "QIODevice *"] {
+            (*holder)->setOutputDevice(device);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::setSize
+    pub fn set_size(&mut self, size: QSize) {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *", size as "QSize"] {
+            (*holder)->setSize(size);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::size
+    pub fn size(&self) -> QSize {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "const std::unique_ptr<QSvgGenerator> *"] -> QSize as "QSize" {
+            return (*holder)->size();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::setViewBox
+    pub fn set_view_box(&mut self, rect: QRectF) {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *", rect as "QRectF"] {
+            (*holder)->setViewBox(rect);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::viewBoxF
+    pub fn view_box(&self) -> QRectF {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "const std::unique_ptr<QSvgGenerator> *"] -> QRectF as "QRectF" {
+            return (*holder)->viewBoxF();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::setTitle
+    pub fn set_title(&mut self, title: &str) {
+        let holder = &mut self.holder;
+        let title = QString::from(title);
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *", title as "QString"] {
+            (*holder)->setTitle(title);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::title
+    pub fn title(&self) -> QString {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "const std::unique_ptr<QSvgGenerator> *"] -> QString as "QString" {
+            return (*holder)->title();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::setDescription
+    pub fn set_description(&mut self, description: &str) {
+        let holder = &mut self.holder;
+        let description = QString::from(description);
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *", description as "QString"] {
+            (*holder)->setDescription(description);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSvgGenerator::description
+    pub fn description(&self) -> QString {
+        let holder = &self.holder;
+        cpp!(unsafe [holder as "const std::unique_ptr<QSvgGenerator> *"] -> QString as "QString" {
+            return (*holder)->description();
+        })
+    }
+}
+
+impl Default for QSvgGenerator<'_> {
+    fn default() -> Self {
+        QSvgGenerator::new()
+    }
+}
+
+impl QPaintDevice for QSvgGenerator<'_> {
+    fn paint_device_ptr(&mut self) -> *mut c_void {
+        let holder = &mut self.holder;
+        cpp!(unsafe [holder as "std::unique_ptr<QSvgGenerator> *"] -> *mut c_void as "QPaintDevice *" {
+            return holder->get();
+        })
+    }
+}
@@ -0,0 +1,443 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Bindings for the classes of the QtWidgets module.
+//!
+//! The widgets are accessed through Rust objects which hold a guarded pointer to the C++ widget,
+//! so they never dangle: once the C++ widget is destroyed, all the functions do nothing and
+//! return default values.
+//!
+//! Dropping the Rust object of a widget deletes the widget, unless it has a parent, in which case
+//! the parent owns it. Widgets can therefore be created from Rust and then given to a parent
+//! widget or layout, and accessors such as [`QTableView::horizontal_header`] return objects that
+//! don't delete the widget they refer to.
+//!
+//! A `QApplication` must exist before any widget is created. It is created by
+//! [`QmlEngine::new`](../qtdeclarative/struct.QmlEngine.html#method.new).
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    #include <QtGui/QAction>
+#else
+    #include <QtWidgets/QAction>
+#endif
+    #include <QtWidgets/QLayout>
+    #include <QtWidgets/QSizePolicy>
+    #include <QtWidgets/QWidget>
+}}
+
+/// Implements the common boilerplate of the widget wrappers: the struct holding the wrapper of
+/// the base class, `Deref` to it, and the internal `from_widget` constructor.
+///
+/// Concrete classes also get a `Drop` implementation which deletes the widget unless it has a
+/// parent, while abstract classes are plain handles which can be cloned.
+macro_rules! widget_wrapper {
+    ($(#[$attr:meta])* abstract $name:ident : $base:ident) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        #[repr(transparent)]
+        pub struct $name($base);
+
+        widget_wrapper!(@common $name : $base);
+    };
+    ($(#[$attr:meta])* $name:ident : $base:ident) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        pub struct $name($base);
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                let widget: &$crate::widgets::QWidget = &self.0;
+                widget.delete_if_unowned();
+            }
+        }
+
+        widget_wrapper!(@common $name : $base);
+    };
+    (@common $name:ident : $base:ident) => {
+        impl std::ops::Deref for $name {
+            type Target = $base;
+            fn deref(&self) -> &$base {
+                &self.0
+            }
+        }
+
+        impl $name {
+            #[allow(dead_code)]
+            pub(crate) fn from_widget(widget: $crate::widgets::QWidget) -> $name {
+                $name($base::from_widget(widget))
+            }
+        }
+    };
+}
+
+mod actions;
+mod buttons;
+#[cfg(feature = "charts")]
+mod charts;
+mod completer;
+mod containers;
+mod datawidgetmapper;
+mod dialogs;
+mod display;
+mod graphicsview;
+mod inputs;
+mod itemviews;
+mod layouts;
+mod mainwindow;
+#[cfg(feature = "printsupport")]
+mod printsupport;
+mod scrollarea;
+#[cfg(feature = "webengine-widgets")]
+#[cfg(not(any(qt_6_0, qt_6_1)))]
+#[cfg(not(all(target_os = "windows", not(target_env = "msvc"))))]
+mod webengineview;
+mod wizard;
+
+pub use actions::*;
+pub use buttons::*;
+#[cfg(feature = "charts")]
+pub use charts::*;
+pub use completer::*;
+pub use containers::*;
+pub use datawidgetmapper::*;
+pub use dialogs::*;
+pub use display::*;
+pub use graphicsview::*;
+pub use inputs::*;
+pub use itemviews::*;
+pub use layouts::*;
+pub use mainwindow::*;
+#[cfg(feature = "printsupport")]
+pub use printsupport::*;
+pub use scrollarea::*;
+#[cfg(feature = "webengine-widgets")]
+#[cfg(not(any(qt_6_0, qt_6_1)))]
+#[cfg(not(all(target_os = "windows", not(target_env = "msvc"))))]
+pub use webengineview::*;
+pub use wizard::*;
+
+cpp_class!(
+    /// A guarded, non-owning pointer to a [`QWidget`][class].
+    ///
+    /// All the widget wrappers dereference to this type, so the functions defined here are
+    /// available on all of them.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qwidget.html
+    #[derive(Clone)]
+    pub unsafe struct QWidget as "QPointer<QWidget>"
+);
+
+impl QWidget {
+    #[allow(dead_code)]
+    pub(crate) fn from_widget(widget: QWidget) -> QWidget {
+        widget
+    }
+
+    /// Deletes the widget, unless it is owned by a parent.
+    pub(crate) fn delete_if_unowned(&self) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] {
+            if (*self && !(*self)->parent())
+                delete self->data();
+        })
+    }
+
+    /// Returns a pointer to the underlying QWidget, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    ///
+    /// Returns a null pointer if the widget was destroyed.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> *mut c_void as "QWidget *" {
+            return self->data();
+        })
+    }
+
+    /// Returns true if the widget was destroyed.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::show
+    pub fn show(&self) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] {
+            if (*self)
+                (*self)->show();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::hide
+    pub fn hide(&self) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] {
+            if (*self)
+                (*self)->hide();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::close
+    pub fn close(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> bool as "bool" {
+            return *self && (*self)->close();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::setVisible
+    pub fn set_visible(&self, visible: bool) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", visible as "bool"] {
+            if (*self)
+                (*self)->setVisible(visible);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::isVisible
+    pub fn is_visible(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> bool as "bool" {
+            return *self && (*self)->isVisible();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::setEnabled
+    pub fn set_enabled(&self, enabled: bool) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", enabled as "bool"] {
+            if (*self)
+                (*self)->setEnabled(enabled);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::isEnabled
+    pub fn is_enabled(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> bool as "bool" {
+            return *self && (*self)->isEnabled();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::resize
+    pub fn resize(&self, width: i32, height: i32) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", width as "int", height as "int"] {
+            if (*self)
+                (*self)->resize(width, height);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::width
+    pub fn width(&self) -> i32 {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> i32 as "int" {
+            return *self ? (*self)->width() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::height
+    pub fn height(&self) -> i32 {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> i32 as "int" {
+            return *self ? (*self)->height() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::setWindowTitle
+    pub fn set_window_title(&self, title: QString) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", title as "QString"] {
+            if (*self)
+                (*self)->setWindowTitle(title);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::windowTitle
+    pub fn window_title(&self) -> QString {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> QString as "QString" {
+            return *self ? (*self)->windowTitle() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::setToolTip
+    pub fn set_tool_tip(&self, tool_tip: QString) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", tool_tip as "QString"] {
+            if (*self)
+                (*self)->setToolTip(tool_tip);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::setSizePolicy
+    pub fn set_size_policy(&self, policy: QSizePolicy) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", policy as "QSizePolicy"] {
+            if (*self)
+                (*self)->setSizePolicy(policy);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::sizePolicy
+    pub fn size_policy(&self) -> QSizePolicy {
+        cpp!(unsafe [self as "const QPointer<QWidget> *"] -> QSizePolicy as "QSizePolicy" {
+            return *self ? (*self)->sizePolicy() : QSizePolicy();
+        })
+    }
+
+    /// Appends the action to the list of actions of the widget, which shows it if it is a menu
+    /// or a tool bar. The widget does not take ownership of the action.
+    ///
+    /// Refer to the Qt documentation of QWidget::addAction
+    pub fn add_action(&self, action: &QAction) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", action as "const QPointer<QAction> *"] {
+            if (*self && *action)
+                (*self)->addAction(action->data());
+        })
+    }
+
+    /// Installs the layout on the widget, which then owns the layout and the widgets it
+    /// contains.
+    ///
+    /// Refer to the Qt documentation of QWidget::setLayout
+    pub fn set_layout(&self, layout: &QLayout) {
+        cpp!(unsafe [self as "const QPointer<QWidget> *", layout as "const QPointer<QLayout> *"] {
+            if (*self && *layout)
+                (*self)->setLayout(layout->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QWidget::layout
+    pub fn layout(&self) -> Option<QLayout> {
+        let layout = cpp!(unsafe [self as "const QPointer<QWidget> *"] -> QLayout as "QPointer<QLayout>" {
+            return *self ? (*self)->layout() : nullptr;
+        });
+        if layout.is_null() {
+            None
+        } else {
+            Some(layout)
+        }
+    }
+
+    /// Returns a handle to the parent widget, if any.
+    ///
+    /// Refer to the Qt documentation of QWidget::parentWidget
+    pub fn parent_widget(&self) -> Option<QWidget> {
+        let parent = cpp!(unsafe [self as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+            return *self ? (*self)->parentWidget() : nullptr;
+        });
+        if parent.is_null() {
+            None
+        } else {
+            Some(parent)
+        }
+    }
+
+    /// Sets the parent of the widget, which then owns it.
+    ///
+    /// Refer to the Qt documentation of QWidget::setParent
+    pub fn set_parent(&self, parent: Option<&QWidget>) {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        cpp!(unsafe [self as "const QPointer<QWidget> *", parent as "QWidget *"] {
+            if (*self)
+                (*self)->setParent(parent);
+        })
+    }
+}
+
+/// Wrapper around [`QSizePolicy::Policy`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qsizepolicy.html#Policy-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizePolicy {
+    Fixed = 0,
+    Minimum = 1,
+    Maximum = 4,
+    Preferred = 5,
+    MinimumExpanding = 3,
+    Expanding = 7,
+    Ignored = 13,
+}
+
+cpp_class!(
+    /// Wrapper around [`QSizePolicy`][class] class, the layout hints of a widget.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qsizepolicy.html
+    #[derive(Default, Clone, Copy, PartialEq)]
+    pub unsafe struct QSizePolicy as "QSizePolicy"
+);
+
+impl QSizePolicy {
+    /// Creates a size policy with the given horizontal and vertical policies.
+    pub fn new(horizontal: SizePolicy, vertical: SizePolicy) -> QSizePolicy {
+        cpp!(unsafe [horizontal as "QSizePolicy::Policy", vertical as "QSizePolicy::Policy"]
+                -> QSizePolicy as "QSizePolicy" {
+            return QSizePolicy(horizontal, vertical);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSizePolicy::horizontalPolicy
+    pub fn horizontal_policy(&self) -> SizePolicy {
+        cpp!(unsafe [self as "const QSizePolicy *"] -> SizePolicy as "QSizePolicy::Policy" {
+            return self->horizontalPolicy();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSizePolicy::verticalPolicy
+    pub fn vertical_policy(&self) -> SizePolicy {
+        cpp!(unsafe [self as "const QSizePolicy *"] -> SizePolicy as "QSizePolicy::Policy" {
+            return self->verticalPolicy();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSizePolicy::setHorizontalStretch
+    pub fn set_horizontal_stretch(&mut self, factor: u8) {
+        cpp!(unsafe [self as "QSizePolicy *", factor as "uchar"] {
+            self->setHorizontalStretch(factor);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSizePolicy::horizontalStretch
+    pub fn horizontal_stretch(&self) -> u8 {
+        cpp!(unsafe [self as "const QSizePolicy *"] -> u8 as "uchar" {
+            return self->horizontalStretch();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSizePolicy::setVerticalStretch
+    pub fn set_vertical_stretch(&mut self, factor: u8) {
+        cpp!(unsafe [self as "QSizePolicy *", factor as "uchar"] {
+            self->setVerticalStretch(factor);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSizePolicy::verticalStretch
+    pub fn vertical_stretch(&self) -> u8 {
+        cpp!(unsafe [self as "const QSizePolicy *"] -> u8 as "uchar" {
+            return self->verticalStretch();
+        })
+    }
+}
+
+impl From<QSizePolicy> for QVariant {
+    fn from(policy: QSizePolicy) -> QVariant {
+        cpp!(unsafe [policy as "QSizePolicy"] -> QVariant as "QVariant" {
+            return QVariant::fromValue(policy);
+        })
+    }
+}
+
+impl std::fmt::Debug for QSizePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("QSizePolicy")
+            .field("horizontal", &self.horizontal_policy())
+            .field("vertical", &self.vertical_policy())
+            .finish()
+    }
+}
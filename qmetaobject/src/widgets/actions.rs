@@ -0,0 +1,218 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QMenu>
+}}
+
+cpp_class!(
+    /// A guarded, non-owning pointer to a [`QAction`][class].
+    ///
+    /// Actions are always owned by a parent, such as the widget they are created for or the
+    /// menu which created them.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qaction.html
+    #[derive(Clone)]
+    pub unsafe struct QAction as "QPointer<QAction>"
+);
+
+impl QAction {
+    /// Creates a new action with the given text, owned by `parent`. The action still needs to
+    /// be added to a widget, for example with [`QWidget::add_action`], to be shown.
+    pub fn new(text: &str, parent: &QWidget) -> QAction {
+        let text = QString::from(text);
+        cpp!(unsafe [text as "QString", parent as "const QPointer<QWidget> *"] -> QAction as "QPointer<QAction>" {
+            return new QAction(text, parent->data());
+        })
+    }
+
+    /// Returns a guarded pointer to the action pointed to by `ptr`, for example the argument of
+    /// a signal such as [`QToolButton::triggered_signal`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null or a valid pointer to a QAction.
+    pub unsafe fn from_ptr(ptr: *mut c_void) -> QAction {
+        cpp!([ptr as "QAction *"] -> QAction as "QPointer<QAction>" {
+            return ptr;
+        })
+    }
+
+    /// Returns a pointer to the underlying QAction, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    ///
+    /// Returns a null pointer if the action was destroyed.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QPointer<QAction> *"] -> *mut c_void as "QAction *" {
+            return self->data();
+        })
+    }
+
+    /// Returns true if the action was destroyed.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QAction> *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::text
+    pub fn text(&self) -> QString {
+        cpp!(unsafe [self as "const QPointer<QAction> *"] -> QString as "QString" {
+            return *self ? (*self)->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::setText
+    pub fn set_text(&self, text: &str) {
+        let text = QString::from(text);
+        cpp!(unsafe [self as "const QPointer<QAction> *", text as "QString"] {
+            if (*self)
+                (*self)->setText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::setIcon
+    pub fn set_icon(&self, icon: QIcon) {
+        cpp!(unsafe [self as "const QPointer<QAction> *", icon as "QIcon"] {
+            if (*self)
+                (*self)->setIcon(icon);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::setEnabled
+    pub fn set_enabled(&self, enabled: bool) {
+        cpp!(unsafe [self as "const QPointer<QAction> *", enabled as "bool"] {
+            if (*self)
+                (*self)->setEnabled(enabled);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::setCheckable
+    pub fn set_checkable(&self, checkable: bool) {
+        cpp!(unsafe [self as "const QPointer<QAction> *", checkable as "bool"] {
+            if (*self)
+                (*self)->setCheckable(checkable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::isChecked
+    pub fn is_checked(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QAction> *"] -> bool as "bool" {
+            return *self && (*self)->isChecked();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::setChecked
+    pub fn set_checked(&self, checked: bool) {
+        cpp!(unsafe [self as "const QPointer<QAction> *", checked as "bool"] {
+            if (*self)
+                (*self)->setChecked(checked);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::trigger
+    pub fn trigger(&self) {
+        cpp!(unsafe [self as "const QPointer<QAction> *"] {
+            if (*self)
+                (*self)->trigger();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAction::triggered
+    pub fn triggered_signal() -> Signal<fn(bool)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAction::triggered;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAction::toggled
+    pub fn toggled_signal() -> Signal<fn(bool)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAction::toggled;
+            }))
+        }
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QMenu`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qmenu.html
+    QMenu: QWidget
+);
+
+impl QMenu {
+    /// Creates a new menu without parent, with the given title
+    pub fn new(title: &str) -> QMenu {
+        let title = QString::from(title);
+        QMenu::from_widget(cpp!(unsafe [title as "QString"] -> QWidget as "QPointer<QWidget>" {
+            return new QMenu(title);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QMenu::title
+    pub fn title(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto menu = qobject_cast<QMenu *>(widget->data());
+            return menu ? menu->title() : QString();
+        })
+    }
+
+    /// Creates an action with the given text, owned by the menu, and appends it to the menu.
+    ///
+    /// Refer to the Qt documentation of QMenu::addAction
+    pub fn add_action_with_text(&self, text: &str) -> QAction {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] -> QAction as "QPointer<QAction>" {
+            auto menu = qobject_cast<QMenu *>(widget->data());
+            return menu ? menu->addAction(text) : nullptr;
+        })
+    }
+
+    /// Appends a separator, which is an action owned by the menu.
+    ///
+    /// Refer to the Qt documentation of QMenu::addSeparator
+    pub fn add_separator(&self) -> QAction {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QAction as "QPointer<QAction>" {
+            auto menu = qobject_cast<QMenu *>(widget->data());
+            return menu ? menu->addSeparator() : nullptr;
+        })
+    }
+
+    /// The argument of the signal is a pointer to the QAction, see [`QAction::from_ptr`].
+    ///
+    /// Refer to the Qt documentation of QMenu::triggered
+    pub fn triggered_signal() -> Signal<fn(*mut c_void)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QMenu::triggered;
+            }))
+        }
+    }
+}
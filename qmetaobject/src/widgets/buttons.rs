@@ -0,0 +1,398 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use super::{QMenu, QWidget};
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QCheckBox>
+    #include <QtWidgets/QMenu>
+    #include <QtWidgets/QPushButton>
+    #include <QtWidgets/QRadioButton>
+    #include <QtWidgets/QToolButton>
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QAbstractButton`][class] class, the base class of the buttons.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qabstractbutton.html
+    abstract QAbstractButton: QWidget
+);
+
+impl QAbstractButton {
+    /// Refer to the Qt documentation of QAbstractButton::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto button = qobject_cast<QAbstractButton *>(widget->data());
+            return button ? button->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::setText
+    pub fn set_text(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto button = qobject_cast<QAbstractButton *>(widget->data()))
+                button->setText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::isCheckable
+    pub fn is_checkable(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto button = qobject_cast<QAbstractButton *>(widget->data());
+            return button && button->isCheckable();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::setCheckable
+    pub fn set_checkable(&self, checkable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", checkable as "bool"] {
+            if (auto button = qobject_cast<QAbstractButton *>(widget->data()))
+                button->setCheckable(checkable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::isChecked
+    pub fn is_checked(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto button = qobject_cast<QAbstractButton *>(widget->data());
+            return button && button->isChecked();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::setChecked
+    pub fn set_checked(&self, checked: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", checked as "bool"] {
+            if (auto button = qobject_cast<QAbstractButton *>(widget->data()))
+                button->setChecked(checked);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::setIcon
+    pub fn set_icon(&self, icon: QIcon) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", icon as "QIcon"] {
+            if (auto button = qobject_cast<QAbstractButton *>(widget->data()))
+                button->setIcon(icon);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::icon
+    pub fn icon(&self) -> QIcon {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QIcon as "QIcon" {
+            auto button = qobject_cast<QAbstractButton *>(widget->data());
+            return button ? button->icon() : QIcon();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::click
+    pub fn click(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto button = qobject_cast<QAbstractButton *>(widget->data()))
+                button->click();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::clicked
+    pub fn clicked_signal() -> Signal<fn(bool)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractButton::clicked;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::pressed
+    pub fn pressed_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractButton::pressed;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::released
+    pub fn released_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractButton::released;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAbstractButton::toggled
+    pub fn toggled_signal() -> Signal<fn(bool)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractButton::toggled;
+            }))
+        }
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QCheckBox`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcheckbox.html
+    QCheckBox: QAbstractButton
+);
+
+impl QCheckBox {
+    /// Creates a new check box without parent, with the given text
+    pub fn new(text: &str) -> QCheckBox {
+        let text = QString::from(text);
+        QCheckBox::from_widget(cpp!(unsafe [text as "QString"] -> QWidget as "QPointer<QWidget>" {
+            return new QCheckBox(text);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QCheckBox::checkState
+    pub fn check_state(&self) -> CheckState {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> CheckState as "Qt::CheckState" {
+            auto check = qobject_cast<QCheckBox *>(widget->data());
+            return check ? check->checkState() : Qt::Unchecked;
+        })
+    }
+
+    /// Refer to the Qt documentation of QCheckBox::setCheckState
+    pub fn set_check_state(&self, state: CheckState) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", state as "Qt::CheckState"] {
+            if (auto check = qobject_cast<QCheckBox *>(widget->data()))
+                check->setCheckState(state);
+        })
+    }
+
+    /// Refer to the Qt documentation of QCheckBox::isTristate
+    pub fn is_tristate(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto check = qobject_cast<QCheckBox *>(widget->data());
+            return check && check->isTristate();
+        })
+    }
+
+    /// Refer to the Qt documentation of QCheckBox::setTristate
+    pub fn set_tristate(&self, tristate: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", tristate as "bool"] {
+            if (auto check = qobject_cast<QCheckBox *>(widget->data()))
+                check->setTristate(tristate);
+        })
+    }
+
+    /// The argument of the signal is the [`CheckState`] as an integer.
+    ///
+    /// Refer to the Qt documentation of QCheckBox::stateChanged
+    pub fn state_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QCheckBox::stateChanged;
+            }))
+        }
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QRadioButton`][class] class.
+    ///
+    /// Radio buttons with the same parent widget are exclusive.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qradiobutton.html
+    QRadioButton: QAbstractButton
+);
+
+impl QRadioButton {
+    /// Creates a new radio button without parent, with the given text
+    pub fn new(text: &str) -> QRadioButton {
+        let text = QString::from(text);
+        QRadioButton::from_widget(
+            cpp!(unsafe [text as "QString"] -> QWidget as "QPointer<QWidget>" {
+                return new QRadioButton(text);
+            }),
+        )
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QPushButton`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qpushbutton.html
+    QPushButton: QAbstractButton
+);
+
+impl QPushButton {
+    /// Creates a new push button without parent, with the given text
+    pub fn new(text: &str) -> QPushButton {
+        let text = QString::from(text);
+        QPushButton::from_widget(
+            cpp!(unsafe [text as "QString"] -> QWidget as "QPointer<QWidget>" {
+                return new QPushButton(text);
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QPushButton::isFlat
+    pub fn is_flat(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto button = qobject_cast<QPushButton *>(widget->data());
+            return button && button->isFlat();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPushButton::setFlat
+    pub fn set_flat(&self, flat: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", flat as "bool"] {
+            if (auto button = qobject_cast<QPushButton *>(widget->data()))
+                button->setFlat(flat);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPushButton::isDefault
+    pub fn is_default(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto button = qobject_cast<QPushButton *>(widget->data());
+            return button && button->isDefault();
+        })
+    }
+
+    /// Makes the button the default button of its dialog, pressed when the user hits enter.
+    ///
+    /// Refer to the Qt documentation of QPushButton::setDefault
+    pub fn set_default(&self, default: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", default as "bool"] {
+            if (auto button = qobject_cast<QPushButton *>(widget->data()))
+                button->setDefault(default);
+        })
+    }
+
+    /// Sets the popup menu of the button. The button does not take ownership of the menu.
+    ///
+    /// Refer to the Qt documentation of QPushButton::setMenu
+    pub fn set_menu(&self, menu: &QMenu) {
+        let widget: &QWidget = self;
+        let menu: &QWidget = menu;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", menu as "const QPointer<QWidget> *"] {
+            if (auto button = qobject_cast<QPushButton *>(widget->data()))
+                button->setMenu(qobject_cast<QMenu *>(menu->data()));
+        })
+    }
+}
+
+/// Wrapper around [`QToolButton::ToolButtonPopupMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qtoolbutton.html#ToolButtonPopupMode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolButtonPopupMode {
+    DelayedPopup = 0,
+    MenuButtonPopup = 1,
+    InstantPopup = 2,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QToolButton`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtoolbutton.html
+    QToolButton: QAbstractButton
+);
+
+impl QToolButton {
+    /// Creates a new tool button without parent
+    pub fn new() -> QToolButton {
+        QToolButton::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QToolButton;
+        }))
+    }
+
+    /// Refer to the Qt documentation of QToolButton::setPopupMode
+    pub fn set_popup_mode(&self, mode: ToolButtonPopupMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", mode as "QToolButton::ToolButtonPopupMode"] {
+            if (auto button = qobject_cast<QToolButton *>(widget->data()))
+                button->setPopupMode(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolButton::popupMode
+    pub fn popup_mode(&self) -> ToolButtonPopupMode {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"]
+                -> ToolButtonPopupMode as "QToolButton::ToolButtonPopupMode" {
+            auto button = qobject_cast<QToolButton *>(widget->data());
+            return button ? button->popupMode() : QToolButton::DelayedPopup;
+        })
+    }
+
+    /// Sets the popup menu of the button. The button does not take ownership of the menu.
+    ///
+    /// Refer to the Qt documentation of QToolButton::setMenu
+    pub fn set_menu(&self, menu: &QMenu) {
+        let widget: &QWidget = self;
+        let menu: &QWidget = menu;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", menu as "const QPointer<QWidget> *"] {
+            if (auto button = qobject_cast<QToolButton *>(widget->data()))
+                button->setMenu(qobject_cast<QMenu *>(menu->data()));
+        })
+    }
+
+    /// Sets the action shown by the button, whose text and icon the button takes, and which is
+    /// triggered when the button is clicked.
+    ///
+    /// Refer to the Qt documentation of QToolButton::setDefaultAction
+    pub fn set_default_action(&self, action: &QAction) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", action as "const QPointer<QAction> *"] {
+            if (auto button = qobject_cast<QToolButton *>(widget->data()))
+                button->setDefaultAction(action->data());
+        })
+    }
+
+    /// The argument of the signal is a pointer to the QAction, see [`QAction::from_ptr`].
+    ///
+    /// Refer to the Qt documentation of QToolButton::triggered
+    pub fn triggered_signal() -> Signal<fn(*mut c_void)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QToolButton::triggered;
+            }))
+        }
+    }
+}
+
+impl Default for QToolButton {
+    fn default() -> Self {
+        QToolButton::new()
+    }
+}
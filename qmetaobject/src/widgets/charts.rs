@@ -0,0 +1,387 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtCharts/QAbstractAxis>
+    #include <QtCharts/QChart>
+    #include <QtCharts/QChartView>
+    #include <QtCharts/QLegend>
+    #include <QtCharts/QLineSeries>
+
+#if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+    QT_CHARTS_USE_NAMESPACE
+#endif
+
+    struct QChartHolder {
+        QPointer<QChart> chart;
+
+        QChartHolder() : chart(new QChart) {}
+        ~QChartHolder() {
+            if (chart && !chart->scene())
+                delete chart.data();
+        }
+    };
+
+    struct QLineSeriesHolder {
+        QPointer<QLineSeries> series;
+
+        QLineSeriesHolder() : series(new QLineSeries) {}
+        ~QLineSeriesHolder() {
+            if (series && !series->chart())
+                delete series.data();
+        }
+    };
+}}
+
+cpp_class!(
+    /// Wrapper around [`QLineSeries`][class] class.
+    ///
+    /// The series is deleted when this object is dropped, unless it was added to a chart with
+    /// [`QChart::add_series`], which then owns it. Requires the `charts` feature.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlineseries.html
+    #[derive(Default)]
+    pub unsafe struct QLineSeries as "QLineSeriesHolder"
+);
+
+impl QLineSeries {
+    /// Creates a new empty series
+    pub fn new() -> QLineSeries {
+        Default::default()
+    }
+
+    /// Returns a pointer to the underlying QLineSeries, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QLineSeriesHolder *"] -> *mut c_void as "QLineSeries *" {
+            return self->series.data();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSeries::setName
+    pub fn set_name(&self, name: &str) {
+        let name = QString::from(name);
+        cpp!(unsafe [self as "const QLineSeriesHolder *", name as "QString"] {
+            if (self->series)
+                self->series->setName(name);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSeries::name
+    pub fn name(&self) -> QString {
+        cpp!(unsafe [self as "const QLineSeriesHolder *"] -> QString as "QString" {
+            return self->series ? self->series->name() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QXYSeries::append
+    pub fn append(&self, x: f64, y: f64) {
+        cpp!(unsafe [self as "const QLineSeriesHolder *", x as "double", y as "double"] {
+            if (self->series)
+                self->series->append(x, y);
+        })
+    }
+
+    /// Replaces all the points of the series, which is much faster than clearing it and
+    /// appending the points one by one.
+    ///
+    /// Refer to the Qt documentation of QXYSeries::replace
+    pub fn replace_all(&self, points: &[(f64, f64)]) {
+        let points: Vec<QPointF> =
+            points.iter().map(|&(x, y)| QPointF { x: x as qreal, y: y as qreal }).collect();
+        let ptr = points.as_ptr();
+        let len = points.len();
+        cpp!(unsafe [self as "const QLineSeriesHolder *", ptr as "const QPointF *", len as "size_t"] {
+            if (!self->series)
+                return;
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            QList<QPointF> list(ptr, ptr + len);
+        #else
+            QVector<QPointF> list;
+            list.reserve(len);
+            for (size_t i = 0; i < len; ++i)
+                list.append(ptr[i]);
+        #endif
+            self->series->replace(list);
+        })
+    }
+
+    /// Refer to the Qt documentation of QXYSeries::count
+    pub fn count(&self) -> i32 {
+        cpp!(unsafe [self as "const QLineSeriesHolder *"] -> i32 as "int" {
+            return self->series ? self->series->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QXYSeries::at
+    pub fn at(&self, index: i32) -> QPointF {
+        cpp!(unsafe [self as "const QLineSeriesHolder *", index as "int"] -> QPointF as "QPointF" {
+            return self->series && index >= 0 && index < self->series->count()
+                ? self->series->at(index) : QPointF();
+        })
+    }
+
+    /// Refer to the Qt documentation of QXYSeries::clear
+    pub fn clear(&self) {
+        cpp!(unsafe [self as "const QLineSeriesHolder *"] {
+            if (self->series)
+                self->series->clear();
+        })
+    }
+
+    /// Refer to the Qt documentation of QXYSeries::clicked
+    pub fn clicked_signal() -> Signal<fn(QPointF)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLineSeries::clicked;
+            }))
+        }
+    }
+}
+
+cpp_class!(
+    /// A guarded, non-owning pointer to a [`QAbstractAxis`][class] of a chart.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qabstractaxis.html
+    #[derive(Clone)]
+    pub unsafe struct QAbstractAxis as "QPointer<QAbstractAxis>"
+);
+
+impl QAbstractAxis {
+    /// Returns true if the axis was destroyed.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QAbstractAxis> *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAxis::orientation
+    pub fn orientation(&self) -> Orientation {
+        cpp!(unsafe [self as "const QPointer<QAbstractAxis> *"] -> Orientation as "Qt::Orientation" {
+            return *self ? (*self)->orientation() : Qt::Horizontal;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAxis::setRange
+    pub fn set_range(&self, min: QVariant, max: QVariant) {
+        cpp!(unsafe [self as "const QPointer<QAbstractAxis> *", min as "QVariant", max as "QVariant"] {
+            if (*self)
+                (*self)->setRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAxis::setTitleText
+    pub fn set_title_text(&self, title: &str) {
+        let title = QString::from(title);
+        cpp!(unsafe [self as "const QPointer<QAbstractAxis> *", title as "QString"] {
+            if (*self)
+                (*self)->setTitleText(title);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractAxis::titleText
+    pub fn title_text(&self) -> QString {
+        cpp!(unsafe [self as "const QPointer<QAbstractAxis> *"] -> QString as "QString" {
+            return *self ? (*self)->titleText() : QString();
+        })
+    }
+}
+
+cpp_class!(
+    /// A guarded, non-owning pointer to the [`QLegend`][class] of a chart.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlegend.html
+    #[derive(Clone)]
+    pub unsafe struct QLegend as "QPointer<QLegend>"
+);
+
+impl QLegend {
+    /// Refer to the Qt documentation of QGraphicsItem::isVisible
+    pub fn is_visible(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QLegend> *"] -> bool as "bool" {
+            return *self && (*self)->isVisible();
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::setVisible
+    pub fn set_visible(&self, visible: bool) {
+        cpp!(unsafe [self as "const QPointer<QLegend> *", visible as "bool"] {
+            if (*self)
+                (*self)->setVisible(visible);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLegend::setAlignment
+    pub fn set_alignment(&self, alignment: Alignment) {
+        cpp!(unsafe [self as "const QPointer<QLegend> *", alignment as "Qt::Alignment"] {
+            if (*self)
+                (*self)->setAlignment(alignment);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLegend::alignment
+    pub fn alignment(&self) -> Alignment {
+        cpp!(unsafe [self as "const QPointer<QLegend> *"] -> Alignment as "Qt::Alignment" {
+            return *self ? (*self)->alignment() : Qt::Alignment();
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QChart`][class] class.
+    ///
+    /// The chart is deleted when this object is dropped, unless it is shown in a
+    /// [`QChartView`], which then owns it. Requires the `charts` feature.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qchart.html
+    #[derive(Default)]
+    pub unsafe struct QChart as "QChartHolder"
+);
+
+impl QChart {
+    /// Creates a new empty chart
+    pub fn new() -> QChart {
+        Default::default()
+    }
+
+    /// Adds the series, which is then owned by the chart.
+    ///
+    /// Refer to the Qt documentation of QChart::addSeries
+    pub fn add_series(&self, series: &QLineSeries) {
+        cpp!(unsafe [self as "const QChartHolder *", series as "const QLineSeriesHolder *"] {
+            if (self->chart && series->series)
+                self->chart->addSeries(series->series);
+        })
+    }
+
+    /// Refer to the Qt documentation of QChart::setTitle
+    pub fn set_title(&self, title: &str) {
+        let title = QString::from(title);
+        cpp!(unsafe [self as "const QChartHolder *", title as "QString"] {
+            if (self->chart)
+                self->chart->setTitle(title);
+        })
+    }
+
+    /// Refer to the Qt documentation of QChart::title
+    pub fn title(&self) -> QString {
+        cpp!(unsafe [self as "const QChartHolder *"] -> QString as "QString" {
+            return self->chart ? self->chart->title() : QString();
+        })
+    }
+
+    /// Creates axes fitting the series already added to the chart.
+    ///
+    /// Refer to the Qt documentation of QChart::createDefaultAxes
+    pub fn create_default_axes(&self) {
+        cpp!(unsafe [self as "const QChartHolder *"] {
+            if (self->chart)
+                self->chart->createDefaultAxes();
+        })
+    }
+
+    /// Returns the axes of the chart with the given orientation.
+    ///
+    /// Refer to the Qt documentation of QChart::axes
+    pub fn axes(&self, orientation: Orientation) -> Vec<QAbstractAxis> {
+        let count = cpp!(unsafe [self as "const QChartHolder *", orientation as "Qt::Orientation"]
+                -> usize as "size_t" {
+            return self->chart ? self->chart->axes(orientation).size() : 0;
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [self as "const QChartHolder *", orientation as "Qt::Orientation", i as "size_t"]
+                        -> QAbstractAxis as "QPointer<QAbstractAxis>" {
+                    return self->chart->axes(orientation).at(i);
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the legend of the chart, which is owned by the chart.
+    ///
+    /// Refer to the Qt documentation of QChart::legend
+    pub fn legend(&self) -> QLegend {
+        cpp!(unsafe [self as "const QChartHolder *"] -> QLegend as "QPointer<QLegend>" {
+            return self->chart ? self->chart->legend() : nullptr;
+        })
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QChartView`][class] class.
+    ///
+    /// Requires the `charts` feature.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qchartview.html
+    QChartView: QWidget
+);
+
+impl QChartView {
+    /// Creates a new chart view, with an empty chart, and without parent
+    pub fn new() -> QChartView {
+        QChartView::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QChartView;
+        }))
+    }
+
+    /// Shows the chart, which is then owned by the view. The previous chart is deleted.
+    ///
+    /// Refer to the Qt documentation of QChartView::setChart
+    pub fn set_chart(&self, chart: &QChart) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", chart as "const QChartHolder *"] {
+            auto view = qobject_cast<QChartView *>(widget->data());
+            if (view && chart->chart) {
+                auto old = view->chart();
+                view->setChart(chart->chart);
+                if (old != chart->chart)
+                    delete old;
+            }
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsView::setRenderHints
+    pub fn set_render_hints(&self, hints: RenderHints) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", hints as "QPainter::RenderHints"] {
+            if (auto view = qobject_cast<QChartView *>(widget->data()))
+                view->setRenderHints(hints);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsView::renderHints
+    pub fn render_hints(&self) -> RenderHints {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> RenderHints as "QPainter::RenderHints" {
+            auto view = qobject_cast<QChartView *>(widget->data());
+            return view ? view->renderHints() : QPainter::RenderHints();
+        })
+    }
+}
+
+impl Default for QChartView {
+    fn default() -> Self {
+        QChartView::new()
+    }
+}
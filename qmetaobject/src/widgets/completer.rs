@@ -0,0 +1,175 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtWidgets/QCompleter>
+
+    struct QCompleterHolder {
+        std::unique_ptr<QCompleter> completer;
+
+        QCompleterHolder(QAbstractItemModel *model) : completer(new QCompleter(model)) {}
+    };
+}}
+
+cpp_class!(
+    /// Wrapper around [`QCompleter`][class] class.
+    ///
+    /// The completer can be exposed to QML with [`to_qvariant`](#method.to_qvariant), for
+    /// example as a context property. Note that the QtQuick Controls `TextField` has no
+    /// completer support on its own, so the QML code has to drive the completer, e.g. by setting
+    /// its `completionPrefix` property and reading `currentCompletion`.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcompleter.html
+    pub unsafe struct QCompleter as "QCompleterHolder"
+);
+
+impl QCompleter {
+    /// Creates a completer which provides completions from the given model.
+    ///
+    /// The C++ object of the model must already exist, so the model needs to be pinned.
+    /// The model must outlive the completer.
+    pub fn new(model: &dyn QAbstractItemModel) -> QCompleter {
+        let model = model.get_cpp_object();
+        assert!(!model.is_null(), "The model must be pinned before creating a completer");
+        unsafe { QCompleter::from_model_ptr(model) }
+    }
+
+    /// Creates a completer which provides completions from a C++ model, such as the one
+    /// returned by [`QStandardItemModel::cpp_ptr`](../struct.QStandardItemModel.html#method.cpp_ptr).
+    ///
+    /// # Safety
+    ///
+    /// `model` must be a valid pointer to a QAbstractItemModel that outlives the completer.
+    pub unsafe fn from_model_ptr(model: *mut c_void) -> QCompleter {
+        cpp!([model as "QAbstractItemModel *"] -> QCompleter as "QCompleterHolder" {
+            return QCompleterHolder(model);
+        })
+    }
+
+    /// Returns a pointer to the underlying QCompleter, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QCompleterHolder *"] -> *mut c_void as "QCompleter *" {
+            return self->completer.get();
+        })
+    }
+
+    /// Returns a QVariant holding a pointer to the completer, which can be used to expose it to
+    /// QML, for example with [`QmlEngine::set_property`](../struct.QmlEngine.html#method.set_property).
+    pub fn to_qvariant(&self) -> QVariant {
+        cpp!(unsafe [self as "QCompleterHolder *"] -> QVariant as "QVariant" {
+            return QVariant::fromValue<QObject *>(self->completer.get());
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::setCompletionPrefix
+    pub fn set_completion_prefix(&mut self, prefix: &str) {
+        let prefix = QString::from(prefix);
+        cpp!(unsafe [self as "QCompleterHolder *", prefix as "QString"] {
+            self->completer->setCompletionPrefix(prefix);
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::completionPrefix
+    pub fn completion_prefix(&self) -> QString {
+        cpp!(unsafe [self as "QCompleterHolder *"] -> QString as "QString" {
+            return self->completer->completionPrefix();
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::setCompletionColumn
+    pub fn set_completion_column(&mut self, column: i32) {
+        cpp!(unsafe [self as "QCompleterHolder *", column as "int"] {
+            self->completer->setCompletionColumn(column);
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::setCompletionRole
+    pub fn set_completion_role(&mut self, role: i32) {
+        cpp!(unsafe [self as "QCompleterHolder *", role as "int"] {
+            self->completer->setCompletionRole(role);
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::setCaseSensitivity
+    pub fn set_case_sensitivity(&mut self, case_sensitivity: CaseSensitivity) {
+        cpp!(unsafe [self as "QCompleterHolder *", case_sensitivity as "Qt::CaseSensitivity"] {
+            self->completer->setCaseSensitivity(case_sensitivity);
+        })
+    }
+
+    /// Only `MatchStartsWith`, `MatchContains` and `MatchEndsWith` are supported (the latter
+    /// two since Qt 5.2).
+    ///
+    /// Refer to the Qt documentation of QCompleter::setFilterMode
+    pub fn set_filter_mode(&mut self, mode: MatchFlags) {
+        cpp!(unsafe [self as "QCompleterHolder *", mode as "Qt::MatchFlags"] {
+            self->completer->setFilterMode(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::completionCount
+    pub fn completion_count(&self) -> i32 {
+        cpp!(unsafe [self as "QCompleterHolder *"] -> i32 as "int" {
+            return self->completer->completionCount();
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::setCurrentRow
+    pub fn set_current_row(&mut self, row: i32) -> bool {
+        cpp!(unsafe [self as "QCompleterHolder *", row as "int"] -> bool as "bool" {
+            return self->completer->setCurrentRow(row);
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::currentCompletion
+    pub fn current_completion(&self) -> QString {
+        cpp!(unsafe [self as "QCompleterHolder *"] -> QString as "QString" {
+            return self->completer->currentCompletion();
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::complete
+    pub fn complete(&self) {
+        cpp!(unsafe [self as "QCompleterHolder *"] {
+            self->completer->complete();
+        })
+    }
+
+    /// Refer to the Qt documentation of QCompleter::activated
+    pub fn activated_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return static_cast<void (QCompleter::*)(const QString &)>(&QCompleter::activated);
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QCompleter::highlighted
+    pub fn highlighted_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return static_cast<void (QCompleter::*)(const QString &)>(&QCompleter::highlighted);
+            }))
+        }
+    }
+}
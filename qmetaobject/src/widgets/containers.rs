@@ -0,0 +1,506 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QSplitter>
+    #include <QtWidgets/QStackedWidget>
+    #include <QtWidgets/QTabBar>
+    #include <QtWidgets/QTabWidget>
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QTabBar`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtabbar.html
+    QTabBar: QWidget
+);
+
+impl QTabBar {
+    /// Creates a new tab bar without parent
+    pub fn new() -> QTabBar {
+        QTabBar::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QTabBar;
+        }))
+    }
+
+    /// Refer to the Qt documentation of QTabBar::addTab
+    pub fn add_tab(&self, text: &str) -> i32 {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] -> i32 as "int" {
+            auto bar = qobject_cast<QTabBar *>(widget->data());
+            return bar ? bar->addTab(text) : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::removeTab
+    pub fn remove_tab(&self, index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] {
+            if (auto bar = qobject_cast<QTabBar *>(widget->data()))
+                bar->removeTab(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::count
+    pub fn count(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto bar = qobject_cast<QTabBar *>(widget->data());
+            return bar ? bar->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::currentIndex
+    pub fn current_index(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto bar = qobject_cast<QTabBar *>(widget->data());
+            return bar ? bar->currentIndex() : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::setCurrentIndex
+    pub fn set_current_index(&self, index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] {
+            if (auto bar = qobject_cast<QTabBar *>(widget->data()))
+                bar->setCurrentIndex(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::tabText
+    pub fn tab_text(&self, index: i32) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] -> QString as "QString" {
+            auto bar = qobject_cast<QTabBar *>(widget->data());
+            return bar ? bar->tabText(index) : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::setTabText
+    pub fn set_tab_text(&self, index: i32, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int", text as "QString"] {
+            if (auto bar = qobject_cast<QTabBar *>(widget->data()))
+                bar->setTabText(index, text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::setTabsClosable
+    pub fn set_tabs_closable(&self, closable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", closable as "bool"] {
+            if (auto bar = qobject_cast<QTabBar *>(widget->data()))
+                bar->setTabsClosable(closable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabBar::currentChanged
+    pub fn current_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTabBar::currentChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QTabBar::tabCloseRequested
+    pub fn tab_close_requested_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTabBar::tabCloseRequested;
+            }))
+        }
+    }
+}
+
+impl Default for QTabBar {
+    fn default() -> Self {
+        QTabBar::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QTabWidget`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtabwidget.html
+    QTabWidget: QWidget
+);
+
+impl QTabWidget {
+    /// Creates a new tab widget without parent
+    pub fn new() -> QTabWidget {
+        QTabWidget::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QTabWidget;
+        }))
+    }
+
+    /// Adds a tab showing the page, which is then owned by the tab widget, and returns its
+    /// index.
+    ///
+    /// Refer to the Qt documentation of QTabWidget::addTab
+    pub fn add_tab(&self, page: &QWidget, label: &str) -> i32 {
+        let widget: &QWidget = self;
+        let label = QString::from(label);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", page as "const QPointer<QWidget> *",
+                label as "QString"] -> i32 as "int" {
+            auto tabs = qobject_cast<QTabWidget *>(widget->data());
+            return tabs && *page ? tabs->addTab(page->data(), label) : -1;
+        })
+    }
+
+    /// Inserts a tab showing the page at the given index, or at the end if the index is out
+    /// of range, and returns its index.
+    ///
+    /// Refer to the Qt documentation of QTabWidget::insertTab
+    pub fn insert_tab(&self, index: i32, page: &QWidget, label: &str) -> i32 {
+        let widget: &QWidget = self;
+        let label = QString::from(label);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int",
+                page as "const QPointer<QWidget> *", label as "QString"] -> i32 as "int" {
+            auto tabs = qobject_cast<QTabWidget *>(widget->data());
+            return tabs && *page ? tabs->insertTab(index, page->data(), label) : -1;
+        })
+    }
+
+    /// Removes the tab at the given index. The page is not deleted.
+    ///
+    /// Refer to the Qt documentation of QTabWidget::removeTab
+    pub fn remove_tab(&self, index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] {
+            if (auto tabs = qobject_cast<QTabWidget *>(widget->data()))
+                tabs->removeTab(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::count
+    pub fn count(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto tabs = qobject_cast<QTabWidget *>(widget->data());
+            return tabs ? tabs->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::currentIndex
+    pub fn current_index(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto tabs = qobject_cast<QTabWidget *>(widget->data());
+            return tabs ? tabs->currentIndex() : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::setCurrentIndex
+    pub fn set_current_index(&self, index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] {
+            if (auto tabs = qobject_cast<QTabWidget *>(widget->data()))
+                tabs->setCurrentIndex(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::tabText
+    pub fn tab_text(&self, index: i32) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] -> QString as "QString" {
+            auto tabs = qobject_cast<QTabWidget *>(widget->data());
+            return tabs ? tabs->tabText(index) : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::setTabsClosable
+    pub fn set_tabs_closable(&self, closable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", closable as "bool"] {
+            if (auto tabs = qobject_cast<QTabWidget *>(widget->data()))
+                tabs->setTabsClosable(closable);
+        })
+    }
+
+    /// Returns the tab bar of the widget, which is owned by the tab widget.
+    ///
+    /// Refer to the Qt documentation of QTabWidget::tabBar
+    pub fn tab_bar(&self) -> QTabBar {
+        let widget: &QWidget = self;
+        QTabBar::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+                auto tabs = qobject_cast<QTabWidget *>(widget->data());
+                return tabs ? tabs->tabBar() : nullptr;
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::currentChanged
+    pub fn current_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTabWidget::currentChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QTabWidget::tabCloseRequested
+    pub fn tab_close_requested_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QTabWidget::tabCloseRequested;
+            }))
+        }
+    }
+}
+
+impl Default for QTabWidget {
+    fn default() -> Self {
+        QTabWidget::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QStackedWidget`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qstackedwidget.html
+    QStackedWidget: QWidget
+);
+
+impl QStackedWidget {
+    /// Creates a new stacked widget without parent
+    pub fn new() -> QStackedWidget {
+        QStackedWidget::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QStackedWidget;
+        }))
+    }
+
+    /// Appends the page, which is then owned by the stacked widget, and returns its index.
+    ///
+    /// Refer to the Qt documentation of QStackedWidget::addWidget
+    pub fn add_widget(&self, page: &QWidget) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", page as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto stack = qobject_cast<QStackedWidget *>(widget->data());
+            return stack && *page ? stack->addWidget(page->data()) : -1;
+        })
+    }
+
+    /// Inserts the page at the given index, or at the end if the index is out of range, and
+    /// returns its index.
+    ///
+    /// Refer to the Qt documentation of QStackedWidget::insertWidget
+    pub fn insert_widget(&self, index: i32, page: &QWidget) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int",
+                page as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto stack = qobject_cast<QStackedWidget *>(widget->data());
+            return stack && *page ? stack->insertWidget(index, page->data()) : -1;
+        })
+    }
+
+    /// Removes the page from the stacked widget. The page is not deleted, but it keeps the
+    /// stacked widget as parent.
+    ///
+    /// Refer to the Qt documentation of QStackedWidget::removeWidget
+    pub fn remove_widget(&self, page: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", page as "const QPointer<QWidget> *"] {
+            if (auto stack = qobject_cast<QStackedWidget *>(widget->data()))
+                if (*page)
+                    stack->removeWidget(page->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QStackedWidget::count
+    pub fn count(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto stack = qobject_cast<QStackedWidget *>(widget->data());
+            return stack ? stack->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QStackedWidget::currentIndex
+    pub fn current_index(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto stack = qobject_cast<QStackedWidget *>(widget->data());
+            return stack ? stack->currentIndex() : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QStackedWidget::setCurrentIndex
+    pub fn set_current_index(&self, index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] {
+            if (auto stack = qobject_cast<QStackedWidget *>(widget->data()))
+                stack->setCurrentIndex(index);
+        })
+    }
+
+    /// Returns a handle to the current page, if any.
+    ///
+    /// Refer to the Qt documentation of QStackedWidget::currentWidget
+    pub fn current_widget(&self) -> Option<QWidget> {
+        let widget: &QWidget = self;
+        let current = cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+            auto stack = qobject_cast<QStackedWidget *>(widget->data());
+            return stack ? stack->currentWidget() : nullptr;
+        });
+        if current.is_null() {
+            None
+        } else {
+            Some(current)
+        }
+    }
+
+    /// Refer to the Qt documentation of QStackedWidget::currentChanged
+    pub fn current_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QStackedWidget::currentChanged;
+            }))
+        }
+    }
+}
+
+impl Default for QStackedWidget {
+    fn default() -> Self {
+        QStackedWidget::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QSplitter`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qsplitter.html
+    QSplitter: QWidget
+);
+
+impl QSplitter {
+    /// Creates a new splitter without parent, laying out its children in the given direction.
+    pub fn new(orientation: Orientation) -> QSplitter {
+        QSplitter::from_widget(
+            cpp!(unsafe [orientation as "Qt::Orientation"] -> QWidget as "QPointer<QWidget>" {
+                return new QSplitter(orientation);
+            }),
+        )
+    }
+
+    /// Appends the widget, which is then owned by the splitter.
+    ///
+    /// Refer to the Qt documentation of QSplitter::addWidget
+    pub fn add_widget(&self, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"] {
+            if (auto splitter = qobject_cast<QSplitter *>(widget->data()))
+                if (*child)
+                    splitter->addWidget(child->data());
+        })
+    }
+
+    /// Inserts the widget at the given index, or at the end if the index is out of range.
+    ///
+    /// Refer to the Qt documentation of QSplitter::insertWidget
+    pub fn insert_widget(&self, index: i32, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int",
+                child as "const QPointer<QWidget> *"] {
+            if (auto splitter = qobject_cast<QSplitter *>(widget->data()))
+                if (*child)
+                    splitter->insertWidget(index, child->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QSplitter::count
+    pub fn count(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto splitter = qobject_cast<QSplitter *>(widget->data());
+            return splitter ? splitter->count() : 0;
+        })
+    }
+
+    /// Sets the sizes of the widgets, in pixels, from left to right or top to bottom.
+    ///
+    /// Refer to the Qt documentation of QSplitter::setSizes
+    pub fn set_sizes(&self, sizes: &[i32]) {
+        let widget: &QWidget = self;
+        let data = sizes.as_ptr();
+        let len = sizes.len();
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", data as "const int *",
+                len as "size_t"] {
+            if (auto splitter = qobject_cast<QSplitter *>(widget->data())) {
+                QList<int> list;
+                for (size_t i = 0; i < len; ++i)
+                    list.append(data[i]);
+                splitter->setSizes(list);
+            }
+        })
+    }
+
+    /// Returns the sizes of the widgets, in pixels. Hidden widgets have a size of 0.
+    ///
+    /// Refer to the Qt documentation of QSplitter::sizes
+    pub fn sizes(&self) -> Vec<i32> {
+        let widget: &QWidget = self;
+        let mut sizes = vec![0; self.count() as usize];
+        let data = sizes.as_mut_ptr();
+        let len = sizes.len();
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", data as "int *", len as "size_t"] {
+            if (auto splitter = qobject_cast<QSplitter *>(widget->data())) {
+                const auto list = splitter->sizes();
+                for (size_t i = 0; i < len && i < size_t(list.size()); ++i)
+                    data[i] = list[i];
+            }
+        });
+        sizes
+    }
+
+    /// Refer to the Qt documentation of QSplitter::setCollapsible
+    pub fn set_collapsible(&self, index: i32, collapsible: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int", collapsible as "bool"] {
+            if (auto splitter = qobject_cast<QSplitter *>(widget->data()))
+                splitter->setCollapsible(index, collapsible);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSplitter::isCollapsible
+    pub fn is_collapsible(&self, index: i32) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] -> bool as "bool" {
+            auto splitter = qobject_cast<QSplitter *>(widget->data());
+            return splitter && splitter->isCollapsible(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSplitter::splitterMoved
+    pub fn splitter_moved_signal() -> Signal<fn(i32, i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QSplitter::splitterMoved;
+            }))
+        }
+    }
+}
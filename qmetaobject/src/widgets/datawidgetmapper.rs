@@ -0,0 +1,177 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtWidgets/QDataWidgetMapper>
+
+    struct QDataWidgetMapperHolder {
+        std::unique_ptr<QDataWidgetMapper> mapper;
+
+        QDataWidgetMapperHolder() : mapper(new QDataWidgetMapper) {}
+    };
+}}
+
+/// Wrapper around [`QDataWidgetMapper::SubmitPolicy`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qdatawidgetmapper.html#SubmitPolicy-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitPolicy {
+    AutoSubmit = 0,
+    ManualSubmit = 1,
+}
+
+cpp_class!(
+    /// Wrapper around [`QDataWidgetMapper`][class] class, which maps the sections of a row of
+    /// a model to widgets, for example to edit a record in a form.
+    ///
+    /// The model and the widgets must outlive the mapper.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qdatawidgetmapper.html
+    pub unsafe struct QDataWidgetMapper as "QDataWidgetMapperHolder"
+);
+
+impl QDataWidgetMapper {
+    /// Creates a new mapper without model
+    pub fn new() -> QDataWidgetMapper {
+        cpp!(unsafe [] -> QDataWidgetMapper as "QDataWidgetMapperHolder" {
+            return QDataWidgetMapperHolder();
+        })
+    }
+
+    /// Returns a pointer to the underlying QDataWidgetMapper, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] -> *mut c_void as "QDataWidgetMapper *" {
+            return self->mapper.get();
+        })
+    }
+
+    /// Sets the model whose data is mapped to the widgets.
+    ///
+    /// The C++ object of the model must already exist, so the model needs to be pinned.
+    pub fn set_model(&mut self, model: &dyn QAbstractItemModel) {
+        let model = model.get_cpp_object();
+        assert!(!model.is_null(), "The model must be pinned before being set on a mapper");
+        unsafe { self.set_model_ptr(model) }
+    }
+
+    /// Sets a C++ model, such as the one returned by
+    /// [`QStandardItemModel::cpp_ptr`](../struct.QStandardItemModel.html#method.cpp_ptr).
+    ///
+    /// # Safety
+    ///
+    /// `model` must be a valid pointer to a QAbstractItemModel that outlives the mapper.
+    pub unsafe fn set_model_ptr(&mut self, model: *mut c_void) {
+        cpp!([self as "QDataWidgetMapperHolder *", model as "QAbstractItemModel *"] {
+            self->mapper->setModel(model);
+        })
+    }
+
+    /// Maps the widget to the section of the model, using the user property of the widget
+    /// (e.g. `text` for a line edit).
+    ///
+    /// Refer to the Qt documentation of QDataWidgetMapper::addMapping
+    pub fn add_mapping(&mut self, widget: &QWidget, section: i32) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *", widget as "const QPointer<QWidget> *", section as "int"] {
+            if (*widget)
+                self->mapper->addMapping(widget->data(), section);
+        })
+    }
+
+    /// Maps the given property of the widget to the section of the model.
+    ///
+    /// Refer to the Qt documentation of QDataWidgetMapper::addMapping
+    pub fn add_mapping_with_property(&mut self, widget: &QWidget, section: i32, property: &str) {
+        let property = QByteArray::from(property);
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *", widget as "const QPointer<QWidget> *",
+                     section as "int", property as "QByteArray"] {
+            if (*widget)
+                self->mapper->addMapping(widget->data(), section, property);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::setSubmitPolicy
+    pub fn set_submit_policy(&mut self, policy: SubmitPolicy) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *", policy as "QDataWidgetMapper::SubmitPolicy"] {
+            self->mapper->setSubmitPolicy(policy);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::currentIndex
+    pub fn current_index(&self) -> i32 {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] -> i32 as "int" {
+            return self->mapper->currentIndex();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::setCurrentIndex
+    pub fn set_current_index(&mut self, row: i32) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *", row as "int"] {
+            self->mapper->setCurrentIndex(row);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::toFirst
+    pub fn to_first(&mut self) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] {
+            self->mapper->toFirst();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::toNext
+    pub fn to_next(&mut self) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] {
+            self->mapper->toNext();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::toPrevious
+    pub fn to_previous(&mut self) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] {
+            self->mapper->toPrevious();
+        })
+    }
+
+    /// Writes the data of the widgets to the model, and calls the `submit` of the model.
+    ///
+    /// Refer to the Qt documentation of QDataWidgetMapper::submit
+    pub fn submit(&mut self) -> bool {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] -> bool as "bool" {
+            return self->mapper->submit();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDataWidgetMapper::revert
+    pub fn revert(&mut self) {
+        cpp!(unsafe [self as "QDataWidgetMapperHolder *"] {
+            self->mapper->revert();
+        })
+    }
+}
+
+impl Default for QDataWidgetMapper {
+    fn default() -> Self {
+        QDataWidgetMapper::new()
+    }
+}
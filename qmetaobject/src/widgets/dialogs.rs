@@ -0,0 +1,1011 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use std::ops::{BitOr, BitOrAssign};
+
+use super::QWidget;
+use crate::future::wait_on_signal;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QColorDialog>
+    #include <QtWidgets/QDialog>
+    #include <QtWidgets/QFileDialog>
+    #include <QtWidgets/QFontDialog>
+    #include <QtWidgets/QInputDialog>
+    #include <QtWidgets/QMessageBox>
+}}
+
+/// Wrapper around [`QDialog::DialogCode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qdialog.html#DialogCode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogCode {
+    Rejected = 0,
+    Accepted = 1,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QDialog`][class] class, the base class of the dialogs.
+    ///
+    /// Running a dialog with `exec()` would block the event loop, so the dialogs are run with
+    /// the asynchronous [`exec_async`](#method.exec_async) instead, within
+    /// [`execute_async`](../future/fn.execute_async.html).
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qdialog.html
+    abstract QDialog: QWidget
+);
+
+impl QDialog {
+    /// Shows the dialog as a window modal dialog, and returns immediately.
+    ///
+    /// Refer to the Qt documentation of QDialog::open
+    pub fn open(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto dialog = qobject_cast<QDialog *>(widget->data()))
+                dialog->open();
+        })
+    }
+
+    /// Shows the dialog, and waits until it is closed without blocking the event loop.
+    /// Returns the result code, which is a [`DialogCode`] unless the dialog was closed with
+    /// [`done`](#method.done) and another value.
+    ///
+    /// This is the asynchronous equivalent of `QDialog::exec`.
+    pub async fn exec_async(&self) -> i32 {
+        let finished = unsafe { wait_on_signal(self.cpp_ptr(), QDialog::finished_signal()) };
+        self.open();
+        finished.await.0
+    }
+
+    /// Refer to the Qt documentation of QDialog::accept
+    pub fn accept(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto dialog = qobject_cast<QDialog *>(widget->data()))
+                dialog->accept();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDialog::reject
+    pub fn reject(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto dialog = qobject_cast<QDialog *>(widget->data()))
+                dialog->reject();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDialog::done
+    pub fn done(&self, result: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", result as "int"] {
+            if (auto dialog = qobject_cast<QDialog *>(widget->data()))
+                dialog->done(result);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDialog::result
+    pub fn result(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto dialog = qobject_cast<QDialog *>(widget->data());
+            return dialog ? dialog->result() : 0;
+        })
+    }
+
+    /// Deletes the dialog once control returns to the event loop, even if it has a parent.
+    pub(crate) fn delete_later(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (*widget)
+                (*widget)->deleteLater();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDialog::finished
+    pub fn finished_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QDialog::finished;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QDialog::accepted
+    pub fn accepted_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QDialog::accepted;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QDialog::rejected
+    pub fn rejected_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QDialog::rejected;
+            }))
+        }
+    }
+}
+
+/// Wrapper around [`QFileDialog::FileMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qfiledialog.html#FileMode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileMode {
+    AnyFile = 0,
+    ExistingFile = 1,
+    Directory = 2,
+    ExistingFiles = 3,
+}
+
+/// Wrapper around [`QFileDialog::AcceptMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qfiledialog.html#AcceptMode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AcceptMode {
+    AcceptOpen = 0,
+    AcceptSave = 1,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QFileDialog`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfiledialog.html
+    QFileDialog: QDialog
+);
+
+impl QFileDialog {
+    /// Creates a new file dialog. If `parent` is given, the dialog is owned by it.
+    ///
+    /// `filter` is a list of filters separated by `;;`, such as `"Images (*.png *.jpg);;Any (*)"`.
+    pub fn new(parent: Option<&QWidget>, caption: &str, dir: &str, filter: &str) -> QFileDialog {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        let caption = QString::from(caption);
+        let dir = QString::from(dir);
+        let filter = QString::from(filter);
+        QFileDialog::from_widget(cpp!(unsafe [parent as "QWidget *", caption as "QString",
+                dir as "QString", filter as "QString"] -> QWidget as "QPointer<QWidget>" {
+            return new QFileDialog(parent, caption, dir, filter);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QFileDialog::setFileMode
+    pub fn set_file_mode(&self, mode: FileMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", mode as "QFileDialog::FileMode"] {
+            if (auto dialog = qobject_cast<QFileDialog *>(widget->data()))
+                dialog->setFileMode(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QFileDialog::setAcceptMode
+    pub fn set_accept_mode(&self, mode: AcceptMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", mode as "QFileDialog::AcceptMode"] {
+            if (auto dialog = qobject_cast<QFileDialog *>(widget->data()))
+                dialog->setAcceptMode(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QFileDialog::selectFile
+    pub fn select_file(&self, filename: &str) {
+        let widget: &QWidget = self;
+        let filename = QString::from(filename);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", filename as "QString"] {
+            if (auto dialog = qobject_cast<QFileDialog *>(widget->data()))
+                dialog->selectFile(filename);
+        })
+    }
+
+    /// Refer to the Qt documentation of QFileDialog::selectedFiles
+    pub fn selected_files(&self) -> Vec<QString> {
+        let widget: &QWidget = self;
+        let count = cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> usize as "size_t" {
+            auto dialog = qobject_cast<QFileDialog *>(widget->data());
+            return dialog ? dialog->selectedFiles().size() : 0;
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [widget as "const QPointer<QWidget> *", i as "size_t"] -> QString as "QString" {
+                    return qobject_cast<QFileDialog *>(widget->data())->selectedFiles().at(i);
+                })
+            })
+            .collect()
+    }
+}
+
+/// Shows a file dialog and waits for the user to choose files.
+/// Returns the selected files, or None if the dialog was canceled.
+async fn run_file_dialog(
+    parent: Option<&QWidget>,
+    caption: &str,
+    dir: &str,
+    filter: &str,
+    mode: FileMode,
+    accept_mode: AcceptMode,
+) -> Option<Vec<QString>> {
+    let dialog = QFileDialog::new(parent, caption, dir, filter);
+    dialog.set_file_mode(mode);
+    dialog.set_accept_mode(accept_mode);
+    let result = dialog.exec_async().await;
+    let files = dialog.selected_files();
+    dialog.delete_later();
+    if result == DialogCode::Accepted as i32 {
+        Some(files)
+    } else {
+        None
+    }
+}
+
+/// Asks the user for an existing file to open, without blocking the event loop.
+///
+/// This is the asynchronous equivalent of `QFileDialog::getOpenFileName`.
+pub async fn open_file_dialog(
+    parent: Option<&QWidget>,
+    caption: &str,
+    dir: &str,
+    filter: &str,
+) -> Option<QString> {
+    let files = run_file_dialog(
+        parent,
+        caption,
+        dir,
+        filter,
+        FileMode::ExistingFile,
+        AcceptMode::AcceptOpen,
+    )
+    .await?;
+    files.into_iter().next()
+}
+
+/// Asks the user for one or more existing files to open, without blocking the event loop.
+///
+/// This is the asynchronous equivalent of `QFileDialog::getOpenFileNames`.
+pub async fn open_files_dialog(
+    parent: Option<&QWidget>,
+    caption: &str,
+    dir: &str,
+    filter: &str,
+) -> Option<Vec<QString>> {
+    run_file_dialog(parent, caption, dir, filter, FileMode::ExistingFiles, AcceptMode::AcceptOpen)
+        .await
+}
+
+/// Asks the user for a file name to save to, without blocking the event loop.
+///
+/// This is the asynchronous equivalent of `QFileDialog::getSaveFileName`.
+pub async fn save_file_dialog(
+    parent: Option<&QWidget>,
+    caption: &str,
+    dir: &str,
+    filter: &str,
+) -> Option<QString> {
+    let files =
+        run_file_dialog(parent, caption, dir, filter, FileMode::AnyFile, AcceptMode::AcceptSave)
+            .await?;
+    files.into_iter().next()
+}
+
+/// Asks the user for an existing directory, without blocking the event loop.
+///
+/// This is the asynchronous equivalent of `QFileDialog::getExistingDirectory`.
+pub async fn open_directory_dialog(
+    parent: Option<&QWidget>,
+    caption: &str,
+    dir: &str,
+) -> Option<QString> {
+    let files =
+        run_file_dialog(parent, caption, dir, "", FileMode::Directory, AcceptMode::AcceptOpen)
+            .await?;
+    files.into_iter().next()
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QColorDialog`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcolordialog.html
+    QColorDialog: QDialog
+);
+
+impl QColorDialog {
+    /// Creates a new color dialog showing the initial color. If `parent` is given, the dialog
+    /// is owned by it.
+    pub fn new(parent: Option<&QWidget>, initial: QColor) -> QColorDialog {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        QColorDialog::from_widget(cpp!(unsafe [parent as "QWidget *", initial as "QColor"]
+                -> QWidget as "QPointer<QWidget>" {
+            return new QColorDialog(initial, parent);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QColorDialog::currentColor
+    pub fn current_color(&self) -> QColor {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QColor as "QColor" {
+            auto dialog = qobject_cast<QColorDialog *>(widget->data());
+            return dialog ? dialog->currentColor() : QColor();
+        })
+    }
+
+    /// Refer to the Qt documentation of QColorDialog::setCurrentColor
+    pub fn set_current_color(&self, color: QColor) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", color as "QColor"] {
+            if (auto dialog = qobject_cast<QColorDialog *>(widget->data()))
+                dialog->setCurrentColor(color);
+        })
+    }
+
+    /// Returns the color the user selected by accepting the dialog, which is invalid if the
+    /// dialog was canceled.
+    ///
+    /// Refer to the Qt documentation of QColorDialog::selectedColor
+    pub fn selected_color(&self) -> QColor {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QColor as "QColor" {
+            auto dialog = qobject_cast<QColorDialog *>(widget->data());
+            return dialog ? dialog->selectedColor() : QColor();
+        })
+    }
+
+    /// Returns the custom colors, which are shared by all the color dialogs of the
+    /// application.
+    ///
+    /// Refer to the Qt documentation of QColorDialog::customColor
+    pub fn custom_colors() -> Vec<QColor> {
+        let count = cpp!(unsafe [] -> i32 as "int" {
+            return QColorDialog::customCount();
+        });
+        (0..count)
+            .map(|i| {
+                cpp!(unsafe [i as "int"] -> QColor as "QColor" {
+                    return QColorDialog::customColor(i);
+                })
+            })
+            .collect()
+    }
+
+    /// Refer to the Qt documentation of QColorDialog::setCustomColor
+    pub fn set_custom_color(index: i32, color: QColor) {
+        cpp!(unsafe [index as "int", color as "QColor"] {
+            QColorDialog::setCustomColor(index, color);
+        })
+    }
+
+    /// Refer to the Qt documentation of QColorDialog::colorSelected
+    pub fn color_selected_signal() -> Signal<fn(QColor)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QColorDialog::colorSelected;
+            }))
+        }
+    }
+}
+
+/// Asks the user for a color, without blocking the event loop.
+/// Returns None if the dialog was canceled.
+///
+/// This is the asynchronous equivalent of `QColorDialog::getColor`.
+pub async fn pick_color(parent: Option<&QWidget>, initial: QColor) -> Option<QColor> {
+    let dialog = QColorDialog::new(parent, initial);
+    let result = dialog.exec_async().await;
+    let color = dialog.selected_color();
+    dialog.delete_later();
+    if result == DialogCode::Accepted as i32 {
+        Some(color)
+    } else {
+        None
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QFontDialog`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfontdialog.html
+    QFontDialog: QDialog
+);
+
+impl QFontDialog {
+    /// Creates a new font dialog showing the initial font. If `parent` is given, the dialog is
+    /// owned by it.
+    pub fn new(parent: Option<&QWidget>, initial: &QFont) -> QFontDialog {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        QFontDialog::from_widget(cpp!(unsafe [parent as "QWidget *", initial as "const QFont *"]
+                -> QWidget as "QPointer<QWidget>" {
+            return new QFontDialog(*initial, parent);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QFontDialog::currentFont
+    pub fn current_font(&self) -> QFont {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QFont as "QFont" {
+            auto dialog = qobject_cast<QFontDialog *>(widget->data());
+            return dialog ? dialog->currentFont() : QFont();
+        })
+    }
+
+    /// Refer to the Qt documentation of QFontDialog::setCurrentFont
+    pub fn set_current_font(&self, font: &QFont) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", font as "const QFont *"] {
+            if (auto dialog = qobject_cast<QFontDialog *>(widget->data()))
+                dialog->setCurrentFont(*font);
+        })
+    }
+
+    /// Returns the font the user selected by accepting the dialog.
+    ///
+    /// Refer to the Qt documentation of QFontDialog::selectedFont
+    pub fn selected_font(&self) -> QFont {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QFont as "QFont" {
+            auto dialog = qobject_cast<QFontDialog *>(widget->data());
+            return dialog ? dialog->selectedFont() : QFont();
+        })
+    }
+
+    /// Refer to the Qt documentation of QFontDialog::fontSelected
+    pub fn font_selected_signal() -> Signal<fn(QFont)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QFontDialog::fontSelected;
+            }))
+        }
+    }
+}
+
+/// Asks the user for a font, without blocking the event loop.
+/// Returns None if the dialog was canceled.
+///
+/// This is the asynchronous equivalent of `QFontDialog::getFont`.
+pub async fn pick_font(parent: Option<&QWidget>, initial: &QFont) -> Option<QFont> {
+    let dialog = QFontDialog::new(parent, initial);
+    let result = dialog.exec_async().await;
+    let font = dialog.selected_font();
+    dialog.delete_later();
+    if result == DialogCode::Accepted as i32 {
+        Some(font)
+    } else {
+        None
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QInputDialog`][class] class.
+    ///
+    /// The asynchronous functions [`get_text`](#method.get_text),
+    /// [`get_int`](#method.get_int), [`get_double`](#method.get_double) and
+    /// [`get_item`](#method.get_item) are the equivalent of the static functions of
+    /// QInputDialog.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qinputdialog.html
+    QInputDialog: QDialog
+);
+
+impl QInputDialog {
+    /// Creates a new input dialog. If `parent` is given, the dialog is owned by it.
+    pub fn new(parent: Option<&QWidget>) -> QInputDialog {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        QInputDialog::from_widget(
+            cpp!(unsafe [parent as "QWidget *"] -> QWidget as "QPointer<QWidget>" {
+                return new QInputDialog(parent);
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setLabelText
+    pub fn set_label_text(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setLabelText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::textValue
+    pub fn text_value(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto dialog = qobject_cast<QInputDialog *>(widget->data());
+            return dialog ? dialog->textValue() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setTextValue
+    pub fn set_text_value(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setTextValue(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::intValue
+    pub fn int_value(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto dialog = qobject_cast<QInputDialog *>(widget->data());
+            return dialog ? dialog->intValue() : 0;
+        })
+    }
+
+    /// Sets the value, which also switches the dialog to integer input.
+    ///
+    /// Refer to the Qt documentation of QInputDialog::setIntValue
+    pub fn set_int_value(&self, value: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", value as "int"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setIntValue(value);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setIntRange
+    pub fn set_int_range(&self, min: i32, max: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "int", max as "int"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setIntRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setIntStep
+    pub fn set_int_step(&self, step: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", step as "int"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setIntStep(step);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::doubleValue
+    pub fn double_value(&self) -> f64 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> f64 as "double" {
+            auto dialog = qobject_cast<QInputDialog *>(widget->data());
+            return dialog ? dialog->doubleValue() : 0;
+        })
+    }
+
+    /// Sets the value, which also switches the dialog to floating point input.
+    ///
+    /// Refer to the Qt documentation of QInputDialog::setDoubleValue
+    pub fn set_double_value(&self, value: f64) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", value as "double"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setDoubleValue(value);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setDoubleRange
+    pub fn set_double_range(&self, min: f64, max: f64) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "double", max as "double"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setDoubleRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setDoubleDecimals
+    pub fn set_double_decimals(&self, decimals: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", decimals as "int"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setDoubleDecimals(decimals);
+        })
+    }
+
+    /// Sets the items to choose from, which also switches the dialog to text input with a
+    /// combo box.
+    ///
+    /// Refer to the Qt documentation of QInputDialog::setComboBoxItems
+    pub fn set_combo_box_items(&self, items: &[&str]) {
+        let widget: &QWidget = self;
+        let items: Vec<QString> = items.iter().map(|i| QString::from(*i)).collect();
+        let data = items.as_ptr();
+        let len = items.len();
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", data as "const QString *",
+                len as "size_t"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data())) {
+                QStringList list;
+                for (size_t i = 0; i < len; ++i)
+                    list.append(data[i]);
+                dialog->setComboBoxItems(list);
+            }
+        })
+    }
+
+    /// Refer to the Qt documentation of QInputDialog::setComboBoxEditable
+    pub fn set_combo_box_editable(&self, editable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", editable as "bool"] {
+            if (auto dialog = qobject_cast<QInputDialog *>(widget->data()))
+                dialog->setComboBoxEditable(editable);
+        })
+    }
+
+    /// Creates an input dialog with the given title and label, runs it, and returns the
+    /// value extracted by `value` if the dialog was accepted.
+    async fn run<T>(
+        parent: Option<&QWidget>,
+        title: &str,
+        label: &str,
+        setup: impl FnOnce(&QInputDialog),
+        value: impl FnOnce(&QInputDialog) -> T,
+    ) -> Option<T> {
+        let dialog = QInputDialog::new(parent);
+        dialog.set_window_title(title.into());
+        dialog.set_label_text(label);
+        setup(&dialog);
+        let result = dialog.exec_async().await;
+        let value = value(&dialog);
+        dialog.delete_later();
+        if result == DialogCode::Accepted as i32 {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Asks the user for a string, without blocking the event loop.
+    /// Returns None if the dialog was canceled.
+    ///
+    /// This is the asynchronous equivalent of `QInputDialog::getText`.
+    pub async fn get_text(
+        parent: Option<&QWidget>,
+        title: &str,
+        label: &str,
+        default: &str,
+    ) -> Option<QString> {
+        QInputDialog::run(parent, title, label, |d| d.set_text_value(default), |d| d.text_value())
+            .await
+    }
+
+    /// Asks the user for an integer between `min` and `max`, without blocking the event loop.
+    /// Returns None if the dialog was canceled.
+    ///
+    /// This is the asynchronous equivalent of `QInputDialog::getInt`.
+    pub async fn get_int(
+        parent: Option<&QWidget>,
+        title: &str,
+        label: &str,
+        default: i32,
+        min: i32,
+        max: i32,
+        step: i32,
+    ) -> Option<i32> {
+        let setup = |d: &QInputDialog| {
+            d.set_int_range(min, max);
+            d.set_int_value(default);
+            d.set_int_step(step);
+        };
+        QInputDialog::run(parent, title, label, setup, |d| d.int_value()).await
+    }
+
+    /// Asks the user for a floating point number between `min` and `max`, without blocking
+    /// the event loop. Returns None if the dialog was canceled.
+    ///
+    /// This is the asynchronous equivalent of `QInputDialog::getDouble`.
+    pub async fn get_double(
+        parent: Option<&QWidget>,
+        title: &str,
+        label: &str,
+        default: f64,
+        min: f64,
+        max: f64,
+        decimals: i32,
+    ) -> Option<f64> {
+        let setup = |d: &QInputDialog| {
+            d.set_double_range(min, max);
+            d.set_double_decimals(decimals);
+            d.set_double_value(default);
+        };
+        QInputDialog::run(parent, title, label, setup, |d| d.double_value()).await
+    }
+
+    /// Asks the user to choose one of the items, or to type another string if `editable` is
+    /// true, without blocking the event loop. Returns None if the dialog was canceled.
+    ///
+    /// This is the asynchronous equivalent of `QInputDialog::getItem`.
+    pub async fn get_item(
+        parent: Option<&QWidget>,
+        title: &str,
+        label: &str,
+        items: &[&str],
+        current: usize,
+        editable: bool,
+    ) -> Option<QString> {
+        let setup = |d: &QInputDialog| {
+            d.set_combo_box_items(items);
+            d.set_combo_box_editable(editable);
+            if let Some(item) = items.get(current) {
+                d.set_text_value(item);
+            }
+        };
+        QInputDialog::run(parent, title, label, setup, |d| d.text_value()).await
+    }
+}
+
+/// Wrapper around [`QMessageBox::StandardButton`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qmessagebox.html#StandardButton-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StandardButton {
+    NoButton = 0,
+    Ok = 0x400,
+    Save = 0x800,
+    SaveAll = 0x1000,
+    Open = 0x2000,
+    Yes = 0x4000,
+    YesToAll = 0x8000,
+    No = 0x10000,
+    NoToAll = 0x20000,
+    Abort = 0x40000,
+    Retry = 0x80000,
+    Ignore = 0x100000,
+    Close = 0x200000,
+    Cancel = 0x400000,
+    Discard = 0x800000,
+    Help = 0x1000000,
+    Apply = 0x2000000,
+    Reset = 0x4000000,
+    RestoreDefaults = 0x8000000,
+}
+
+impl StandardButton {
+    /// Maps the result code of a message box to the button, or NoButton if it is not the code
+    /// of a standard button.
+    fn from_result(result: i32) -> StandardButton {
+        match result as u32 {
+            0x400 => StandardButton::Ok,
+            0x800 => StandardButton::Save,
+            0x1000 => StandardButton::SaveAll,
+            0x2000 => StandardButton::Open,
+            0x4000 => StandardButton::Yes,
+            0x8000 => StandardButton::YesToAll,
+            0x10000 => StandardButton::No,
+            0x20000 => StandardButton::NoToAll,
+            0x40000 => StandardButton::Abort,
+            0x80000 => StandardButton::Retry,
+            0x100000 => StandardButton::Ignore,
+            0x200000 => StandardButton::Close,
+            0x400000 => StandardButton::Cancel,
+            0x800000 => StandardButton::Discard,
+            0x1000000 => StandardButton::Help,
+            0x2000000 => StandardButton::Apply,
+            0x4000000 => StandardButton::Reset,
+            0x8000000 => StandardButton::RestoreDefaults,
+            _ => StandardButton::NoButton,
+        }
+    }
+}
+
+/// Wrapper around [`QMessageBox::StandardButtons`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qmessagebox.html#StandardButton-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct StandardButtons(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl StandardButtons {
+    pub const NoButton: StandardButtons = StandardButtons(0);
+    pub const Ok: StandardButtons = StandardButtons(0x400);
+    pub const Save: StandardButtons = StandardButtons(0x800);
+    pub const SaveAll: StandardButtons = StandardButtons(0x1000);
+    pub const Open: StandardButtons = StandardButtons(0x2000);
+    pub const Yes: StandardButtons = StandardButtons(0x4000);
+    pub const YesToAll: StandardButtons = StandardButtons(0x8000);
+    pub const No: StandardButtons = StandardButtons(0x10000);
+    pub const NoToAll: StandardButtons = StandardButtons(0x20000);
+    pub const Abort: StandardButtons = StandardButtons(0x40000);
+    pub const Retry: StandardButtons = StandardButtons(0x80000);
+    pub const Ignore: StandardButtons = StandardButtons(0x100000);
+    pub const Close: StandardButtons = StandardButtons(0x200000);
+    pub const Cancel: StandardButtons = StandardButtons(0x400000);
+    pub const Discard: StandardButtons = StandardButtons(0x800000);
+    pub const Help: StandardButtons = StandardButtons(0x1000000);
+    pub const Apply: StandardButtons = StandardButtons(0x2000000);
+    pub const Reset: StandardButtons = StandardButtons(0x4000000);
+    pub const RestoreDefaults: StandardButtons = StandardButtons(0x8000000);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: StandardButtons) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<StandardButton> for StandardButtons {
+    fn from(button: StandardButton) -> Self {
+        StandardButtons(button as u32)
+    }
+}
+
+impl BitOr for StandardButtons {
+    type Output = StandardButtons;
+    fn bitor(self, other: StandardButtons) -> StandardButtons {
+        StandardButtons(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for StandardButtons {
+    fn bitor_assign(&mut self, other: StandardButtons) {
+        self.0 |= other.0;
+    }
+}
+
+/// Wrapper around [`QMessageBox::Icon`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qmessagebox.html#Icon-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageBoxIcon {
+    NoIcon = 0,
+    Information = 1,
+    Warning = 2,
+    Critical = 3,
+    Question = 4,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QMessageBox`][class] class.
+    ///
+    /// The asynchronous functions [`information`](#method.information),
+    /// [`warning`](#method.warning), [`question`](#method.question) and
+    /// [`critical`](#method.critical) are the equivalent of the static functions of
+    /// QMessageBox.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qmessagebox.html
+    QMessageBox: QDialog
+);
+
+impl QMessageBox {
+    /// Creates a new message box. If `parent` is given, the message box is owned by it.
+    pub fn new(
+        parent: Option<&QWidget>,
+        icon: MessageBoxIcon,
+        title: &str,
+        text: &str,
+        buttons: StandardButtons,
+    ) -> QMessageBox {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        let title = QString::from(title);
+        let text = QString::from(text);
+        QMessageBox::from_widget(cpp!(unsafe [parent as "QWidget *", icon as "QMessageBox::Icon",
+                title as "QString", text as "QString", buttons as "QMessageBox::StandardButtons"]
+                -> QWidget as "QPointer<QWidget>" {
+            return new QMessageBox(icon, title, text, buttons, parent);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QMessageBox::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto box = qobject_cast<QMessageBox *>(widget->data());
+            return box ? box->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QMessageBox::setDefaultButton
+    pub fn set_default_button(&self, button: StandardButton) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", button as "QMessageBox::StandardButton"] {
+            if (auto box = qobject_cast<QMessageBox *>(widget->data()))
+                box->setDefaultButton(button);
+        })
+    }
+
+    /// Clicks the given standard button of the message box, which closes it.
+    pub fn click_button(&self, button: StandardButton) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", button as "QMessageBox::StandardButton"] {
+            if (auto box = qobject_cast<QMessageBox *>(widget->data()))
+                if (auto b = box->button(button))
+                    b->click();
+        })
+    }
+
+    /// Shows the message box, and waits until the user closes it without blocking the event
+    /// loop. Returns the button that was clicked.
+    pub async fn exec_async(&self) -> StandardButton {
+        let dialog: &QDialog = self;
+        StandardButton::from_result(dialog.exec_async().await)
+    }
+
+    async fn run(
+        parent: Option<&QWidget>,
+        icon: MessageBoxIcon,
+        title: &str,
+        text: &str,
+        buttons: StandardButtons,
+        default: StandardButton,
+    ) -> StandardButton {
+        let message_box = QMessageBox::new(parent, icon, title, text, buttons);
+        message_box.set_default_button(default);
+        let button = message_box.exec_async().await;
+        message_box.delete_later();
+        button
+    }
+
+    /// Shows an information message with an Ok button, without blocking the event loop.
+    ///
+    /// This is the asynchronous equivalent of `QMessageBox::information`.
+    pub async fn information(parent: Option<&QWidget>, title: &str, text: &str) -> StandardButton {
+        let icon = MessageBoxIcon::Information;
+        let ok = StandardButton::Ok;
+        QMessageBox::run(parent, icon, title, text, ok.into(), ok).await
+    }
+
+    /// Shows a warning message with the given buttons, without blocking the event loop.
+    /// Returns the button that was clicked.
+    ///
+    /// This is the asynchronous equivalent of `QMessageBox::warning`.
+    pub async fn warning(
+        parent: Option<&QWidget>,
+        title: &str,
+        text: &str,
+        buttons: StandardButtons,
+    ) -> StandardButton {
+        let icon = MessageBoxIcon::Warning;
+        QMessageBox::run(parent, icon, title, text, buttons, StandardButton::NoButton).await
+    }
+
+    /// Asks a question with the given buttons, without blocking the event loop.
+    /// Returns the button that was clicked.
+    ///
+    /// This is the asynchronous equivalent of `QMessageBox::question`.
+    pub async fn question(
+        parent: Option<&QWidget>,
+        title: &str,
+        text: &str,
+        buttons: StandardButtons,
+        default: StandardButton,
+    ) -> StandardButton {
+        QMessageBox::run(parent, MessageBoxIcon::Question, title, text, buttons, default).await
+    }
+
+    /// Shows a critical error message with the given buttons, without blocking the event loop.
+    /// Returns the button that was clicked.
+    ///
+    /// This is the asynchronous equivalent of `QMessageBox::critical`.
+    pub async fn critical(
+        parent: Option<&QWidget>,
+        title: &str,
+        text: &str,
+        buttons: StandardButtons,
+    ) -> StandardButton {
+        let icon = MessageBoxIcon::Critical;
+        QMessageBox::run(parent, icon, title, text, buttons, StandardButton::NoButton).await
+    }
+}
@@ -0,0 +1,258 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QLabel>
+    #include <QtWidgets/QProgressBar>
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QProgressBar`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qprogressbar.html
+    QProgressBar: QWidget
+);
+
+impl QProgressBar {
+    /// Creates a new progress bar without parent, with a range of 0 to 100
+    pub fn new() -> QProgressBar {
+        QProgressBar::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QProgressBar;
+        }))
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::setRange
+    pub fn set_range(&self, min: i32, max: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "int", max as "int"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->setRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::setMinimum
+    pub fn set_minimum(&self, min: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "int"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->setMinimum(min);
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::minimum
+    pub fn minimum(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto bar = qobject_cast<QProgressBar *>(widget->data());
+            return bar ? bar->minimum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::setMaximum
+    pub fn set_maximum(&self, max: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", max as "int"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->setMaximum(max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::maximum
+    pub fn maximum(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto bar = qobject_cast<QProgressBar *>(widget->data());
+            return bar ? bar->maximum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::setValue
+    pub fn set_value(&self, value: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", value as "int"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->setValue(value);
+        })
+    }
+
+    /// Returns the current value, or `minimum() - 1` if the progress bar was reset.
+    ///
+    /// Refer to the Qt documentation of QProgressBar::value
+    pub fn value(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto bar = qobject_cast<QProgressBar *>(widget->data());
+            return bar ? bar->value() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::reset
+    pub fn reset(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->reset();
+        })
+    }
+
+    /// Sets the format of the text shown in the bar: `%p` is replaced by the percentage, `%v`
+    /// by the value and `%m` by the total number of steps.
+    ///
+    /// Refer to the Qt documentation of QProgressBar::setFormat
+    pub fn set_format(&self, format: &str) {
+        let widget: &QWidget = self;
+        let format = QString::from(format);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", format as "QString"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->setFormat(format);
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto bar = qobject_cast<QProgressBar *>(widget->data());
+            return bar ? bar->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::setTextVisible
+    pub fn set_text_visible(&self, visible: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", visible as "bool"] {
+            if (auto bar = qobject_cast<QProgressBar *>(widget->data()))
+                bar->setTextVisible(visible);
+        })
+    }
+
+    /// Refer to the Qt documentation of QProgressBar::valueChanged
+    pub fn value_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QProgressBar::valueChanged;
+            }))
+        }
+    }
+}
+
+impl Default for QProgressBar {
+    fn default() -> Self {
+        QProgressBar::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QLabel`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlabel.html
+    QLabel: QWidget
+);
+
+impl QLabel {
+    /// Creates a new label without parent, showing the given text
+    pub fn new(text: &str) -> QLabel {
+        let text = QString::from(text);
+        QLabel::from_widget(cpp!(unsafe [text as "QString"] -> QWidget as "QPointer<QWidget>" {
+            return new QLabel(text);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QLabel::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto label = qobject_cast<QLabel *>(widget->data());
+            return label ? label->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLabel::setText
+    pub fn set_text(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto label = qobject_cast<QLabel *>(widget->data()))
+                label->setText(text);
+        })
+    }
+
+    /// Shows the pixmap instead of the text.
+    ///
+    /// Refer to the Qt documentation of QLabel::setPixmap
+    pub fn set_pixmap(&self, pixmap: &QPixmap) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", pixmap as "const QPixmap *"] {
+            if (auto label = qobject_cast<QLabel *>(widget->data()))
+                label->setPixmap(*pixmap);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLabel::setWordWrap
+    pub fn set_word_wrap(&self, wrap: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", wrap as "bool"] {
+            if (auto label = qobject_cast<QLabel *>(widget->data()))
+                label->setWordWrap(wrap);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLabel::setAlignment
+    pub fn set_alignment(&self, alignment: Alignment) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", alignment as "Qt::Alignment"] {
+            if (auto label = qobject_cast<QLabel *>(widget->data()))
+                label->setAlignment(alignment);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLabel::alignment
+    pub fn alignment(&self) -> Alignment {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> Alignment as "Qt::Alignment" {
+            auto label = qobject_cast<QLabel *>(widget->data());
+            return label ? label->alignment() : Qt::Alignment();
+        })
+    }
+
+    /// When enabled, clicking a link opens it with `QDesktopServices` instead of only emitting
+    /// the `linkActivated` signal.
+    ///
+    /// Refer to the Qt documentation of QLabel::setOpenExternalLinks
+    pub fn set_open_external_links(&self, open: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", open as "bool"] {
+            if (auto label = qobject_cast<QLabel *>(widget->data()))
+                label->setOpenExternalLinks(open);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLabel::linkActivated
+    pub fn link_activated_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLabel::linkActivated;
+            }))
+        }
+    }
+}
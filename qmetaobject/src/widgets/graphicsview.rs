@@ -0,0 +1,403 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QGraphicsItem>
+    #include <QtWidgets/QGraphicsScene>
+    #include <QtWidgets/QGraphicsView>
+
+    struct QGraphicsSceneHolder {
+        QPointer<QGraphicsScene> scene;
+
+        QGraphicsSceneHolder() : scene(new QGraphicsScene) {}
+        ~QGraphicsSceneHolder() {
+            if (scene && !scene->parent())
+                delete scene.data();
+        }
+    };
+
+    /// Empty child item of the items referenced from Rust: it is deleted with its parent, so a
+    /// QPointer to it tells whether the item still exists, even if another item was since
+    /// allocated at the same address.
+    struct QGraphicsItemGuard : QGraphicsObject {
+        enum { Type = UserType + 0x5157 };
+
+        explicit QGraphicsItemGuard(QGraphicsItem *parent) : QGraphicsObject(parent) {
+            setFlag(ItemHasNoContents);
+        }
+        int type() const override { return Type; }
+        QRectF boundingRect() const override { return QRectF(); }
+        void paint(QPainter *, const QStyleOptionGraphicsItem *, QWidget *) override {}
+
+        /// Returns the guard of the item, creating it the first time
+        static QGraphicsItemGuard *of(QGraphicsItem *item) {
+            for (auto child : item->childItems()) {
+                if (auto guard = qgraphicsitem_cast<QGraphicsItemGuard *>(child))
+                    return guard;
+            }
+            return new QGraphicsItemGuard(item);
+        }
+    };
+
+    /// An item referenced from Rust, which is only accessed as long as it exists.
+    struct QGraphicsItemRef {
+        QGraphicsItem *item = nullptr;
+        QPointer<QGraphicsObject> guard;
+
+        QGraphicsItemRef() = default;
+        explicit QGraphicsItemRef(QGraphicsItem *item)
+            : item(item), guard(item ? QGraphicsItemGuard::of(item) : nullptr) {}
+
+        QGraphicsItem *get() const {
+            return guard ? item : nullptr;
+        }
+    };
+}}
+
+cpp_class!(
+    /// Wrapper around [`QGraphicsScene`][class] class.
+    ///
+    /// The scene owns its items, and is deleted when this object is dropped, unless it is shown
+    /// in a [`QGraphicsView`], which then becomes its parent.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qgraphicsscene.html
+    #[derive(Default)]
+    pub unsafe struct QGraphicsScene as "QGraphicsSceneHolder"
+);
+
+impl QGraphicsScene {
+    /// Creates a new empty scene
+    pub fn new() -> QGraphicsScene {
+        Default::default()
+    }
+
+    /// Returns a pointer to the underlying QGraphicsScene, to be used as a sender with
+    /// [`connections::connect`](../connections/fn.connect.html).
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *"] -> *mut c_void as "QGraphicsScene *" {
+            return self->scene.data();
+        })
+    }
+
+    fn item_ref(&self, item: *mut c_void) -> QGraphicsItem {
+        cpp!(unsafe [item as "QGraphicsItem *"] -> QGraphicsItem as "QGraphicsItemRef" {
+            return QGraphicsItemRef(item);
+        })
+    }
+
+    /// Adds a rectangle to the scene and returns it.
+    ///
+    /// Refer to the Qt documentation of QGraphicsScene::addRect
+    pub fn add_rect(&self, rect: QRectF, pen: &QPen, brush: &QBrush) -> QGraphicsItem {
+        let item = cpp!(unsafe [self as "const QGraphicsSceneHolder *", rect as "QRectF",
+                                pen as "const QPen *", brush as "const QBrush *"]
+                -> *mut c_void as "QGraphicsItem *" {
+            return self->scene ? self->scene->addRect(rect, *pen, *brush) : nullptr;
+        });
+        self.item_ref(item)
+    }
+
+    /// Adds a plain text to the scene and returns it.
+    ///
+    /// Refer to the Qt documentation of QGraphicsScene::addText
+    pub fn add_text(&self, text: &str) -> QGraphicsItem {
+        let text = QString::from(text);
+        let item = cpp!(unsafe [self as "const QGraphicsSceneHolder *", text as "QString"]
+                -> *mut c_void as "QGraphicsItem *" {
+            return self->scene ? self->scene->addText(text) : nullptr;
+        });
+        self.item_ref(item)
+    }
+
+    /// Adds a line to the scene and returns it.
+    ///
+    /// Refer to the Qt documentation of QGraphicsScene::addLine
+    pub fn add_line(&self, line: QLineF, pen: &QPen) -> QGraphicsItem {
+        let item = cpp!(unsafe [self as "const QGraphicsSceneHolder *", line as "QLineF",
+                                pen as "const QPen *"] -> *mut c_void as "QGraphicsItem *" {
+            return self->scene ? self->scene->addLine(line, *pen) : nullptr;
+        });
+        self.item_ref(item)
+    }
+
+    /// Removes the item from the scene and deletes it.
+    pub fn delete_item(&self, item: &QGraphicsItem) {
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *", item as "const QGraphicsItemRef *"] {
+            auto i = item->get();
+            if (i && self->scene && i->scene() == self->scene)
+                delete i;
+        })
+    }
+
+    /// Returns all the items of the scene, in descending stacking order.
+    ///
+    /// Refer to the Qt documentation of QGraphicsScene::items
+    pub fn items(&self) -> Vec<QGraphicsItem> {
+        let mut result = Vec::new();
+        let result_ref = &mut result;
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *", result_ref as "void *"] {
+            if (!self->scene)
+                return;
+            for (auto i : self->scene->items()) {
+                if (qgraphicsitem_cast<QGraphicsItemGuard *>(i))
+                    continue;
+                auto item = QGraphicsItemRef(i);
+                rust!(QGraphicsScene_items_push [
+                    result_ref: &mut Vec<QGraphicsItem> as "void *",
+                    item: QGraphicsItem as "QGraphicsItemRef"
+                ] {
+                    result_ref.push(item);
+                });
+            }
+        });
+        result
+    }
+
+    /// Returns the items at the given position, in descending stacking order.
+    ///
+    /// Refer to the Qt documentation of QGraphicsScene::items
+    pub fn items_at(&self, pos: QPointF) -> Vec<QGraphicsItem> {
+        let mut result = Vec::new();
+        let result_ref = &mut result;
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *", pos as "QPointF", result_ref as "void *"] {
+            if (!self->scene)
+                return;
+            for (auto i : self->scene->items(pos)) {
+                if (qgraphicsitem_cast<QGraphicsItemGuard *>(i))
+                    continue;
+                auto item = QGraphicsItemRef(i);
+                rust!(QGraphicsScene_items_at_push [
+                    result_ref: &mut Vec<QGraphicsItem> as "void *",
+                    item: QGraphicsItem as "QGraphicsItemRef"
+                ] {
+                    result_ref.push(item);
+                });
+            }
+        });
+        result
+    }
+
+    /// Refer to the Qt documentation of QGraphicsScene::itemsBoundingRect
+    pub fn items_bounding_rect(&self) -> QRectF {
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *"] -> QRectF as "QRectF" {
+            return self->scene ? self->scene->itemsBoundingRect() : QRectF();
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsScene::setSceneRect
+    pub fn set_scene_rect(&self, rect: QRectF) {
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *", rect as "QRectF"] {
+            if (self->scene)
+                self->scene->setSceneRect(rect);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsScene::sceneRect
+    pub fn scene_rect(&self) -> QRectF {
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *"] -> QRectF as "QRectF" {
+            return self->scene ? self->scene->sceneRect() : QRectF();
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsScene::clear
+    pub fn clear(&self) {
+        cpp!(unsafe [self as "const QGraphicsSceneHolder *"] {
+            if (self->scene)
+                self->scene->clear();
+        })
+    }
+}
+
+cpp_class!(
+    /// A reference to a [`QGraphicsItem`][class] owned by a [`QGraphicsScene`].
+    ///
+    /// The item is not accessed anymore once it was deleted, for example with
+    /// [`QGraphicsScene::delete_item`] or when the scene was destroyed: the getters then return
+    /// default values. To track this, an empty child item is added to the referenced items.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qgraphicsitem.html
+    #[derive(Clone)]
+    pub unsafe struct QGraphicsItem as "QGraphicsItemRef"
+);
+
+impl QGraphicsItem {
+    /// Returns true if the item was deleted.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QGraphicsItemRef *"] -> bool as "bool" {
+            return !self->get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::pos
+    pub fn pos(&self) -> QPointF {
+        cpp!(unsafe [self as "const QGraphicsItemRef *"] -> QPointF as "QPointF" {
+            auto item = self->get();
+            return item ? item->pos() : QPointF();
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::setPos
+    pub fn set_pos(&self, pos: QPointF) {
+        cpp!(unsafe [self as "const QGraphicsItemRef *", pos as "QPointF"] {
+            if (auto item = self->get())
+                item->setPos(pos);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::zValue
+    pub fn z_value(&self) -> qreal {
+        cpp!(unsafe [self as "const QGraphicsItemRef *"] -> qreal as "qreal" {
+            auto item = self->get();
+            return item ? item->zValue() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::setZValue
+    pub fn set_z_value(&self, z: qreal) {
+        cpp!(unsafe [self as "const QGraphicsItemRef *", z as "qreal"] {
+            if (auto item = self->get())
+                item->setZValue(z);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::isVisible
+    pub fn is_visible(&self) -> bool {
+        cpp!(unsafe [self as "const QGraphicsItemRef *"] -> bool as "bool" {
+            auto item = self->get();
+            return item && item->isVisible();
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::setVisible
+    pub fn set_visible(&self, visible: bool) {
+        cpp!(unsafe [self as "const QGraphicsItemRef *", visible as "bool"] {
+            if (auto item = self->get())
+                item->setVisible(visible);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsItem::setToolTip
+    pub fn set_tool_tip(&self, tool_tip: &str) {
+        let tool_tip = QString::from(tool_tip);
+        cpp!(unsafe [self as "const QGraphicsItemRef *", tool_tip as "QString"] {
+            if (auto item = self->get())
+                item->setToolTip(tool_tip);
+        })
+    }
+
+    /// Returns the bounding rectangle of the item, in scene coordinates.
+    ///
+    /// Refer to the Qt documentation of QGraphicsItem::sceneBoundingRect
+    pub fn scene_bounding_rect(&self) -> QRectF {
+        cpp!(unsafe [self as "const QGraphicsItemRef *"] -> QRectF as "QRectF" {
+            auto item = self->get();
+            return item ? item->sceneBoundingRect() : QRectF();
+        })
+    }
+
+    /// Returns the text of a text item, or an empty string for the other items.
+    ///
+    /// Refer to the Qt documentation of QGraphicsTextItem::toPlainText
+    pub fn text(&self) -> QString {
+        cpp!(unsafe [self as "const QGraphicsItemRef *"] -> QString as "QString" {
+            auto item = qgraphicsitem_cast<QGraphicsTextItem *>(self->get());
+            return item ? item->toPlainText() : QString();
+        })
+    }
+}
+
+/// Wrapper around [`QGraphicsView::DragMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qgraphicsview.html#DragMode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DragMode {
+    NoDrag = 0,
+    ScrollHandDrag = 1,
+    RubberBandDrag = 2,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QGraphicsView`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qgraphicsview.html
+    QGraphicsView: QWidget
+);
+
+impl QGraphicsView {
+    /// Creates a new view without scene and without parent
+    pub fn new() -> QGraphicsView {
+        QGraphicsView::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QGraphicsView;
+        }))
+    }
+
+    /// Shows the scene in the view. If the scene has no parent yet, the view becomes its
+    /// parent, so that the scene lives as long as the view.
+    ///
+    /// Refer to the Qt documentation of QGraphicsView::setScene
+    pub fn set_scene(&self, scene: &QGraphicsScene) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", scene as "const QGraphicsSceneHolder *"] {
+            auto view = qobject_cast<QGraphicsView *>(widget->data());
+            if (!view || !scene->scene)
+                return;
+            if (!scene->scene->parent())
+                scene->scene->setParent(view);
+            view->setScene(scene->scene);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsView::fitInView
+    pub fn fit_in_view(&self, rect: QRectF) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", rect as "QRectF"] {
+            if (auto view = qobject_cast<QGraphicsView *>(widget->data()))
+                view->fitInView(rect, Qt::KeepAspectRatio);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsView::setDragMode
+    pub fn set_drag_mode(&self, mode: DragMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", mode as "QGraphicsView::DragMode"] {
+            if (auto view = qobject_cast<QGraphicsView *>(widget->data()))
+                view->setDragMode(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGraphicsView::dragMode
+    pub fn drag_mode(&self) -> DragMode {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> DragMode as "QGraphicsView::DragMode" {
+            auto view = qobject_cast<QGraphicsView *>(widget->data());
+            return view ? view->dragMode() : QGraphicsView::NoDrag;
+        })
+    }
+}
+
+impl Default for QGraphicsView {
+    fn default() -> Self {
+        QGraphicsView::new()
+    }
+}
@@ -0,0 +1,643 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::{QCompleter, QWidget};
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QPointer>
+    #include <QtCore/QRegularExpression>
+    #include <QtGui/QValidator>
+    #include <QtWidgets/QComboBox>
+    #include <QtWidgets/QCompleter>
+    #include <QtWidgets/QLineEdit>
+    #include <QtWidgets/QSpinBox>
+
+    struct QValidatorHolder {
+        std::unique_ptr<QValidator> validator;
+
+        QValidatorHolder(QValidator *validator) : validator(validator) {}
+    };
+}}
+
+/// Wrapper around [`QValidator::State`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qvalidator.html#State-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidatorState {
+    Invalid = 0,
+    Intermediate = 1,
+    Acceptable = 2,
+}
+
+cpp_class!(
+    /// Wrapper around [`QValidator`][class] class, created with one of the constructors of
+    /// the standard validators.
+    ///
+    /// A widget using the validator does not own it: it stops validating its input once the
+    /// validator is dropped.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qvalidator.html
+    pub unsafe struct QValidator as "QValidatorHolder"
+);
+
+impl QValidator {
+    /// Creates a validator accepting the integers from `bottom` to `top`.
+    ///
+    /// Refer to the Qt documentation of QIntValidator
+    pub fn new_int(bottom: i32, top: i32) -> QValidator {
+        cpp!(unsafe [bottom as "int", top as "int"] -> QValidator as "QValidatorHolder" {
+            return QValidatorHolder(new QIntValidator(bottom, top));
+        })
+    }
+
+    /// Creates a validator accepting the floating point numbers from `bottom` to `top`, with at
+    /// most `decimals` digits after the decimal point.
+    ///
+    /// Refer to the Qt documentation of QDoubleValidator
+    pub fn new_double(bottom: f64, top: f64, decimals: i32) -> QValidator {
+        cpp!(unsafe [bottom as "double", top as "double", decimals as "int"] -> QValidator as "QValidatorHolder" {
+            return QValidatorHolder(new QDoubleValidator(bottom, top, decimals));
+        })
+    }
+
+    /// Creates a validator accepting the strings matching the regular expression.
+    ///
+    /// Refer to the Qt documentation of QRegularExpressionValidator
+    pub fn new_regular_expression(pattern: &str) -> QValidator {
+        let pattern = QString::from(pattern);
+        cpp!(unsafe [pattern as "QString"] -> QValidator as "QValidatorHolder" {
+            return QValidatorHolder(new QRegularExpressionValidator(QRegularExpression(pattern)));
+        })
+    }
+
+    /// Returns a pointer to the underlying QValidator.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "QValidatorHolder *"] -> *mut c_void as "QValidator *" {
+            return self->validator.get();
+        })
+    }
+
+    /// Refer to the Qt documentation of QValidator::validate
+    pub fn validate(&self, input: &str) -> ValidatorState {
+        let input = QString::from(input);
+        cpp!(unsafe [self as "QValidatorHolder *", input as "QString"] -> ValidatorState as "QValidator::State" {
+            int pos = 0;
+            return self->validator->validate(input, pos);
+        })
+    }
+}
+
+/// Wrapper around [`QLineEdit::EchoMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qlineedit.html#EchoMode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EchoMode {
+    Normal = 0,
+    NoEcho = 1,
+    Password = 2,
+    PasswordEchoOnEdit = 3,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QLineEdit`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlineedit.html
+    QLineEdit: QWidget
+);
+
+impl QLineEdit {
+    /// Creates a new empty line edit without parent
+    pub fn new() -> QLineEdit {
+        QLineEdit::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QLineEdit;
+        }))
+    }
+
+    /// Creates a new line edit without parent, containing the given text
+    pub fn new_with_text(text: &str) -> QLineEdit {
+        let text = QString::from(text);
+        QLineEdit::from_widget(cpp!(unsafe [text as "QString"] -> QWidget as "QPointer<QWidget>" {
+            return new QLineEdit(text);
+        }))
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto edit = qobject_cast<QLineEdit *>(widget->data());
+            return edit ? edit->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::setText
+    pub fn set_text(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto edit = qobject_cast<QLineEdit *>(widget->data()))
+                edit->setText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::setPlaceholderText
+    pub fn set_placeholder_text(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto edit = qobject_cast<QLineEdit *>(widget->data()))
+                edit->setPlaceholderText(text);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::displayText
+    pub fn display_text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto edit = qobject_cast<QLineEdit *>(widget->data());
+            return edit ? edit->displayText() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::setEchoMode
+    pub fn set_echo_mode(&self, mode: EchoMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", mode as "QLineEdit::EchoMode"] {
+            if (auto edit = qobject_cast<QLineEdit *>(widget->data()))
+                edit->setEchoMode(mode);
+        })
+    }
+
+    /// Sets the validator restricting the input. The line edit does not take ownership of the
+    /// validator.
+    ///
+    /// Refer to the Qt documentation of QLineEdit::setValidator
+    pub fn set_validator(&self, validator: &QValidator) {
+        let widget: &QWidget = self;
+        let validator = validator.cpp_ptr();
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", validator as "const QValidator *"] {
+            if (auto edit = qobject_cast<QLineEdit *>(widget->data()))
+                edit->setValidator(validator);
+        })
+    }
+
+    /// Sets the completer providing completions while typing. The line edit does not take
+    /// ownership of the completer.
+    ///
+    /// Refer to the Qt documentation of QLineEdit::setCompleter
+    pub fn set_completer(&self, completer: &QCompleter) {
+        let widget: &QWidget = self;
+        let completer = completer.cpp_ptr();
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", completer as "QCompleter *"] {
+            if (auto edit = qobject_cast<QLineEdit *>(widget->data()))
+                edit->setCompleter(completer);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::setMaxLength
+    pub fn set_max_length(&self, length: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", length as "int"] {
+            if (auto edit = qobject_cast<QLineEdit *>(widget->data()))
+                edit->setMaxLength(length);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::hasAcceptableInput
+    pub fn has_acceptable_input(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto edit = qobject_cast<QLineEdit *>(widget->data());
+            return edit && edit->hasAcceptableInput();
+        })
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::textChanged
+    pub fn text_changed_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLineEdit::textChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::textEdited
+    pub fn text_edited_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLineEdit::textEdited;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QLineEdit::returnPressed
+    pub fn return_pressed_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QLineEdit::returnPressed;
+            }))
+        }
+    }
+}
+
+impl Default for QLineEdit {
+    fn default() -> Self {
+        QLineEdit::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QComboBox`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qcombobox.html
+    QComboBox: QWidget
+);
+
+impl QComboBox {
+    /// Creates a new empty combo box without parent
+    pub fn new() -> QComboBox {
+        QComboBox::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QComboBox;
+        }))
+    }
+
+    /// Sets the model providing the items, replacing the default model of the combo box.
+    ///
+    /// The C++ object of the model must already exist, so the model needs to be pinned.
+    /// The model must outlive the combo box.
+    pub fn set_model(&self, model: &dyn QAbstractItemModel) {
+        let model = model.get_cpp_object();
+        assert!(!model.is_null(), "The model must be pinned before being set on a combo box");
+        unsafe { self.set_model_ptr(model) }
+    }
+
+    /// Sets a C++ model, such as the one returned by
+    /// [`QStandardItemModel::cpp_ptr`](../struct.QStandardItemModel.html#method.cpp_ptr),
+    /// to provide the items.
+    ///
+    /// # Safety
+    ///
+    /// `model` must be a valid pointer to a QAbstractItemModel that outlives the combo box.
+    pub unsafe fn set_model_ptr(&self, model: *mut c_void) {
+        let widget: &QWidget = self;
+        cpp!([widget as "const QPointer<QWidget> *", model as "QAbstractItemModel *"] {
+            if (auto combo = qobject_cast<QComboBox *>(widget->data()))
+                combo->setModel(model);
+        })
+    }
+
+    /// Appends an item to the default model of the combo box.
+    ///
+    /// Refer to the Qt documentation of QComboBox::addItem
+    pub fn add_item(&self, text: &str) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString"] {
+            if (auto combo = qobject_cast<QComboBox *>(widget->data()))
+                combo->addItem(text);
+        })
+    }
+
+    /// Appends the items to the default model of the combo box.
+    ///
+    /// Refer to the Qt documentation of QComboBox::addItems
+    pub fn add_items(&self, texts: &[&str]) {
+        for text in texts {
+            self.add_item(text);
+        }
+    }
+
+    /// Refer to the Qt documentation of QComboBox::count
+    pub fn count(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto combo = qobject_cast<QComboBox *>(widget->data());
+            return combo ? combo->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QComboBox::currentIndex
+    pub fn current_index(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto combo = qobject_cast<QComboBox *>(widget->data());
+            return combo ? combo->currentIndex() : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QComboBox::setCurrentIndex
+    pub fn set_current_index(&self, index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "int"] {
+            if (auto combo = qobject_cast<QComboBox *>(widget->data()))
+                combo->setCurrentIndex(index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QComboBox::currentText
+    pub fn current_text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto combo = qobject_cast<QComboBox *>(widget->data());
+            return combo ? combo->currentText() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QComboBox::currentIndexChanged
+    pub fn current_index_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return static_cast<void (QComboBox::*)(int)>(&QComboBox::currentIndexChanged);
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QComboBox::currentTextChanged
+    pub fn current_text_changed_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QComboBox::currentTextChanged;
+            }))
+        }
+    }
+}
+
+impl Default for QComboBox {
+    fn default() -> Self {
+        QComboBox::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QSpinBox`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qspinbox.html
+    QSpinBox: QWidget
+);
+
+impl QSpinBox {
+    /// Creates a new integer spin box without parent
+    pub fn new() -> QSpinBox {
+        QSpinBox::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QSpinBox;
+        }))
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::setRange
+    pub fn set_range(&self, min: i32, max: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "int", max as "int"] {
+            if (auto spin = qobject_cast<QSpinBox *>(widget->data()))
+                spin->setRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::minimum
+    pub fn minimum(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto spin = qobject_cast<QSpinBox *>(widget->data());
+            return spin ? spin->minimum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::maximum
+    pub fn maximum(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto spin = qobject_cast<QSpinBox *>(widget->data());
+            return spin ? spin->maximum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::value
+    pub fn value(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto spin = qobject_cast<QSpinBox *>(widget->data());
+            return spin ? spin->value() : 0;
+        })
+    }
+
+    /// Sets the value, which is clamped to the range.
+    ///
+    /// Refer to the Qt documentation of QSpinBox::setValue
+    pub fn set_value(&self, value: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", value as "int"] {
+            if (auto spin = qobject_cast<QSpinBox *>(widget->data()))
+                spin->setValue(value);
+        })
+    }
+
+    /// Sets the amount by which the value changes with the arrows.
+    ///
+    /// Refer to the Qt documentation of QSpinBox::setSingleStep
+    pub fn set_step(&self, step: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", step as "int"] {
+            if (auto spin = qobject_cast<QSpinBox *>(widget->data()))
+                spin->setSingleStep(step);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::setPrefix
+    pub fn set_prefix(&self, prefix: &str) {
+        let widget: &QWidget = self;
+        let prefix = QString::from(prefix);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", prefix as "QString"] {
+            if (auto spin = qobject_cast<QSpinBox *>(widget->data()))
+                spin->setPrefix(prefix);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::setSuffix
+    pub fn set_suffix(&self, suffix: &str) {
+        let widget: &QWidget = self;
+        let suffix = QString::from(suffix);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", suffix as "QString"] {
+            if (auto spin = qobject_cast<QSpinBox *>(widget->data()))
+                spin->setSuffix(suffix);
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto spin = qobject_cast<QSpinBox *>(widget->data());
+            return spin ? spin->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QSpinBox::valueChanged
+    pub fn value_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return static_cast<void (QSpinBox::*)(int)>(&QSpinBox::valueChanged);
+            }))
+        }
+    }
+}
+
+impl Default for QSpinBox {
+    fn default() -> Self {
+        QSpinBox::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QDoubleSpinBox`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qdoublespinbox.html
+    QDoubleSpinBox: QWidget
+);
+
+impl QDoubleSpinBox {
+    /// Creates a new floating point spin box without parent
+    pub fn new() -> QDoubleSpinBox {
+        QDoubleSpinBox::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QDoubleSpinBox;
+        }))
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::setRange
+    pub fn set_range(&self, min: f64, max: f64) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "double", max as "double"] {
+            if (auto spin = qobject_cast<QDoubleSpinBox *>(widget->data()))
+                spin->setRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::minimum
+    pub fn minimum(&self) -> f64 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> f64 as "double" {
+            auto spin = qobject_cast<QDoubleSpinBox *>(widget->data());
+            return spin ? spin->minimum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::maximum
+    pub fn maximum(&self) -> f64 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> f64 as "double" {
+            auto spin = qobject_cast<QDoubleSpinBox *>(widget->data());
+            return spin ? spin->maximum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::value
+    pub fn value(&self) -> f64 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> f64 as "double" {
+            auto spin = qobject_cast<QDoubleSpinBox *>(widget->data());
+            return spin ? spin->value() : 0;
+        })
+    }
+
+    /// Sets the value, which is clamped to the range.
+    ///
+    /// Refer to the Qt documentation of QDoubleSpinBox::setValue
+    pub fn set_value(&self, value: f64) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", value as "double"] {
+            if (auto spin = qobject_cast<QDoubleSpinBox *>(widget->data()))
+                spin->setValue(value);
+        })
+    }
+
+    /// Sets the amount by which the value changes with the arrows.
+    ///
+    /// Refer to the Qt documentation of QDoubleSpinBox::setSingleStep
+    pub fn set_step(&self, step: f64) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", step as "double"] {
+            if (auto spin = qobject_cast<QDoubleSpinBox *>(widget->data()))
+                spin->setSingleStep(step);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::setPrefix
+    pub fn set_prefix(&self, prefix: &str) {
+        let widget: &QWidget = self;
+        let prefix = QString::from(prefix);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", prefix as "QString"] {
+            if (auto spin = qobject_cast<QDoubleSpinBox *>(widget->data()))
+                spin->setPrefix(prefix);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::setSuffix
+    pub fn set_suffix(&self, suffix: &str) {
+        let widget: &QWidget = self;
+        let suffix = QString::from(suffix);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", suffix as "QString"] {
+            if (auto spin = qobject_cast<QDoubleSpinBox *>(widget->data()))
+                spin->setSuffix(suffix);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::text
+    pub fn text(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto spin = qobject_cast<QDoubleSpinBox *>(widget->data());
+            return spin ? spin->text() : QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::setDecimals
+    pub fn set_decimals(&self, decimals: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", decimals as "int"] {
+            if (auto spin = qobject_cast<QDoubleSpinBox *>(widget->data()))
+                spin->setDecimals(decimals);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::decimals
+    pub fn decimals(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto spin = qobject_cast<QDoubleSpinBox *>(widget->data());
+            return spin ? spin->decimals() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QDoubleSpinBox::valueChanged
+    pub fn value_changed_signal() -> Signal<fn(f64)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return static_cast<void (QDoubleSpinBox::*)(double)>(&QDoubleSpinBox::valueChanged);
+            }))
+        }
+    }
+}
+
+impl Default for QDoubleSpinBox {
+    fn default() -> Self {
+        QDoubleSpinBox::new()
+    }
+}
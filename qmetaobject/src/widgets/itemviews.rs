@@ -0,0 +1,320 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QHeaderView>
+    #include <QtWidgets/QTableView>
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QAbstractItemView`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qabstractitemview.html
+    abstract QAbstractItemView: QWidget
+);
+
+impl QAbstractItemView {
+    /// Sets the model to be displayed by the view.
+    ///
+    /// The C++ object of the model must already exist, so the model needs to be pinned.
+    /// The model must outlive the view.
+    pub fn set_model(&self, model: &dyn QAbstractItemModel) {
+        let model = model.get_cpp_object();
+        assert!(!model.is_null(), "The model must be pinned before being set on a view");
+        unsafe { self.set_model_ptr(model) }
+    }
+
+    /// Sets a C++ model, such as the one returned by
+    /// [`QStandardItemModel::cpp_ptr`](../struct.QStandardItemModel.html#method.cpp_ptr),
+    /// to be displayed by the view.
+    ///
+    /// # Safety
+    ///
+    /// `model` must be a valid pointer to a QAbstractItemModel that outlives the view.
+    pub unsafe fn set_model_ptr(&self, model: *mut c_void) {
+        let widget: &QWidget = self;
+        cpp!([widget as "const QPointer<QWidget> *", model as "QAbstractItemModel *"] {
+            if (auto view = qobject_cast<QAbstractItemView *>(widget->data()))
+                view->setModel(model);
+        })
+    }
+
+    /// Returns a pointer to the selection model of the view, which can be used as a sender
+    /// with [`connections::connect`](../connections/fn.connect.html) to track the selection.
+    ///
+    /// Refer to the Qt documentation of QAbstractItemView::selectionModel
+    pub fn selection_model_ptr(&self) -> *mut c_void {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> *mut c_void as "QItemSelectionModel *" {
+            auto view = qobject_cast<QAbstractItemView *>(widget->data());
+            return view ? view->selectionModel() : nullptr;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemView::currentIndex
+    pub fn current_index(&self) -> QModelIndex {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QModelIndex as "QModelIndex" {
+            auto view = qobject_cast<QAbstractItemView *>(widget->data());
+            return view ? view->currentIndex() : QModelIndex();
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemView::setCurrentIndex
+    pub fn set_current_index(&self, index: &QModelIndex) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", index as "const QModelIndex *"] {
+            if (auto view = qobject_cast<QAbstractItemView *>(widget->data()))
+                view->setCurrentIndex(*index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemView::clicked
+    pub fn clicked_signal() -> Signal<fn(QModelIndex)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractItemView::clicked;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QAbstractItemView::activated
+    pub fn activated_signal() -> Signal<fn(QModelIndex)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractItemView::activated;
+            }))
+        }
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QTableView`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtableview.html
+    QTableView: QAbstractItemView
+);
+
+impl QTableView {
+    /// Creates a new table view without parent
+    pub fn new() -> QTableView {
+        QTableView::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QTableView;
+        }))
+    }
+
+    /// Returns the horizontal header of the view, which is owned by the view.
+    ///
+    /// Refer to the Qt documentation of QTableView::horizontalHeader
+    pub fn horizontal_header(&self) -> QHeaderView {
+        let widget: &QWidget = self;
+        QHeaderView::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+                auto view = qobject_cast<QTableView *>(widget->data());
+                return view ? view->horizontalHeader() : nullptr;
+            }),
+        )
+    }
+
+    /// Returns the vertical header of the view, which is owned by the view.
+    ///
+    /// Refer to the Qt documentation of QTableView::verticalHeader
+    pub fn vertical_header(&self) -> QHeaderView {
+        let widget: &QWidget = self;
+        QHeaderView::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+                auto view = qobject_cast<QTableView *>(widget->data());
+                return view ? view->verticalHeader() : nullptr;
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QTableView::setSortingEnabled
+    pub fn set_sorting_enabled(&self, enable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", enable as "bool"] {
+            if (auto view = qobject_cast<QTableView *>(widget->data()))
+                view->setSortingEnabled(enable);
+        })
+    }
+}
+
+impl Default for QTableView {
+    fn default() -> Self {
+        QTableView::new()
+    }
+}
+
+/// Wrapper around [`QHeaderView::ResizeMode`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qheaderview.html#ResizeMode-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeMode {
+    Interactive = 0,
+    Stretch = 1,
+    Fixed = 2,
+    ResizeToContents = 3,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QHeaderView`][class] class.
+    ///
+    /// The headers of a table view are obtained with [`QTableView::horizontal_header`] and
+    /// [`QTableView::vertical_header`].
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qheaderview.html
+    QHeaderView: QAbstractItemView
+);
+
+impl QHeaderView {
+    /// Creates a new header without parent
+    pub fn new(orientation: Orientation) -> QHeaderView {
+        QHeaderView::from_widget(
+            cpp!(unsafe [orientation as "Qt::Orientation"] -> QWidget as "QPointer<QWidget>" {
+                return new QHeaderView(orientation);
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::setSectionResizeMode
+    pub fn set_section_resize_mode(&self, logical_index: i32, mode: ResizeMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int", mode as "QHeaderView::ResizeMode"] {
+            if (auto header = qobject_cast<QHeaderView *>(widget->data()))
+                header->setSectionResizeMode(logical_index, mode);
+        })
+    }
+
+    /// Sets the resize mode of all the sections.
+    ///
+    /// Refer to the Qt documentation of QHeaderView::setSectionResizeMode
+    pub fn set_all_sections_resize_mode(&self, mode: ResizeMode) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", mode as "QHeaderView::ResizeMode"] {
+            if (auto header = qobject_cast<QHeaderView *>(widget->data()))
+                header->setSectionResizeMode(mode);
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::sectionResizeMode
+    pub fn section_resize_mode(&self, logical_index: i32) -> ResizeMode {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int"] -> ResizeMode as "QHeaderView::ResizeMode" {
+            auto header = qobject_cast<QHeaderView *>(widget->data());
+            return header ? header->sectionResizeMode(logical_index) : QHeaderView::Interactive;
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::setDefaultSectionSize
+    pub fn set_default_section_size(&self, size: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", size as "int"] {
+            if (auto header = qobject_cast<QHeaderView *>(widget->data()))
+                header->setDefaultSectionSize(size);
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::hideSection
+    pub fn hide_section(&self, logical_index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int"] {
+            if (auto header = qobject_cast<QHeaderView *>(widget->data()))
+                header->hideSection(logical_index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::showSection
+    pub fn show_section(&self, logical_index: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int"] {
+            if (auto header = qobject_cast<QHeaderView *>(widget->data()))
+                header->showSection(logical_index);
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::isSectionHidden
+    pub fn is_section_hidden(&self, logical_index: i32) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int"] -> bool as "bool" {
+            auto header = qobject_cast<QHeaderView *>(widget->data());
+            return header && header->isSectionHidden(logical_index);
+        })
+    }
+
+    /// Returns the logical index of the section at the given position, or -1 if there is none.
+    ///
+    /// Refer to the Qt documentation of QHeaderView::logicalIndexAt
+    pub fn logical_index_at(&self, position: i32) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", position as "int"] -> i32 as "int" {
+            auto header = qobject_cast<QHeaderView *>(widget->data());
+            return header ? header->logicalIndexAt(position) : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::sectionSize
+    pub fn section_size(&self, logical_index: i32) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int"] -> i32 as "int" {
+            auto header = qobject_cast<QHeaderView *>(widget->data());
+            return header ? header->sectionSize(logical_index) : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::resizeSection
+    pub fn resize_section(&self, logical_index: i32, size: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", logical_index as "int", size as "int"] {
+            if (auto header = qobject_cast<QHeaderView *>(widget->data()))
+                header->resizeSection(logical_index, size);
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::count
+    pub fn count(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto header = qobject_cast<QHeaderView *>(widget->data());
+            return header ? header->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::sectionClicked
+    pub fn section_clicked_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QHeaderView::sectionClicked;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QHeaderView::sectionResized
+    pub fn section_resized_signal() -> Signal<fn(i32, i32, i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QHeaderView::sectionResized;
+            }))
+        }
+    }
+}
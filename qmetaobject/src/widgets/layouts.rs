@@ -0,0 +1,458 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QBoxLayout>
+    #include <QtWidgets/QFormLayout>
+    #include <QtWidgets/QGridLayout>
+    #include <QtWidgets/QWidget>
+}}
+
+/// Same as `widget_wrapper!`, for the layouts: the struct holding the wrapper of the base
+/// class, `Deref` to it, and the internal `from_layout` constructor.
+macro_rules! layout_wrapper {
+    ($(#[$attr:meta])* abstract $name:ident : $base:ident) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        #[repr(transparent)]
+        pub struct $name($base);
+
+        layout_wrapper!(@common $name : $base);
+    };
+    ($(#[$attr:meta])* $name:ident : $base:ident) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        pub struct $name($base);
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                let layout: &QLayout = &self.0;
+                layout.delete_if_unowned();
+            }
+        }
+
+        layout_wrapper!(@common $name : $base);
+    };
+    (@common $name:ident : $base:ident) => {
+        impl std::ops::Deref for $name {
+            type Target = $base;
+            fn deref(&self) -> &$base {
+                &self.0
+            }
+        }
+
+        impl $name {
+            #[allow(dead_code)]
+            pub(crate) fn from_layout(layout: QLayout) -> $name {
+                $name($base::from_layout(layout))
+            }
+        }
+    };
+}
+
+cpp_class!(
+    /// A guarded, non-owning pointer to a [`QLayout`][class].
+    ///
+    /// Like the widgets, a layout is deleted when its Rust object is dropped, unless it was
+    /// installed on a widget with [`QWidget::set_layout`] or added to another layout, in which
+    /// case it is owned by its parent.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qlayout.html
+    #[derive(Clone)]
+    pub unsafe struct QLayout as "QPointer<QLayout>"
+);
+
+impl QLayout {
+    #[allow(dead_code)]
+    pub(crate) fn from_layout(layout: QLayout) -> QLayout {
+        layout
+    }
+
+    /// Deletes the layout, unless it is owned by a parent.
+    pub(crate) fn delete_if_unowned(&self) {
+        cpp!(unsafe [self as "const QPointer<QLayout> *"] {
+            if (*self && !(*self)->parent())
+                delete self->data();
+        })
+    }
+
+    /// Returns a pointer to the underlying QLayout.
+    ///
+    /// Returns a null pointer if the layout was destroyed.
+    pub fn cpp_ptr(&self) -> *mut c_void {
+        cpp!(unsafe [self as "const QPointer<QLayout> *"] -> *mut c_void as "QLayout *" {
+            return self->data();
+        })
+    }
+
+    /// Returns true if the layout was destroyed.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QLayout> *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Adds the widget at the end of the layout, which reparents it to the widget the layout
+    /// is installed on.
+    ///
+    /// Refer to the Qt documentation of QLayout::addWidget
+    pub fn add_widget(&self, widget: &QWidget) {
+        cpp!(unsafe [self as "const QPointer<QLayout> *", widget as "const QPointer<QWidget> *"] {
+            if (*self && *widget)
+                (*self)->addWidget(widget->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QLayout::count
+    pub fn count(&self) -> i32 {
+        cpp!(unsafe [self as "const QPointer<QLayout> *"] -> i32 as "int" {
+            return *self ? (*self)->count() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QLayout::setSpacing
+    pub fn set_spacing(&self, spacing: i32) {
+        cpp!(unsafe [self as "const QPointer<QLayout> *", spacing as "int"] {
+            if (*self)
+                (*self)->setSpacing(spacing);
+        })
+    }
+
+    /// Refer to the Qt documentation of QLayout::spacing
+    pub fn spacing(&self) -> i32 {
+        cpp!(unsafe [self as "const QPointer<QLayout> *"] -> i32 as "int" {
+            return *self ? (*self)->spacing() : -1;
+        })
+    }
+
+    /// Refer to the Qt documentation of QLayout::setContentsMargins
+    pub fn set_contents_margins(&self, left: i32, top: i32, right: i32, bottom: i32) {
+        cpp!(unsafe [self as "const QPointer<QLayout> *", left as "int", top as "int",
+                right as "int", bottom as "int"] {
+            if (*self)
+                (*self)->setContentsMargins(left, top, right, bottom);
+        })
+    }
+}
+
+layout_wrapper!(
+    /// Wrapper around [`QBoxLayout`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qboxlayout.html
+    abstract QBoxLayout: QLayout
+);
+
+impl QBoxLayout {
+    /// Adds the layout at the end of this layout, which then owns it.
+    ///
+    /// Refer to the Qt documentation of QBoxLayout::addLayout
+    pub fn add_layout(&self, layout: &QLayout) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", layout as "const QPointer<QLayout> *"] {
+            if (auto box = qobject_cast<QBoxLayout *>(this->data()))
+                if (*layout)
+                    box->addLayout(layout->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QBoxLayout::addStretch
+    pub fn add_stretch(&self, stretch: i32) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", stretch as "int"] {
+            if (auto box = qobject_cast<QBoxLayout *>(this->data()))
+                box->addStretch(stretch);
+        })
+    }
+
+    /// Refer to the Qt documentation of QBoxLayout::addSpacing
+    pub fn add_spacing(&self, size: i32) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", size as "int"] {
+            if (auto box = qobject_cast<QBoxLayout *>(this->data()))
+                box->addSpacing(size);
+        })
+    }
+}
+
+layout_wrapper!(
+    /// Wrapper around [`QVBoxLayout`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qvboxlayout.html
+    QVBoxLayout: QBoxLayout
+);
+
+impl QVBoxLayout {
+    /// Creates a new vertical layout.
+    pub fn new() -> QVBoxLayout {
+        QVBoxLayout::from_layout(cpp!(unsafe [] -> QLayout as "QPointer<QLayout>" {
+            return new QVBoxLayout;
+        }))
+    }
+}
+
+impl Default for QVBoxLayout {
+    fn default() -> Self {
+        QVBoxLayout::new()
+    }
+}
+
+layout_wrapper!(
+    /// Wrapper around [`QHBoxLayout`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qhboxlayout.html
+    QHBoxLayout: QBoxLayout
+);
+
+impl QHBoxLayout {
+    /// Creates a new horizontal layout.
+    pub fn new() -> QHBoxLayout {
+        QHBoxLayout::from_layout(cpp!(unsafe [] -> QLayout as "QPointer<QLayout>" {
+            return new QHBoxLayout;
+        }))
+    }
+}
+
+impl Default for QHBoxLayout {
+    fn default() -> Self {
+        QHBoxLayout::new()
+    }
+}
+
+layout_wrapper!(
+    /// Wrapper around [`QGridLayout`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qgridlayout.html
+    QGridLayout: QLayout
+);
+
+impl QGridLayout {
+    /// Creates a new grid layout.
+    pub fn new() -> QGridLayout {
+        QGridLayout::from_layout(cpp!(unsafe [] -> QLayout as "QPointer<QLayout>" {
+            return new QGridLayout;
+        }))
+    }
+
+    /// Adds the widget to the grid, spanning `row_span` rows and `column_span` columns from
+    /// the given cell. A span of -1 extends to the last row or column.
+    ///
+    /// Refer to the Qt documentation of QGridLayout::addWidget
+    pub fn add_widget(
+        &self,
+        widget: &QWidget,
+        row: i32,
+        column: i32,
+        row_span: i32,
+        column_span: i32,
+        alignment: Alignment,
+    ) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", widget as "const QPointer<QWidget> *",
+                row as "int", column as "int", row_span as "int", column_span as "int",
+                alignment as "Qt::Alignment"] {
+            if (auto grid = qobject_cast<QGridLayout *>(this->data()))
+                if (*widget)
+                    grid->addWidget(widget->data(), row, column, row_span, column_span, alignment);
+        })
+    }
+
+    /// Adds the layout to the grid, which then owns it. See [`add_widget`](#method.add_widget)
+    /// for the meaning of the arguments.
+    ///
+    /// Refer to the Qt documentation of QGridLayout::addLayout
+    pub fn add_layout(
+        &self,
+        layout: &QLayout,
+        row: i32,
+        column: i32,
+        row_span: i32,
+        column_span: i32,
+        alignment: Alignment,
+    ) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", layout as "const QPointer<QLayout> *",
+                row as "int", column as "int", row_span as "int", column_span as "int",
+                alignment as "Qt::Alignment"] {
+            if (auto grid = qobject_cast<QGridLayout *>(this->data()))
+                if (*layout)
+                    grid->addLayout(layout->data(), row, column, row_span, column_span, alignment);
+        })
+    }
+
+    /// Refer to the Qt documentation of QGridLayout::rowCount
+    pub fn row_count(&self) -> i32 {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *"] -> i32 as "int" {
+            auto grid = qobject_cast<QGridLayout *>(this->data());
+            return grid ? grid->rowCount() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QGridLayout::columnCount
+    pub fn column_count(&self) -> i32 {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *"] -> i32 as "int" {
+            auto grid = qobject_cast<QGridLayout *>(this->data());
+            return grid ? grid->columnCount() : 0;
+        })
+    }
+}
+
+impl Default for QGridLayout {
+    fn default() -> Self {
+        QGridLayout::new()
+    }
+}
+
+/// Wrapper around [`QFormLayout::FieldGrowthPolicy`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qformlayout.html#FieldGrowthPolicy-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldGrowthPolicy {
+    FieldsStayAtSizeHint = 0,
+    ExpandingFieldsGrow = 1,
+    AllNonFixedFieldsGrow = 2,
+}
+
+/// Wrapper around [`QFormLayout::RowWrapPolicy`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qformlayout.html#RowWrapPolicy-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowWrapPolicy {
+    DontWrapRows = 0,
+    WrapLongRows = 1,
+    WrapAllRows = 2,
+}
+
+layout_wrapper!(
+    /// Wrapper around [`QFormLayout`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qformlayout.html
+    QFormLayout: QLayout
+);
+
+impl QFormLayout {
+    /// Creates a new form layout.
+    pub fn new() -> QFormLayout {
+        QFormLayout::from_layout(cpp!(unsafe [] -> QLayout as "QPointer<QLayout>" {
+            return new QFormLayout;
+        }))
+    }
+
+    /// Adds a row at the end of the form, with a label of the given text for the field.
+    ///
+    /// Refer to the Qt documentation of QFormLayout::addRow
+    pub fn add_row(&self, label: &str, field: &QWidget) {
+        let this: &QLayout = self;
+        let label = QString::from(label);
+        cpp!(unsafe [this as "const QPointer<QLayout> *", label as "QString",
+                field as "const QPointer<QWidget> *"] {
+            if (auto form = qobject_cast<QFormLayout *>(this->data()))
+                if (*field)
+                    form->addRow(label, field->data());
+        })
+    }
+
+    /// Adds a row at the end of the form, with the `label` widget on the left of `field`.
+    ///
+    /// Refer to the Qt documentation of QFormLayout::addRow
+    pub fn add_row_widget(&self, label: &QWidget, field: &QWidget) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", label as "const QPointer<QWidget> *",
+                field as "const QPointer<QWidget> *"] {
+            if (auto form = qobject_cast<QFormLayout *>(this->data()))
+                if (*label && *field)
+                    form->addRow(label->data(), field->data());
+        })
+    }
+
+    /// Inserts a row at position `row`, or at the end if `row` is out of bounds.
+    ///
+    /// Refer to the Qt documentation of QFormLayout::insertRow
+    pub fn insert_row(&self, row: i32, label: &str, field: &QWidget) {
+        let this: &QLayout = self;
+        let label = QString::from(label);
+        cpp!(unsafe [this as "const QPointer<QLayout> *", row as "int", label as "QString",
+                field as "const QPointer<QWidget> *"] {
+            if (auto form = qobject_cast<QFormLayout *>(this->data()))
+                if (*field)
+                    form->insertRow(row, label, field->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QFormLayout::rowCount
+    pub fn row_count(&self) -> i32 {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *"] -> i32 as "int" {
+            auto form = qobject_cast<QFormLayout *>(this->data());
+            return form ? form->rowCount() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QFormLayout::setFieldGrowthPolicy
+    pub fn set_field_growth_policy(&self, policy: FieldGrowthPolicy) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *",
+                policy as "QFormLayout::FieldGrowthPolicy"] {
+            if (auto form = qobject_cast<QFormLayout *>(this->data()))
+                form->setFieldGrowthPolicy(policy);
+        })
+    }
+
+    /// Refer to the Qt documentation of QFormLayout::fieldGrowthPolicy
+    pub fn field_growth_policy(&self) -> FieldGrowthPolicy {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *"]
+                -> FieldGrowthPolicy as "QFormLayout::FieldGrowthPolicy" {
+            auto form = qobject_cast<QFormLayout *>(this->data());
+            return form ? form->fieldGrowthPolicy() : QFormLayout::FieldsStayAtSizeHint;
+        })
+    }
+
+    /// Refer to the Qt documentation of QFormLayout::setRowWrapPolicy
+    pub fn set_row_wrap_policy(&self, policy: RowWrapPolicy) {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *", policy as "QFormLayout::RowWrapPolicy"] {
+            if (auto form = qobject_cast<QFormLayout *>(this->data()))
+                form->setRowWrapPolicy(policy);
+        })
+    }
+
+    /// Refer to the Qt documentation of QFormLayout::rowWrapPolicy
+    pub fn row_wrap_policy(&self) -> RowWrapPolicy {
+        let this: &QLayout = self;
+        cpp!(unsafe [this as "const QPointer<QLayout> *"]
+                -> RowWrapPolicy as "QFormLayout::RowWrapPolicy" {
+            auto form = qobject_cast<QFormLayout *>(this->data());
+            return form ? form->rowWrapPolicy() : QFormLayout::DontWrapRows;
+        })
+    }
+}
+
+impl Default for QFormLayout {
+    fn default() -> Self {
+        QFormLayout::new()
+    }
+}
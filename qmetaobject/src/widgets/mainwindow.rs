@@ -0,0 +1,502 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use std::ops::{BitOr, BitOrAssign};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QDockWidget>
+    #include <QtWidgets/QMainWindow>
+    #include <QtWidgets/QStatusBar>
+    #include <QtWidgets/QToolBar>
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QMainWindow`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qmainwindow.html
+    QMainWindow: QWidget
+);
+
+impl QMainWindow {
+    /// Creates a new main window without parent
+    pub fn new() -> QMainWindow {
+        QMainWindow::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QMainWindow;
+        }))
+    }
+
+    /// Sets the central widget, which is then owned by the main window.
+    ///
+    /// Refer to the Qt documentation of QMainWindow::setCentralWidget
+    pub fn set_central_widget(&self, central: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", central as "const QPointer<QWidget> *"] {
+            if (auto window = qobject_cast<QMainWindow *>(widget->data()))
+                if (*central)
+                    window->setCentralWidget(central->data());
+        })
+    }
+
+    /// Returns the status bar of the window, which is owned by the window. It is created if it
+    /// does not exist yet.
+    ///
+    /// Refer to the Qt documentation of QMainWindow::statusBar
+    pub fn status_bar(&self) -> QStatusBar {
+        let widget: &QWidget = self;
+        QStatusBar::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+                auto window = qobject_cast<QMainWindow *>(widget->data());
+                return window ? window->statusBar() : nullptr;
+            }),
+        )
+    }
+
+    /// Adds the tool bar in the top area, the tool bar is then owned by the window.
+    ///
+    /// Refer to the Qt documentation of QMainWindow::addToolBar
+    pub fn add_tool_bar(&self, tool_bar: &QToolBar) {
+        let widget: &QWidget = self;
+        let tool_bar: &QWidget = tool_bar;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", tool_bar as "const QPointer<QWidget> *"] {
+            auto window = qobject_cast<QMainWindow *>(widget->data());
+            auto bar = qobject_cast<QToolBar *>(tool_bar->data());
+            if (window && bar)
+                window->addToolBar(bar);
+        })
+    }
+
+    /// Adds the dock widget in the given area, the dock widget is then owned by the window.
+    ///
+    /// Refer to the Qt documentation of QMainWindow::addDockWidget
+    pub fn add_dock_widget(&self, area: DockWidgetArea, dock: &QDockWidget) {
+        let widget: &QWidget = self;
+        let dock: &QWidget = dock;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", area as "Qt::DockWidgetArea",
+                     dock as "const QPointer<QWidget> *"] {
+            auto window = qobject_cast<QMainWindow *>(widget->data());
+            auto d = qobject_cast<QDockWidget *>(dock->data());
+            if (window && d)
+                window->addDockWidget(area, d);
+        })
+    }
+
+    /// Refer to the Qt documentation of QMainWindow::dockWidgetArea
+    pub fn dock_widget_area(&self, dock: &QDockWidget) -> DockWidgetArea {
+        let widget: &QWidget = self;
+        let dock: &QWidget = dock;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", dock as "const QPointer<QWidget> *"]
+                -> DockWidgetArea as "Qt::DockWidgetArea" {
+            auto window = qobject_cast<QMainWindow *>(widget->data());
+            auto d = qobject_cast<QDockWidget *>(dock->data());
+            return window && d ? window->dockWidgetArea(d) : Qt::NoDockWidgetArea;
+        })
+    }
+}
+
+impl Default for QMainWindow {
+    fn default() -> Self {
+        QMainWindow::new()
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QStatusBar`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qstatusbar.html
+    QStatusBar: QWidget
+);
+
+impl QStatusBar {
+    /// Creates a new status bar without parent
+    pub fn new() -> QStatusBar {
+        QStatusBar::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QStatusBar;
+        }))
+    }
+
+    /// Shows a temporary message for `timeout_ms` milliseconds, or until the next call if the
+    /// timeout is 0.
+    ///
+    /// Refer to the Qt documentation of QStatusBar::showMessage
+    pub fn show_message(&self, text: &str, timeout_ms: i32) {
+        let widget: &QWidget = self;
+        let text = QString::from(text);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", text as "QString", timeout_ms as "int"] {
+            if (auto bar = qobject_cast<QStatusBar *>(widget->data()))
+                bar->showMessage(text, timeout_ms);
+        })
+    }
+
+    /// Refer to the Qt documentation of QStatusBar::clearMessage
+    pub fn clear_message(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto bar = qobject_cast<QStatusBar *>(widget->data()))
+                bar->clearMessage();
+        })
+    }
+
+    /// Refer to the Qt documentation of QStatusBar::currentMessage
+    pub fn current_message(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+            auto bar = qobject_cast<QStatusBar *>(widget->data());
+            return bar ? bar->currentMessage() : QString();
+        })
+    }
+
+    /// Adds a normal widget, which is then owned by the status bar. Normal widgets may be
+    /// hidden by temporary messages.
+    ///
+    /// Refer to the Qt documentation of QStatusBar::addWidget
+    pub fn add_widget(&self, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"] {
+            if (auto bar = qobject_cast<QStatusBar *>(widget->data()))
+                if (*child)
+                    bar->addWidget(child->data());
+        })
+    }
+
+    /// Adds a permanent widget on the right of the status bar, which is then owned by the
+    /// status bar.
+    ///
+    /// Refer to the Qt documentation of QStatusBar::addPermanentWidget
+    pub fn add_permanent_widget(&self, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"] {
+            if (auto bar = qobject_cast<QStatusBar *>(widget->data()))
+                if (*child)
+                    bar->addPermanentWidget(child->data());
+        })
+    }
+
+    /// Removes the widget from the status bar, without deleting it.
+    ///
+    /// Refer to the Qt documentation of QStatusBar::removeWidget
+    pub fn remove_widget(&self, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"] {
+            if (auto bar = qobject_cast<QStatusBar *>(widget->data()))
+                if (*child)
+                    bar->removeWidget(child->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QStatusBar::messageChanged
+    pub fn message_changed_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QStatusBar::messageChanged;
+            }))
+        }
+    }
+}
+
+impl Default for QStatusBar {
+    fn default() -> Self {
+        QStatusBar::new()
+    }
+}
+
+/// Wrapper around [`QDockWidget::DockWidgetFeatures`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qdockwidget.html#DockWidgetFeature-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct DockWidgetFeatures(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl DockWidgetFeatures {
+    pub const NoDockWidgetFeatures: DockWidgetFeatures = DockWidgetFeatures(0);
+    pub const DockWidgetClosable: DockWidgetFeatures = DockWidgetFeatures(0x01);
+    pub const DockWidgetMovable: DockWidgetFeatures = DockWidgetFeatures(0x02);
+    pub const DockWidgetFloatable: DockWidgetFeatures = DockWidgetFeatures(0x04);
+    pub const DockWidgetVerticalTitleBar: DockWidgetFeatures = DockWidgetFeatures(0x08);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: DockWidgetFeatures) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for DockWidgetFeatures {
+    type Output = DockWidgetFeatures;
+    fn bitor(self, other: DockWidgetFeatures) -> DockWidgetFeatures {
+        DockWidgetFeatures(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for DockWidgetFeatures {
+    fn bitor_assign(&mut self, other: DockWidgetFeatures) {
+        self.0 |= other.0;
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QDockWidget`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qdockwidget.html
+    QDockWidget: QWidget
+);
+
+impl QDockWidget {
+    /// Creates a new dock widget with the given title and without parent
+    pub fn new(title: &str) -> QDockWidget {
+        let title = QString::from(title);
+        QDockWidget::from_widget(
+            cpp!(unsafe [title as "QString"] -> QWidget as "QPointer<QWidget>" {
+                return new QDockWidget(title);
+            }),
+        )
+    }
+
+    /// Sets the content of the dock widget, which is then owned by the dock widget.
+    ///
+    /// Refer to the Qt documentation of QDockWidget::setWidget
+    pub fn set_widget(&self, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"] {
+            if (auto dock = qobject_cast<QDockWidget *>(widget->data()))
+                if (*child)
+                    dock->setWidget(child->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::setAllowedAreas
+    pub fn set_allowed_areas(&self, areas: DockWidgetAreas) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", areas as "Qt::DockWidgetAreas"] {
+            if (auto dock = qobject_cast<QDockWidget *>(widget->data()))
+                dock->setAllowedAreas(areas);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::allowedAreas
+    pub fn allowed_areas(&self) -> DockWidgetAreas {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> DockWidgetAreas as "Qt::DockWidgetAreas" {
+            auto dock = qobject_cast<QDockWidget *>(widget->data());
+            return dock ? dock->allowedAreas() : Qt::DockWidgetAreas();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::setFeatures
+    pub fn set_features(&self, features: DockWidgetFeatures) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", features as "QDockWidget::DockWidgetFeatures"] {
+            if (auto dock = qobject_cast<QDockWidget *>(widget->data()))
+                dock->setFeatures(features);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::features
+    pub fn features(&self) -> DockWidgetFeatures {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"]
+                -> DockWidgetFeatures as "QDockWidget::DockWidgetFeatures" {
+            auto dock = qobject_cast<QDockWidget *>(widget->data());
+            return dock ? dock->features() : QDockWidget::DockWidgetFeatures();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::isFloating
+    pub fn is_floating(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto dock = qobject_cast<QDockWidget *>(widget->data());
+            return dock && dock->isFloating();
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::setFloating
+    pub fn set_floating(&self, floating: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", floating as "bool"] {
+            if (auto dock = qobject_cast<QDockWidget *>(widget->data()))
+                dock->setFloating(floating);
+        })
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::dockLocationChanged
+    pub fn dock_location_changed_signal() -> Signal<fn(DockWidgetArea)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QDockWidget::dockLocationChanged;
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QDockWidget::visibilityChanged
+    pub fn visibility_changed_signal() -> Signal<fn(bool)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QDockWidget::visibilityChanged;
+            }))
+        }
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QToolBar`][class] class.
+    ///
+    /// Actions are added to the tool bar with [`QWidget::add_action`].
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qtoolbar.html
+    QToolBar: QWidget
+);
+
+impl QToolBar {
+    /// Creates a new tool bar with the given title and without parent
+    pub fn new(title: &str) -> QToolBar {
+        let title = QString::from(title);
+        QToolBar::from_widget(cpp!(unsafe [title as "QString"] -> QWidget as "QPointer<QWidget>" {
+            return new QToolBar(title);
+        }))
+    }
+
+    /// Appends a separator, which is an action owned by the tool bar.
+    ///
+    /// Refer to the Qt documentation of QToolBar::addSeparator
+    pub fn add_separator(&self) -> QAction {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QAction as "QPointer<QAction>" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar ? bar->addSeparator() : nullptr;
+        })
+    }
+
+    /// Appends the widget, which is then owned by the tool bar. The returned action (a
+    /// QWidgetAction) can be used to show or hide the widget.
+    ///
+    /// Refer to the Qt documentation of QToolBar::addWidget
+    pub fn add_widget(&self, child: &QWidget) -> QAction {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"]
+                -> QAction as "QPointer<QAction>" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar && *child ? bar->addWidget(child->data()) : nullptr;
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::setIconSize
+    pub fn set_icon_size(&self, size: QSize) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", size as "QSize"] {
+            if (auto bar = qobject_cast<QToolBar *>(widget->data()))
+                bar->setIconSize(size);
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::iconSize
+    pub fn icon_size(&self) -> QSize {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QSize as "QSize" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar ? bar->iconSize() : QSize();
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::setToolButtonStyle
+    pub fn set_tool_button_style(&self, style: ToolButtonStyle) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", style as "Qt::ToolButtonStyle"] {
+            if (auto bar = qobject_cast<QToolBar *>(widget->data()))
+                bar->setToolButtonStyle(style);
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::toolButtonStyle
+    pub fn tool_button_style(&self) -> ToolButtonStyle {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> ToolButtonStyle as "Qt::ToolButtonStyle" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar ? bar->toolButtonStyle() : Qt::ToolButtonIconOnly;
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::setMovable
+    pub fn set_movable(&self, movable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", movable as "bool"] {
+            if (auto bar = qobject_cast<QToolBar *>(widget->data()))
+                bar->setMovable(movable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::isMovable
+    pub fn is_movable(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar && bar->isMovable();
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::setFloatable
+    pub fn set_floatable(&self, floatable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", floatable as "bool"] {
+            if (auto bar = qobject_cast<QToolBar *>(widget->data()))
+                bar->setFloatable(floatable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::isFloatable
+    pub fn is_floatable(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar && bar->isFloatable();
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::orientation
+    pub fn orientation(&self) -> Orientation {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> Orientation as "Qt::Orientation" {
+            auto bar = qobject_cast<QToolBar *>(widget->data());
+            return bar ? bar->orientation() : Qt::Horizontal;
+        })
+    }
+
+    /// Refer to the Qt documentation of QToolBar::orientationChanged
+    pub fn orientation_changed_signal() -> Signal<fn(Orientation)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QToolBar::orientationChanged;
+            }))
+        }
+    }
+
+    /// The argument of the signal is a pointer to the QAction, see [`QAction::from_ptr`].
+    ///
+    /// Refer to the Qt documentation of QToolBar::actionTriggered
+    pub fn action_triggered_signal() -> Signal<fn(*mut c_void)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QToolBar::actionTriggered;
+            }))
+        }
+    }
+}
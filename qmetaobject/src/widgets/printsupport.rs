@@ -0,0 +1,247 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::{cpp, cpp_class};
+
+use super::{DialogCode, QDialog, QWidget};
+use crate::*;
+
+cpp! {{
+    #include <memory>
+    #include <QtCore/QPointer>
+    #include <QtGui/QPageSize>
+    #include <QtPrintSupport/QPrintDialog>
+    #include <QtPrintSupport/QPrinter>
+
+    struct QPrinterHolder {
+        std::unique_ptr<QPrinter> printer;
+
+        QPrinterHolder() : printer(new QPrinter) {}
+    };
+}}
+
+/// Wrapper around [`QPageSize::PageSizeId`][enum] enum.
+///
+/// Only the most common sizes are listed.
+///
+/// [enum]: https://doc.qt.io/qt-5/qpagesize.html#PageSizeId-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageSizeId {
+    A4 = 0,
+    B5 = 1,
+    Letter = 2,
+    Legal = 3,
+    Executive = 4,
+    A0 = 5,
+    A1 = 6,
+    A2 = 7,
+    A3 = 8,
+    A5 = 9,
+    A6 = 10,
+    B4 = 19,
+    Ledger = 28,
+    Tabloid = 29,
+}
+
+cpp_class!(
+    /// Wrapper around [`QPageSize`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qpagesize.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QPageSize as "QPageSize"
+);
+
+impl QPageSize {
+    /// Creates one of the standard page sizes
+    pub fn new(id: PageSizeId) -> QPageSize {
+        cpp!(unsafe [id as "QPageSize::PageSizeId"] -> QPageSize as "QPageSize" {
+            return QPageSize(id);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPageSize::isValid
+    pub fn is_valid(&self) -> bool {
+        cpp!(unsafe [self as "const QPageSize *"] -> bool as "bool" {
+            return self->isValid();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPageSize::name
+    pub fn name(&self) -> QString {
+        cpp!(unsafe [self as "const QPageSize *"] -> QString as "QString" {
+            return self->name();
+        })
+    }
+}
+
+impl From<PageSizeId> for QPageSize {
+    fn from(id: PageSizeId) -> Self {
+        QPageSize::new(id)
+    }
+}
+
+/// Wrapper around [`QPrinter::OutputFormat`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qprinter.html#OutputFormat-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    NativeFormat = 0,
+    PdfFormat = 1,
+}
+
+/// Wrapper around [`QPrinter::Unit`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qprinter.html#Unit-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrinterUnit {
+    Millimeter = 0,
+    Point = 1,
+    Inch = 2,
+    Pica = 3,
+    Didot = 4,
+    Cicero = 5,
+    DevicePixel = 6,
+}
+
+cpp_class!(
+    /// Wrapper around [`QPrinter`][class] class.
+    ///
+    /// Printing is done by painting on the printer with [`QPainter::begin`].
+    /// Requires the `printsupport` feature.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qprinter.html
+    #[derive(Default)]
+    pub unsafe struct QPrinter as "QPrinterHolder"
+);
+
+impl QPrinter {
+    /// Creates a printer for the default printer
+    pub fn new() -> QPrinter {
+        Default::default()
+    }
+
+    /// Refer to the Qt documentation of QPrinter::setOutputFormat
+    pub fn set_output_format(&mut self, format: OutputFormat) {
+        cpp!(unsafe [self as "QPrinterHolder *", format as "QPrinter::OutputFormat"] {
+            self->printer->setOutputFormat(format);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPrinter::outputFormat
+    pub fn output_format(&self) -> OutputFormat {
+        cpp!(unsafe [self as "const QPrinterHolder *"] -> OutputFormat as "QPrinter::OutputFormat" {
+            return self->printer->outputFormat();
+        })
+    }
+
+    /// Setting a file name ending with `.pdf` also sets the output format to
+    /// [`OutputFormat::PdfFormat`].
+    ///
+    /// Refer to the Qt documentation of QPrinter::setOutputFileName
+    pub fn set_output_file_name(&mut self, path: &str) {
+        let path = QString::from(path);
+        cpp!(unsafe [self as "QPrinterHolder *", path as "QString"] {
+            self->printer->setOutputFileName(path);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPrinter::outputFileName
+    pub fn output_file_name(&self) -> QString {
+        cpp!(unsafe [self as "const QPrinterHolder *"] -> QString as "QString" {
+            return self->printer->outputFileName();
+        })
+    }
+
+    /// Returns false if the page size is not supported by the printer.
+    ///
+    /// Refer to the Qt documentation of QPrinter::setPageSize
+    pub fn set_page_size(&mut self, size: QPageSize) -> bool {
+        cpp!(unsafe [self as "QPrinterHolder *", size as "QPageSize"] -> bool as "bool" {
+            return self->printer->setPageSize(size);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPrinter::pageLayout
+    pub fn page_size(&self) -> QPageSize {
+        cpp!(unsafe [self as "const QPrinterHolder *"] -> QPageSize as "QPageSize" {
+            return self->printer->pageLayout().pageSize();
+        })
+    }
+
+    /// Refer to the Qt documentation of QPrinter::setFullPage
+    pub fn set_full_page(&mut self, full_page: bool) {
+        cpp!(unsafe [self as "QPrinterHolder *", full_page as "bool"] {
+            self->printer->setFullPage(full_page);
+        })
+    }
+
+    /// Refer to the Qt documentation of QPrinter::fullPage
+    pub fn full_page(&self) -> bool {
+        cpp!(unsafe [self as "const QPrinterHolder *"] -> bool as "bool" {
+            return self->printer->fullPage();
+        })
+    }
+
+    /// Returns the printable area of the page.
+    ///
+    /// Refer to the Qt documentation of QPrinter::pageRect
+    pub fn page_rect(&self, unit: PrinterUnit) -> QRectF {
+        cpp!(unsafe [self as "const QPrinterHolder *", unit as "QPrinter::Unit"] -> QRectF as "QRectF" {
+            return self->printer->pageRect(unit);
+        })
+    }
+}
+
+impl QPaintDevice for QPrinter {
+    fn paint_device_ptr(&mut self) -> *mut c_void {
+        cpp!(unsafe [self as "QPrinterHolder *"] -> *mut c_void as "QPaintDevice *" {
+            return self->printer.get();
+        })
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QPrintDialog`][class] class.
+    ///
+    /// The dialog is shown with [`QPrintDialog::exec`], which does not block the event loop.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qprintdialog.html
+    QPrintDialog: QDialog
+);
+
+impl QPrintDialog {
+    /// Shows a print dialog to configure the printer, without blocking the event loop.
+    /// Returns true if the user accepted the dialog, in which case the document can be
+    /// printed on the printer.
+    ///
+    /// This is the asynchronous equivalent of `QPrintDialog::exec`.
+    pub async fn exec(parent: Option<&QWidget>, printer: &mut QPrinter) -> bool {
+        let parent = parent.map_or(std::ptr::null_mut(), |p| p.cpp_ptr());
+        let dialog = QPrintDialog::from_widget(
+            cpp!(unsafe [printer as "QPrinterHolder *", parent as "QWidget *"]
+                    -> QWidget as "QPointer<QWidget>" {
+                return new QPrintDialog(printer->printer.get(), parent);
+            }),
+        );
+        let result = dialog.exec_async().await;
+        dialog.delete_later();
+        result == DialogCode::Accepted as i32
+    }
+}
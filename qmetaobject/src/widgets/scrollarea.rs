@@ -0,0 +1,208 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QScrollArea>
+    #include <QtWidgets/QScrollBar>
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QAbstractSlider`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qabstractslider.html
+    abstract QAbstractSlider: QWidget
+);
+
+impl QAbstractSlider {
+    /// Refer to the Qt documentation of QAbstractSlider::value
+    pub fn value(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto slider = qobject_cast<QAbstractSlider *>(widget->data());
+            return slider ? slider->value() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSlider::setValue
+    pub fn set_value(&self, value: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", value as "int"] {
+            if (auto slider = qobject_cast<QAbstractSlider *>(widget->data()))
+                slider->setValue(value);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSlider::minimum
+    pub fn minimum(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto slider = qobject_cast<QAbstractSlider *>(widget->data());
+            return slider ? slider->minimum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSlider::maximum
+    pub fn maximum(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto slider = qobject_cast<QAbstractSlider *>(widget->data());
+            return slider ? slider->maximum() : 0;
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSlider::setRange
+    pub fn set_range(&self, min: i32, max: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", min as "int", max as "int"] {
+            if (auto slider = qobject_cast<QAbstractSlider *>(widget->data()))
+                slider->setRange(min, max);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractSlider::valueChanged
+    pub fn value_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QAbstractSlider::valueChanged;
+            }))
+        }
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QScrollBar`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qscrollbar.html
+    QScrollBar: QAbstractSlider
+);
+
+impl QScrollBar {
+    /// Creates a new scroll bar without parent
+    pub fn new(orientation: Orientation) -> QScrollBar {
+        QScrollBar::from_widget(
+            cpp!(unsafe [orientation as "Qt::Orientation"] -> QWidget as "QPointer<QWidget>" {
+                return new QScrollBar(orientation);
+            }),
+        )
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QScrollArea`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qscrollarea.html
+    QScrollArea: QWidget
+);
+
+impl QScrollArea {
+    /// Creates a new scroll area without parent
+    pub fn new() -> QScrollArea {
+        QScrollArea::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new QScrollArea;
+        }))
+    }
+
+    /// Sets the widget to be scrolled, which is then owned by the scroll area.
+    ///
+    /// Refer to the Qt documentation of QScrollArea::setWidget
+    pub fn set_widget(&self, child: &QWidget) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", child as "const QPointer<QWidget> *"] {
+            if (auto area = qobject_cast<QScrollArea *>(widget->data()))
+                if (*child)
+                    area->setWidget(child->data());
+        })
+    }
+
+    /// Refer to the Qt documentation of QScrollArea::setWidgetResizable
+    pub fn set_widget_resizable(&self, resizable: bool) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", resizable as "bool"] {
+            if (auto area = qobject_cast<QScrollArea *>(widget->data()))
+                area->setWidgetResizable(resizable);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractScrollArea::setHorizontalScrollBarPolicy
+    pub fn set_horizontal_scroll_bar_policy(&self, policy: ScrollBarPolicy) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", policy as "Qt::ScrollBarPolicy"] {
+            if (auto area = qobject_cast<QScrollArea *>(widget->data()))
+                area->setHorizontalScrollBarPolicy(policy);
+        })
+    }
+
+    /// Refer to the Qt documentation of QAbstractScrollArea::setVerticalScrollBarPolicy
+    pub fn set_vertical_scroll_bar_policy(&self, policy: ScrollBarPolicy) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", policy as "Qt::ScrollBarPolicy"] {
+            if (auto area = qobject_cast<QScrollArea *>(widget->data()))
+                area->setVerticalScrollBarPolicy(policy);
+        })
+    }
+
+    /// Scrolls so that the point (`x`, `y`) is visible, with at least `x_margin` and
+    /// `y_margin` pixels around it if possible.
+    ///
+    /// Refer to the Qt documentation of QScrollArea::ensureVisible
+    pub fn ensure_visible(&self, x: i32, y: i32, x_margin: i32, y_margin: i32) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", x as "int", y as "int",
+                x_margin as "int", y_margin as "int"] {
+            if (auto area = qobject_cast<QScrollArea *>(widget->data()))
+                area->ensureVisible(x, y, x_margin, y_margin);
+        })
+    }
+
+    /// Returns the horizontal scroll bar, which is owned by the scroll area.
+    ///
+    /// Refer to the Qt documentation of QAbstractScrollArea::horizontalScrollBar
+    pub fn horizontal_scroll_bar(&self) -> QScrollBar {
+        let widget: &QWidget = self;
+        QScrollBar::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+                auto area = qobject_cast<QScrollArea *>(widget->data());
+                return area ? area->horizontalScrollBar() : nullptr;
+            }),
+        )
+    }
+
+    /// Returns the vertical scroll bar, which is owned by the scroll area.
+    ///
+    /// Refer to the Qt documentation of QAbstractScrollArea::verticalScrollBar
+    pub fn vertical_scroll_bar(&self) -> QScrollBar {
+        let widget: &QWidget = self;
+        QScrollBar::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWidget as "QPointer<QWidget>" {
+                auto area = qobject_cast<QScrollArea *>(widget->data());
+                return area ? area->verticalScrollBar() : nullptr;
+            }),
+        )
+    }
+}
+
+impl Default for QScrollArea {
+    fn default() -> Self {
+        QScrollArea::new()
+    }
+}
@@ -0,0 +1,311 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use cpp::{cpp, cpp_class};
+
+use super::QWidget;
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+
+#if !(QT_VERSION >= QT_VERSION_CHECK(6, 0, 0) && QT_VERSION < QT_VERSION_CHECK(6, 2, 0))
+#  if !(_WIN32 && ! defined(_MSC_VER))
+#    define QMETAOBJECT_HAS_WEBENGINEVIEW
+    #include <QtWebEngineWidgets/QWebEngineView>
+#    if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    #include <QtWebEngineCore/QWebEnginePage>
+#    else
+    #include <QtWebEngineWidgets/QWebEnginePage>
+#    endif
+#  endif
+#endif
+
+    struct JavaScriptCallback {
+        /// Wrapped Box<dyn FnMut(&QVariant)>
+        TraitObject f;
+
+        JavaScriptCallback(TraitObject f) : f(f) {}
+        // QWebEnginePage::runJavaScript requires a copyable callback, the callback is moved
+        // like in FnBoxWrapper.
+        JavaScriptCallback(const JavaScriptCallback &o) : f(o.f) {
+            const_cast<JavaScriptCallback &>(o).f = {};
+        }
+        ~JavaScriptCallback() {
+            if (f.isValid()) {
+                rust!(JavaScriptCallback_destructor [f: *mut dyn FnMut(&QVariant) as "TraitObject"] {
+                    let _ = Box::from_raw(f);
+                });
+            }
+        }
+
+        void operator()(const QVariant &r) {
+            if (!f.isValid())
+                return;
+            auto f = this->f;
+            auto result = &r;
+            rust!(JavaScriptCallback_call [f: *mut dyn FnMut(&QVariant) as "TraitObject",
+                                           result: *const QVariant as "const QVariant *"] {
+                (*f)(&*result);
+            });
+        }
+    };
+}}
+
+#[derive(Default)]
+struct JavaScriptState {
+    result: Option<QVariant>,
+    waker: Option<Waker>,
+}
+
+/// The future returned by [`QWebEnginePage::run_javascript`].
+pub struct JavaScriptFuture(Rc<RefCell<JavaScriptState>>);
+
+impl Future for JavaScriptFuture {
+    type Output = QVariant;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<QVariant> {
+        let mut state = self.0.borrow_mut();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+cpp_class!(
+    /// A guarded, non-owning pointer to a [`QWebEnginePage`][class], owned by its view.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qwebenginepage.html
+    #[derive(Clone)]
+    pub unsafe struct QWebEnginePage as "QPointer<QObject>"
+);
+
+impl QWebEnginePage {
+    /// Returns true if the page was destroyed.
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QPointer<QObject> *"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Runs the script in the page, and returns a future resolving to its result.
+    ///
+    /// The result must be a JSON-compatible value, other values resolve to an invalid
+    /// QVariant. The future never resolves if the page is destroyed before the script ran.
+    ///
+    /// Refer to the Qt documentation of QWebEnginePage::runJavaScript
+    pub fn run_javascript(&self, script: &str) -> JavaScriptFuture {
+        let state = Rc::new(RefCell::new(JavaScriptState::default()));
+        let state2 = state.clone();
+        let f: *mut dyn FnMut(&QVariant) = Box::into_raw(Box::new(move |result: &QVariant| {
+            let mut state = state2.borrow_mut();
+            state.result = Some(result.clone());
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }));
+        let script = QString::from(script);
+        cpp!(unsafe [self as "const QPointer<QObject> *", script as "QString", f as "TraitObject"] {
+            JavaScriptCallback callback(f);
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto page = qobject_cast<QWebEnginePage *>(self->data()))
+                page->runJavaScript(script, callback);
+        #endif
+        });
+        JavaScriptFuture(state)
+    }
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QWebEngineView`][class] class.
+    ///
+    /// Requires the `webengine-widgets` feature.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qwebengineview.html
+    QWebEngineView: QWidget
+);
+
+impl QWebEngineView {
+    /// Creates a new web view without parent
+    pub fn new() -> QWebEngineView {
+        QWebEngineView::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            return new QWebEngineView;
+        #else
+            return nullptr;
+        #endif
+        }))
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::load
+    pub fn load(&self, url: QUrl) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", url as "QUrl"] {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                view->load(url);
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::setHtml
+    pub fn set_html(&self, html: &str, base_url: QUrl) {
+        let widget: &QWidget = self;
+        let html = QString::from(html);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", html as "QString", base_url as "QUrl"] {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                view->setHtml(html, base_url);
+        #endif
+        })
+    }
+
+    /// Returns the page shown by the view, which is owned by the view.
+    ///
+    /// Refer to the Qt documentation of QWebEngineView::page
+    pub fn page(&self) -> QWebEnginePage {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QWebEnginePage as "QPointer<QObject>" {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                return view->page();
+        #endif
+            return nullptr;
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::back
+    pub fn back(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                view->back();
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::forward
+    pub fn forward(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                view->forward();
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::reload
+    pub fn reload(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                view->reload();
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::zoomFactor
+    pub fn zoom_factor(&self) -> f64 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> f64 as "qreal" {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                return view->zoomFactor();
+        #endif
+            return 1.;
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::setZoomFactor
+    pub fn set_zoom_factor(&self, factor: f64) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", factor as "qreal"] {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                view->setZoomFactor(factor);
+        #endif
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::title
+    pub fn title(&self) -> QString {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QString as "QString" {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                return view->title();
+        #endif
+            return QString();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::url
+    pub fn url(&self) -> QUrl {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> QUrl as "QUrl" {
+        #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+            if (auto view = qobject_cast<QWebEngineView *>(widget->data()))
+                return view->url();
+        #endif
+            return QUrl();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::loadFinished
+    pub fn load_finished_signal() -> Signal<fn(bool)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+            #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+                return &QWebEngineView::loadFinished;
+            #else
+                return SignalInner();
+            #endif
+            }))
+        }
+    }
+
+    /// Refer to the Qt documentation of QWebEngineView::titleChanged
+    pub fn title_changed_signal() -> Signal<fn(QString)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+            #ifdef QMETAOBJECT_HAS_WEBENGINEVIEW
+                return &QWebEngineView::titleChanged;
+            #else
+                return SignalInner();
+            #endif
+            }))
+        }
+    }
+}
+
+impl Default for QWebEngineView {
+    fn default() -> Self {
+        QWebEngineView::new()
+    }
+}
@@ -0,0 +1,345 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use cpp::cpp;
+
+use super::{QAbstractButton, QDialog, QWidget};
+use crate::*;
+
+cpp! {{
+    #include <QtCore/QPointer>
+    #include <QtWidgets/QAbstractButton>
+    #include <QtWidgets/QWizard>
+
+    struct RustWizardPage : QWizardPage {
+        /// Wrapped Box<dyn Fn() -> bool>, if any.
+        TraitObject isCompleteFn = {};
+
+        bool isComplete() const override {
+            if (!isCompleteFn.isValid()) {
+                return QWizardPage::isComplete();
+            }
+            auto isCompleteFn = this->isCompleteFn;
+            return rust!(RustWizardPage_isComplete [
+                isCompleteFn: *const dyn Fn() -> bool as "TraitObject"
+            ] -> bool as "bool" {
+                (*isCompleteFn)()
+            });
+        }
+
+        void setIsComplete(TraitObject f) {
+            auto old = isCompleteFn;
+            isCompleteFn = f;
+            dropIsComplete(old);
+            Q_EMIT completeChanged();
+        }
+
+        static void dropIsComplete(TraitObject f) {
+            if (f.isValid()) {
+                rust!(RustWizardPage_dropIsComplete [f: *mut dyn Fn() -> bool as "TraitObject"] {
+                    let _ = Box::from_raw(f);
+                });
+            }
+        }
+
+        ~RustWizardPage() {
+            dropIsComplete(isCompleteFn);
+        }
+    };
+
+    struct RustWizard : QWizard {
+        /// Wrapped Box<dyn Fn(i32) -> i32>, if any.
+        TraitObject nextIdFn = {};
+
+        int nextId() const override {
+            if (!nextIdFn.isValid()) {
+                return QWizard::nextId();
+            }
+            auto nextIdFn = this->nextIdFn;
+            int current = currentId();
+            return rust!(RustWizard_nextId [
+                nextIdFn: *const dyn Fn(i32) -> i32 as "TraitObject",
+                current: i32 as "int"
+            ] -> i32 as "int" {
+                (*nextIdFn)(current)
+            });
+        }
+
+        void setNextId(TraitObject f) {
+            auto old = nextIdFn;
+            nextIdFn = f;
+            dropNextId(old);
+        }
+
+        static void dropNextId(TraitObject f) {
+            if (f.isValid()) {
+                rust!(RustWizard_dropNextId [f: *mut dyn Fn(i32) -> i32 as "TraitObject"] {
+                    let _ = Box::from_raw(f);
+                });
+            }
+        }
+
+        ~RustWizard() {
+            dropNextId(nextIdFn);
+        }
+    };
+}}
+
+widget_wrapper!(
+    /// Wrapper around [`QWizardPage`][class] class.
+    ///
+    /// The contents of the page are set with [`QWidget::set_layout`].
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qwizardpage.html
+    QWizardPage: QWidget
+);
+
+impl QWizardPage {
+    /// Creates a new wizard page without parent
+    pub fn new() -> QWizardPage {
+        QWizardPage::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new RustWizardPage;
+        }))
+    }
+
+    /// Refer to the Qt documentation of QWizardPage::setTitle
+    pub fn set_title(&self, title: &str) {
+        let widget: &QWidget = self;
+        let title = QString::from(title);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", title as "QString"] {
+            if (auto page = qobject_cast<QWizardPage *>(widget->data()))
+                page->setTitle(title);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWizardPage::setSubTitle
+    pub fn set_subtitle(&self, subtitle: &str) {
+        let widget: &QWidget = self;
+        let subtitle = QString::from(subtitle);
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", subtitle as "QString"] {
+            if (auto page = qobject_cast<QWizardPage *>(widget->data()))
+                page->setSubTitle(subtitle);
+        })
+    }
+
+    /// Returns whether the Next or Finish button is enabled for this page.
+    ///
+    /// Refer to the Qt documentation of QWizardPage::isComplete
+    pub fn is_complete(&self) -> bool {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> bool as "bool" {
+            auto page = qobject_cast<QWizardPage *>(widget->data());
+            return page && page->isComplete();
+        })
+    }
+
+    /// Sets the function overriding QWizardPage::isComplete. By default, a page is complete
+    /// when all its mandatory fields are filled.
+    ///
+    /// The function is not called again until
+    /// [`emit_complete_changed`](#method.emit_complete_changed) is called.
+    /// This replaces any previously set function.
+    pub fn set_is_complete(&self, f: impl Fn() -> bool + 'static) {
+        let widget: &QWidget = self;
+        let f: *mut dyn Fn() -> bool = Box::into_raw(Box::new(f));
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", f as "TraitObject"] {
+            if (auto page = dynamic_cast<RustWizardPage *>(widget->data()))
+                page->setIsComplete(f);
+            else
+                RustWizardPage::dropIsComplete(f);
+        })
+    }
+
+    /// Notifies the wizard that the result of [`is_complete`](#method.is_complete) may have
+    /// changed.
+    pub fn emit_complete_changed(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto page = qobject_cast<QWizardPage *>(widget->data()))
+                Q_EMIT page->completeChanged();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWizardPage::completeChanged
+    pub fn complete_changed_signal() -> Signal<fn()> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QWizardPage::completeChanged;
+            }))
+        }
+    }
+}
+
+impl Default for QWizardPage {
+    fn default() -> Self {
+        QWizardPage::new()
+    }
+}
+
+/// Wrapper around [`QWizard::WizardButton`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qwizard.html#WizardButton-enum
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WizardButton {
+    BackButton = 0,
+    NextButton = 1,
+    CommitButton = 2,
+    FinishButton = 3,
+    CancelButton = 4,
+    HelpButton = 5,
+    CustomButton1 = 6,
+    CustomButton2 = 7,
+    CustomButton3 = 8,
+}
+
+widget_wrapper!(
+    /// Wrapper around [`QWizard`][class] class.
+    ///
+    /// The wizard is a dialog, which can be run with [`QDialog::exec_async`].
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qwizard.html
+    QWizard: QDialog
+);
+
+impl QWizard {
+    /// Creates a new wizard without parent
+    pub fn new() -> QWizard {
+        QWizard::from_widget(cpp!(unsafe [] -> QWidget as "QPointer<QWidget>" {
+            return new RustWizard;
+        }))
+    }
+
+    /// Adds the page, which is then owned by the wizard, and returns its id.
+    ///
+    /// Refer to the Qt documentation of QWizard::addPage
+    pub fn add_page(&self, page: &QWizardPage) -> i32 {
+        let widget: &QWidget = self;
+        let page: &QWidget = page;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", page as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto wizard = qobject_cast<QWizard *>(widget->data());
+            auto p = qobject_cast<QWizardPage *>(page->data());
+            return wizard && p ? wizard->addPage(p) : -1;
+        })
+    }
+
+    /// Adds the page with the given id, which is then owned by the wizard.
+    ///
+    /// Refer to the Qt documentation of QWizard::setPage
+    pub fn set_page(&self, id: i32, page: &QWizardPage) {
+        let widget: &QWidget = self;
+        let page: &QWidget = page;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", id as "int", page as "const QPointer<QWidget> *"] {
+            auto wizard = qobject_cast<QWizard *>(widget->data());
+            auto p = qobject_cast<QWizardPage *>(page->data());
+            if (wizard && p)
+                wizard->setPage(id, p);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWizard::currentId
+    pub fn current_id(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto wizard = qobject_cast<QWizard *>(widget->data());
+            return wizard ? wizard->currentId() : -1;
+        })
+    }
+
+    /// Returns the id of the page shown after the current one, or -1 if it is the last page.
+    ///
+    /// Refer to the Qt documentation of QWizard::nextId
+    pub fn next_id(&self) -> i32 {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] -> i32 as "int" {
+            auto wizard = qobject_cast<QWizard *>(widget->data());
+            return wizard ? wizard->nextId() : -1;
+        })
+    }
+
+    /// Sets the function overriding QWizard::nextId, which is given the id of the current page
+    /// and returns the id of the next page, or -1 if the current page is the last one.
+    /// By default, the pages are shown in the order of their ids.
+    ///
+    /// This replaces any previously set function.
+    pub fn set_next_id(&self, f: impl Fn(i32) -> i32 + 'static) {
+        let widget: &QWidget = self;
+        let f: *mut dyn Fn(i32) -> i32 = Box::into_raw(Box::new(f));
+        cpp!(unsafe [widget as "const QPointer<QWidget> *", f as "TraitObject"] {
+            if (auto wizard = dynamic_cast<RustWizard *>(widget->data()))
+                wizard->setNextId(f);
+            else
+                RustWizard::dropNextId(f);
+        })
+    }
+
+    /// Refer to the Qt documentation of QWizard::next
+    pub fn next(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto wizard = qobject_cast<QWizard *>(widget->data()))
+                wizard->next();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWizard::back
+    pub fn back(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto wizard = qobject_cast<QWizard *>(widget->data()))
+                wizard->back();
+        })
+    }
+
+    /// Refer to the Qt documentation of QWizard::restart
+    pub fn restart(&self) {
+        let widget: &QWidget = self;
+        cpp!(unsafe [widget as "const QPointer<QWidget> *"] {
+            if (auto wizard = qobject_cast<QWizard *>(widget->data()))
+                wizard->restart();
+        })
+    }
+
+    /// Returns the given button of the wizard, which is owned by the wizard.
+    ///
+    /// Refer to the Qt documentation of QWizard::button
+    pub fn button(&self, which: WizardButton) -> QAbstractButton {
+        let widget: &QWidget = self;
+        QAbstractButton::from_widget(
+            cpp!(unsafe [widget as "const QPointer<QWidget> *", which as "QWizard::WizardButton"]
+                    -> QWidget as "QPointer<QWidget>" {
+                auto wizard = qobject_cast<QWizard *>(widget->data());
+                return wizard ? wizard->button(which) : nullptr;
+            }),
+        )
+    }
+
+    /// Refer to the Qt documentation of QWizard::currentIdChanged
+    pub fn current_id_changed_signal() -> Signal<fn(i32)> {
+        unsafe {
+            Signal::new(cpp!([] -> SignalInner as "SignalInner" {
+                return &QWizard::currentIdChanged;
+            }))
+        }
+    }
+}
+
+impl Default for QWizard {
+    fn default() -> Self {
+        QWizard::new()
+    }
+}
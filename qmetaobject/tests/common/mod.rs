@@ -19,6 +19,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 #![allow(dead_code)]
 
 use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Mutex;
 
 use qmetaobject::*;
@@ -98,17 +99,22 @@ pub fn do_test_variant(obj: QVariant, qml: &str) -> bool {
     engine.invoke_method("doTest".into(), &[]).to_bool()
 }
 
+/// Load the QML and return true if one of the warnings reported by the engine contains `log`.
 pub fn test_loading_logs(qml: &str, log: &str) -> bool {
-    let _lock = TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
-    QML_LOGS.lock().unwrap_or_else(|e| e.into_inner()).clear();
-
-    install_message_handler(Some(log_capture));
+    let _lock = lock_for_test();
 
     let qml_text = "import QtQuick 2.0\n".to_owned() + qml;
 
+    let logs = Rc::new(RefCell::new(Vec::new()));
+    let logs2 = logs.clone();
     let mut engine = QmlEngine::new();
+    engine.on_warning(move |warning| {
+        let warning = warning.to_string().to_string();
+        println!("{}", warning);
+        logs2.borrow_mut().push(warning);
+    });
     engine.load_data(qml_text.into());
 
-    let logs = QML_LOGS.lock().unwrap_or_else(|e| e.into_inner());
+    let logs = logs.borrow();
     logs.iter().any(|x| x.contains(log))
 }
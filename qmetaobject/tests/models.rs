@@ -17,6 +17,7 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 use std::cell::RefCell;
 use std::iter::FromIterator;
+use std::rc::Rc;
 
 use qmetaobject::*;
 
@@ -133,3 +134,418 @@ fn simple_model_iter() {
     let iterated_items = obj.list.borrow().iter().cloned().collect::<Vec<_>>();
     assert_eq!(original_items, iterated_items);
 }
+
+#[test]
+fn simple_model_get_mut() {
+    #[derive(QObject, Default)]
+    pub struct Foo {
+        base: qt_base_class!(trait QObject),
+        pub list: qt_property!(RefCell<SimpleListModel<X>>; CONST),
+        pub bump: qt_method!(
+            fn bump(&mut self, index: usize) -> bool {
+                match self.list.borrow_mut().get_mut(index) {
+                    Some(mut item) => {
+                        item.val += 100;
+                        true
+                    }
+                    None => false,
+                }
+            }
+        ),
+    }
+
+    #[derive(Debug, Clone, SimpleListItem, Default)]
+    pub struct X {
+        pub val: usize,
+    }
+
+    let obj = Foo {
+        list: RefCell::new(FromIterator::from_iter(vec![X { val: 1 }, X { val: 2 }])),
+        ..Default::default()
+    };
+
+    assert!(do_test(
+        obj,
+        "
+        Item {
+            Repeater{
+                id: rep
+                model: _obj.list
+                Text {
+                    text: val
+                }
+            }
+            function doTest() {
+                var delegate = rep.itemAt(1);
+                var bumped = _obj.bump(1);
+                var outOfBounds = _obj.bump(2);
+                console.log('simple_model_get_mut', rep.itemAt(0).text, rep.itemAt(1).text);
+                return bumped && !outOfBounds
+                    && rep.itemAt(0).text === '1'
+                    && rep.itemAt(1).text === '102'
+                    && rep.itemAt(1) === delegate;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn calendar_model() {
+    #[derive(Debug, Clone, SimpleListItem, Default, PartialEq)]
+    pub struct Appointment {
+        pub title: QString,
+    }
+    let appointment = |title: &str| Appointment { title: title.into() };
+
+    let mut model = CalendarModel::<Appointment>::default();
+    model.insert(QDate::from_y_m_d(2021, 3, 14), appointment("pi"));
+    model.insert(QDate::from_y_m_d(2021, 1, 1), appointment("new year"));
+    model.insert(QDate::from_y_m_d(2021, 3, 14), appointment("birthday"));
+    model.insert(QDate::from_y_m_d(2021, 2, 1), appointment("february"));
+    assert_eq!(
+        model.items_for_date(QDate::from_y_m_d(2021, 3, 14)),
+        &[appointment("pi"), appointment("birthday")]
+    );
+    assert!(model.items_for_date(QDate::from_y_m_d(2021, 3, 15)).is_empty());
+    assert_eq!(model.remove(QDate::from_y_m_d(2021, 2, 1)), vec![appointment("february")]);
+    assert!(model.remove(QDate::from_y_m_d(2021, 2, 1)).is_empty());
+    assert_eq!(model.row_count(), 3);
+    model.set_current_month(QDate::from_y_m_d(2021, 3, 1));
+
+    assert!(do_test(
+        model,
+        "
+        Item {
+            Repeater {
+                id: rep;
+                model: _obj
+                Text {
+                    text: Qt.formatDate(date, 'MM-dd') + ' ' + title
+                }
+            }
+            function doTest() {
+                console.log('calendar_model:', rep.count, _obj.current_month);
+                return rep.count === 3
+                    && rep.itemAt(0).text === '01-01 new year'
+                    && rep.itemAt(1).text === '03-14 pi'
+                    && rep.itemAt(2).text === '03-14 birthday'
+                    && _obj.current_month.getMonth() === 2;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn simple_model_move_swap_retain() {
+    #[derive(QObject, Default)]
+    pub struct Foo {
+        base: qt_base_class!(trait QObject),
+        pub list: qt_property!(RefCell<SimpleListModel<X>>; CONST),
+        pub reorder: qt_method!(
+            fn reorder(&mut self) {
+                let mut list = self.list.borrow_mut();
+                list.move_row(0, 3); // 11 12 13 10 14 15
+                list.move_row(4, 1); // 11 14 12 13 10 15
+                list.swap(5, 0); // 15 14 12 13 10 11
+                list.swap(2, 3); // 15 14 13 12 10 11
+                list.retain(|x| x.val % 2 == 0 || x.val == 13); // 14 13 12 10
+            }
+        ),
+    }
+
+    #[derive(Debug, Clone, SimpleListItem, Default)]
+    pub struct X {
+        pub val: usize,
+    }
+
+    let obj =
+        Foo { list: RefCell::new((10..16).map(|val| X { val }).collect()), ..Default::default() };
+
+    assert!(do_test(
+        obj,
+        "
+        Item {
+            Repeater{
+                id: rep
+                model: _obj.list
+                Text {
+                    text: val
+                }
+            }
+            function doTest() {
+                _obj.reorder();
+                var texts = [];
+                for (var i = 0; i < rep.count; ++i)
+                    texts.push(rep.itemAt(i).text);
+                console.log('simple_model_move_swap_retain', texts);
+                return texts.join(' ') === '14 13 12 10';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn simple_model_list_role() {
+    #[derive(Default, SimpleListItem)]
+    struct TM {
+        #[qt_list_role = "displayName"]
+        name: QString,
+        #[qt_list_role = "count"]
+        pub value: u32,
+        pub not_exposed: u32,
+    }
+    assert_eq!(TM::names(), vec![QByteArray::from("displayName"), QByteArray::from("count")]);
+
+    let model: qmetaobject::listmodel::SimpleListModel<TM> =
+        std::iter::once(TM { name: "hello".into(), value: 42, not_exposed: 1 }).collect();
+    assert!(do_test(
+        model,
+        "
+        Item {
+            Repeater {
+                id: rep;
+                model: _obj
+                Text {
+                    text: displayName + count
+                }
+            }
+            function doTest() {
+                console.log('simple_model_list_role:', rep.count, rep.itemAt(0).text);
+                return rep.count === 1 && rep.itemAt(0).text === 'hello42';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn data_changed_range_and_reset() {
+    #[derive(QObject, Default)]
+    pub struct Numbers {
+        base: qt_base_class!(trait QAbstractListModel),
+        values: Vec<i32>,
+        pub bump: qt_method!(
+            fn bump(&mut self) {
+                for v in &mut self.values[1..3] {
+                    *v += 10;
+                }
+                data_changed_range(self, 1..3, &[USER_ROLE]);
+            }
+        ),
+        pub replace: qt_method!(
+            fn replace(&mut self) {
+                self.values = vec![7, 8];
+                model_reset(self);
+            }
+        ),
+        pub reverse: qt_method!(
+            fn reverse(&mut self) {
+                layout_about_to_be_changed(self);
+                self.values.reverse();
+                layout_changed(self);
+            }
+        ),
+    }
+    impl QAbstractListModel for Numbers {
+        fn row_count(&self) -> i32 {
+            self.values.len() as i32
+        }
+        fn data(&self, index: QModelIndex, role: i32) -> QVariant {
+            match self.values.get(index.row() as usize) {
+                Some(v) if role == USER_ROLE => (*v).into(),
+                _ => QVariant::default(),
+            }
+        }
+        fn role_names(&self) -> std::collections::HashMap<i32, QByteArray> {
+            std::iter::once((USER_ROLE, "value".into())).collect()
+        }
+    }
+
+    let obj = Numbers { values: vec![1, 2, 3, 4], ..Default::default() };
+    assert!(do_test(
+        obj,
+        "
+        Item {
+            Repeater {
+                id: rep
+                model: _obj
+                Text {
+                    text: value
+                }
+            }
+            function texts() {
+                var texts = [];
+                for (var i = 0; i < rep.count; ++i)
+                    texts.push(rep.itemAt(i).text);
+                return texts.join(' ');
+            }
+            function doTest() {
+                _obj.bump();
+                var bumped = texts();
+                _obj.replace();
+                var replaced = texts();
+                _obj.reverse();
+                console.log('data_changed_range_and_reset', bumped, replaced, texts());
+                return bumped === '1 12 13 4' && replaced === '7 8' && texts() === '8 7';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn model_reset_with_guard() {
+    #[derive(QObject, Default)]
+    pub struct Numbers {
+        base: qt_base_class!(trait QAbstractListModel),
+        values: Vec<i32>,
+        pub replace: qt_method!(
+            fn replace(&mut self) {
+                let mut model = model_reset_guard(self);
+                model.values.clear();
+                model.values.extend(&[5, 6, 7]);
+            }
+        ),
+    }
+    impl QAbstractListModel for Numbers {
+        fn row_count(&self) -> i32 {
+            self.values.len() as i32
+        }
+        fn data(&self, index: QModelIndex, role: i32) -> QVariant {
+            match self.values.get(index.row() as usize) {
+                Some(v) if role == USER_ROLE => (*v).into(),
+                _ => QVariant::default(),
+            }
+        }
+        fn role_names(&self) -> std::collections::HashMap<i32, QByteArray> {
+            std::iter::once((USER_ROLE, "value".into())).collect()
+        }
+    }
+
+    let obj = Numbers { values: vec![1], ..Default::default() };
+    assert!(do_test(
+        obj,
+        "
+        Item {
+            Repeater {
+                id: rep
+                model: _obj
+                Text {
+                    text: value
+                }
+            }
+            function doTest() {
+                _obj.replace();
+                console.log('model_reset_with_guard', rep.count);
+                return rep.count === 3 && rep.itemAt(2).text === '7';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn async_paginated_model() {
+    #[derive(Default, SimpleListItem)]
+    struct Row {
+        pub n: u32,
+    }
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let requests2 = requests.clone();
+    let model = RefCell::new(AsyncPaginatedListModel::new(2, move |offset, limit| {
+        requests2.borrow_mut().push((offset, limit));
+        let rows: Vec<Row> =
+            (offset..(offset + limit).min(3)).map(|n| Row { n: n as u32 }).collect();
+        async move { rows }
+    }));
+    unsafe { QObjectPinned::new(&model).get_or_create_cpp_object() };
+
+    assert!(model.borrow().can_fetch_more());
+    model.borrow_mut().fetch_more();
+    assert!(model.borrow().is_loading);
+    assert!(!model.borrow().can_fetch_more());
+    app::process_events();
+    assert!(!model.borrow().is_loading);
+    assert_eq!(model.borrow().row_count(), 2);
+
+    model.borrow_mut().fetch_more();
+    app::process_events();
+    assert_eq!(model.borrow().row_count(), 3);
+    assert_eq!(model.borrow()[2].n, 2);
+    assert!(model.borrow().at_end());
+    assert!(!model.borrow().can_fetch_more());
+    assert_eq!(*requests.borrow(), vec![(0, 2), (2, 2)]);
+}
+
+#[test]
+fn list_model_drag_and_drop() {
+    #[derive(QObject, Default)]
+    pub struct Reorderable {
+        base: qt_base_class!(trait QAbstractListModel),
+        values: Vec<i32>,
+    }
+    impl QAbstractListModel for Reorderable {
+        fn row_count(&self) -> i32 {
+            self.values.len() as i32
+        }
+        fn data(&self, index: QModelIndex, role: i32) -> QVariant {
+            match self.values.get(index.row() as usize) {
+                Some(v) if role == USER_ROLE => (*v).into(),
+                _ => QVariant::default(),
+            }
+        }
+        fn mime_types(&self) -> Vec<QString> {
+            vec!["application/x-row".into()]
+        }
+        fn mime_data(&self, indexes: &[QModelIndex]) -> Option<QMimeData> {
+            let rows: Vec<String> = indexes.iter().map(|i| i.row().to_string()).collect();
+            let mut data = QMimeData::new();
+            data.set_data("application/x-row", QByteArray::from(rows.join(",").as_str()));
+            Some(data)
+        }
+        fn drop_mime_data(
+            &mut self,
+            data: &QMimeData,
+            action: DropAction,
+            row: i32,
+            _column: i32,
+            _parent: QModelIndex,
+        ) -> Option<bool> {
+            if action != DropAction::MoveAction || !data.has_format("application/x-row") {
+                return None;
+            }
+            let from: usize = data.data("application/x-row").to_string().parse().unwrap();
+            let value = self.values.remove(from);
+            self.values.insert(row as usize, value);
+            Some(true)
+        }
+        fn supported_drop_actions(&self) -> DropActions {
+            DropActions::CopyAction | DropActions::MoveAction
+        }
+    }
+
+    let _lock = lock_for_test();
+    let model = RefCell::new(Reorderable { values: vec![1, 2, 3], ..Default::default() });
+    unsafe { QObjectPinned::new(&model).get_or_create_cpp_object() };
+
+    let index = model.borrow().row_index(2);
+    let data = model.borrow().mime_data(&[index]).unwrap();
+    assert_eq!(data.formats(), vec![QString::from("application/x-row")]);
+    assert_eq!(
+        model.borrow_mut().drop_mime_data(
+            &data,
+            DropAction::MoveAction,
+            0,
+            0,
+            QModelIndex::default()
+        ),
+        Some(true)
+    );
+    assert_eq!(model.borrow().values, vec![3, 1, 2]);
+    assert!(model.borrow().supported_drag_actions().contains(DropActions::MoveAction));
+}
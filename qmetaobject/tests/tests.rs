@@ -15,9 +15,10 @@ NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FO
 OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ffi::CStr;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 use if_rust_version::if_rust_version;
 use qmetaobject::*;
@@ -311,6 +312,50 @@ fn simple_gadget() {
     ));
 }
 
+#[test]
+fn tuple_struct_gadget() {
+    #[derive(Default, Clone, QGadget)]
+    struct Meters(f64);
+
+    assert!(do_test_variant(
+        Meters(12.5).to_qvariant(),
+        r"
+        Item {
+            function doTest() {
+                return _obj.value === 12.5;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn gadget_with_skipped_field() {
+    #[derive(Default, Clone, QGadget)]
+    struct MixedGadget {
+        num_value: qt_property!(u32),
+        count: u32,
+        #[qt_skip]
+        cache: std::collections::HashMap<String, u32>,
+    }
+
+    let mut my_gadget = MixedGadget::default();
+    my_gadget.num_value = 5;
+    my_gadget.count = 2;
+    my_gadget.cache.insert("five".into(), 5);
+
+    assert!(do_test_variant(
+        my_gadget.to_qvariant(),
+        r"
+        Item {
+            function doTest() {
+                return _obj.num_value === 5 && _obj.cache === undefined;
+            }
+        }
+        "
+    ));
+}
+
 #[derive(QObject, Default)]
 struct ObjectWithObject {
     base: qt_base_class!(trait QObject),
@@ -349,6 +394,32 @@ fn qobject_properties() {
     ));
 }
 
+#[derive(QObject, Default)]
+struct ObjectWithSharedObject {
+    base: qt_base_class!(trait QObject),
+    shared: qt_property!(Rc<RefCell<MyObject>>; CONST),
+}
+
+#[test]
+fn qobject_rc_property() {
+    let shared = Rc::new(RefCell::new(MyObject::default()));
+    shared.borrow_mut().prop_x = 12;
+    let obj = ObjectWithSharedObject { shared: shared.clone(), ..Default::default() };
+    assert!(do_test(
+        obj,
+        r"
+        Item {
+            property int x: _obj.shared.prop_x;
+            function doTest() {
+                _obj.shared.prop_x = 21;
+                return x === 21;
+            }
+        }
+        "
+    ));
+    assert_eq!(shared.borrow().prop_x, 21);
+}
+
 #[derive(QObject, Default)]
 struct SomeObject {
     base: qt_base_class!(trait QObject),
@@ -535,6 +606,122 @@ fn setter() {
     ));
 }
 
+#[test]
+fn property_field_accessor() {
+    #[derive(Default)]
+    struct Inner {
+        value: String,
+    }
+
+    #[derive(QObject, Default)]
+    struct ObjectWithFields {
+        base: qt_base_class!(trait QObject),
+        count: u32,
+        inner: Inner,
+        prop_x: qt_property!(u32; READ self.count WRITE self.count NOTIFY prop_x_changed),
+        prop_x_changed: qt_signal!(),
+        prop_y: qt_property!(String; READ inner.value WRITE inner.value NOTIFY prop_y_changed),
+        prop_y_changed: qt_signal!(value: String),
+        set_count: qt_method!(fn(&mut self, v: u32)),
+    }
+    impl ObjectWithFields {
+        fn set_count(&mut self, v: u32) {
+            self.count = v;
+            self.prop_x_changed();
+        }
+    }
+
+    let my_obj = ObjectWithFields { count: 5, ..Default::default() };
+    assert!(do_test(
+        my_obj,
+        "
+        Item {
+            property var test: '' + _obj.prop_x + _obj.prop_y;
+            function doTest() {
+                if (test !== '5') {
+                    console.log('FAILURE #1', test);
+                    return false;
+                }
+                _obj.prop_x = 42;
+                if (test !== '42') {
+                    console.log('FAILURE #2', test);
+                    return false;
+                }
+                _obj.prop_y = 'hello';
+                if (test !== '42hello') {
+                    console.log('FAILURE #3', test);
+                    return false;
+                }
+                _obj.set_count(7);
+                if (test !== '7hello') {
+                    console.log('FAILURE #4', test);
+                    return false;
+                }
+                return true;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn property_field_read_without_write() {
+    #[derive(QObject, Default)]
+    struct ObjectWithReadField {
+        base: qt_base_class!(trait QObject),
+        count: u32,
+        prop_x: qt_property!(u32; READ self.count NOTIFY prop_x_changed),
+        prop_x_changed: qt_signal!(),
+    }
+
+    let my_obj = ObjectWithReadField { count: 5, ..Default::default() };
+    assert!(do_test(
+        my_obj,
+        "
+        Item {
+            property var test: _obj.prop_x
+            function doTest() {
+                _obj.prop_x = 42;
+                return _obj.prop_x === 42 && test === 42;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn property_arc_mutex() {
+    #[derive(QObject, Default)]
+    struct ObjectWithSharedState {
+        base: qt_base_class!(trait QObject),
+        value: qt_property!(Arc<Mutex<u32>>; NOTIFY value_changed),
+        value_changed: qt_signal!(),
+    }
+
+    let my_obj = ObjectWithSharedState::default();
+    let shared = my_obj.value.clone();
+    let thread_shared = shared.clone();
+    std::thread::spawn(move || *thread_shared.lock().unwrap() = 42).join().unwrap();
+
+    assert!(do_test(
+        my_obj,
+        "
+        Item {
+            function doTest() {
+                if (_obj.value !== 42) {
+                    console.log('FAILURE #1', _obj.value);
+                    return false;
+                }
+                _obj.value = 7;
+                return _obj.value === 7;
+            }
+        }
+        "
+    ));
+    // Writing from QML assigned the value in place, so it is still shared
+    assert_eq!(*shared.lock().unwrap(), 7);
+}
+
 #[test]
 fn connect_rust_signal() {
     #[derive(QObject, Default)]
@@ -585,124 +772,430 @@ fn connect_rust_signal() {
 }
 
 #[test]
-fn connect_cpp_signal() {
+fn emit_signal_from_shared_ref() {
     #[derive(QObject, Default)]
     struct Foo {
         base: qt_base_class!(trait QObject),
+        no_arg: qt_signal!(),
+        strings: qt_signal!(s: QString, b: QByteArray, r: String),
+        numbers: qt_signal!(d: f64, b: bool, l: i64),
+        variant: qt_signal!(v: QVariant),
     }
 
     let f = RefCell::new(Foo::default());
     let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
-    let mut result = None;
+    let result = RefCell::new(Vec::<String>::new());
+    unsafe {
+        connect(obj_ptr, f.borrow().no_arg.to_cpp_representation(&*f.borrow()), || {
+            result.borrow_mut().push("no_arg".into())
+        });
+        connect(
+            obj_ptr,
+            f.borrow().strings.to_cpp_representation(&*f.borrow()),
+            |s: &QString, b: &QByteArray, r: &String| {
+                result.borrow_mut().push(format!("{} {} {}", s, b, r))
+            },
+        );
+        connect(
+            obj_ptr,
+            f.borrow().numbers.to_cpp_representation(&*f.borrow()),
+            |d: &f64, b: &bool, l: &i64| result.borrow_mut().push(format!("{} {} {}", d, b, l)),
+        );
+        connect(obj_ptr, f.borrow().variant.to_cpp_representation(&*f.borrow()), |v: &QVariant| {
+            result.borrow_mut().push(v.to_qbytearray().to_string())
+        });
+    }
+
+    // Two shared borrows alive at the same time: emitting only needs `&self`
+    let a = f.borrow();
+    let b = f.borrow();
+    a.no_arg();
+    b.strings("qs".into(), "qba".into(), "rs".into());
+    a.numbers(1.5, true, -7);
+    b.variant(QString::from("var").into());
+    assert_eq!(*result.borrow(), vec!["no_arg", "qs qba rs", "1.5 true -7", "var"]);
+}
+
+#[test]
+fn connect_rust_signal_once() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+        my_signal: qt_signal!(xx: u32),
+    }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = RefCell::new(Vec::new());
     let con = unsafe {
-        connect(obj_ptr, <dyn QObject>::object_name_changed_signal(), |name: &QString| {
-            result = Some(name.clone());
-        })
+        connect_once(
+            obj_ptr,
+            f.borrow().my_signal.to_cpp_representation(&*f.borrow()),
+            |xx: &u32| result.borrow_mut().push(*xx),
+        )
     };
     assert!(con.is_valid());
-    (&*f.borrow() as &dyn QObject).set_object_name("YOYO".into());
-    assert_eq!(result, Some("YOYO".into()));
+    f.borrow().my_signal(1);
+    f.borrow().my_signal(2);
+    assert_eq!(*result.borrow(), vec![1]);
+    assert!(!con.is_valid());
+
+    let mut con2 = unsafe {
+        connect_once(
+            obj_ptr,
+            f.borrow().my_signal.to_cpp_representation(&*f.borrow()),
+            |xx: &u32| result.borrow_mut().push(*xx),
+        )
+    };
+    con2.disconnect();
+    f.borrow().my_signal(3);
+    assert_eq!(*result.borrow(), vec![1]);
 }
 
 #[test]
-fn with_life_time() {
+fn signal_multiplexer() {
     #[derive(QObject, Default)]
-    struct WithLT<'a> {
+    struct Foo {
         base: qt_base_class!(trait QObject),
-        _something: Option<&'a u32>,
         my_signal: qt_signal!(xx: u32, yy: String),
-        my_method: qt_method!(
-            fn my_method(&self, _x: u32) {}
-        ),
-        my_property: qt_property!(u32),
     }
 
-    #[derive(QObject, Default)]
-    struct WithWhereClose<T>
-    where
-        T: Clone + 'static,
-    {
-        #[qt_base_class = "QObject"] // FIXME
-        base: QObjectCppWrapper,
-        _something: Option<T>,
-    }
-}
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = Rc::new(RefCell::new(Vec::new()));
+    let mut multiplexer = unsafe {
+        SignalMultiplexer::new(obj_ptr, f.borrow().my_signal.to_cpp_representation(&*f.borrow()))
+    };
+    assert!(multiplexer.is_connected());
+
+    let ids: Vec<SlotId> = (0..3)
+        .map(|n| {
+            let result = result.clone();
+            multiplexer.add_slot(move |xx: &u32, yy: &String| {
+                result.borrow_mut().push(format!("{}: {} {}", n, xx, yy))
+            })
+        })
+        .collect();
+    assert_eq!(multiplexer.slot_count(), 3);
 
-#[test]
-fn qpointer() {
-    let ptr;
-    let pt2;
-    {
-        let obj = RefCell::new(MyObject::default());
-        obj.borrow_mut().prop_x = 23;
-        unsafe { QObjectPinned::new(&obj).get_or_create_cpp_object() };
-        ptr = QPointer::from(&*obj.borrow());
-        pt2 = ptr.clone();
-        assert_eq!(ptr.as_ref().map_or(898, |x| x.prop_x), 23);
-        assert_eq!(pt2.as_ref().map_or(898, |x| x.prop_x), 23);
-        assert_eq!(
-            ptr.as_pinned().map_or(989, |x| {
-                let old = x.borrow().prop_x;
-                x.borrow_mut().prop_x = 42;
-                old
-            }),
-            23
-        );
-        assert_eq!(pt2.as_ref().map_or(898, |x| x.prop_x), 42);
-    }
-    assert!(ptr.as_ref().is_none());
-    assert!(pt2.as_ref().is_none());
+    f.borrow().my_signal(12, "goo".into());
+    assert_eq!(*result.borrow(), vec!["0: 12 goo", "1: 12 goo", "2: 12 goo"]);
 
-    let ptr;
-    let pt2;
-    {
-        #[derive(Default)]
-        struct XX(QString);
-        impl SimpleListItem for XX {
-            fn get(&self, _idx: i32) -> QVariant {
-                self.0.clone().into()
-            }
-            fn names() -> Vec<QByteArray> {
-                vec![QByteArray::from("a")]
-            }
+    assert!(multiplexer.remove_slot(ids[1]));
+    assert!(!multiplexer.remove_slot(ids[1]));
+    result.borrow_mut().clear();
+    f.borrow().my_signal(18, "moo".into());
+    assert_eq!(*result.borrow(), vec!["0: 18 moo", "2: 18 moo"]);
+
+    // A slot emitting the signal again is not called recursively, the other slots are
+    let f_ptr: *const RefCell<Foo> = &f;
+    multiplexer.add_slot(move |xx: &u32, _: &String| {
+        if *xx == 30 {
+            unsafe { (*f_ptr).borrow().my_signal(31, "nested".into()) };
         }
-        let mut obj = SimpleListModel::<XX>::default();
-        obj.push(XX("foo".into()));
-        let obj = RefCell::new(obj);
-        unsafe { QObjectPinned::new(&obj).get_or_create_cpp_object() };
-        let obj_ref: &dyn QAbstractListModel = &*obj.borrow();
-        ptr = QPointer::<dyn QAbstractListModel>::from(obj_ref);
-        pt2 = ptr.clone();
-        assert_eq!(ptr.as_ref().map_or(898, |x| x.row_count()), 1);
-        assert_eq!(pt2.as_ref().map_or(898, |x| x.row_count()), 1);
-    }
-    assert!(ptr.as_ref().is_none());
-    assert!(pt2.as_ref().is_none());
-}
+    });
+    result.borrow_mut().clear();
+    f.borrow().my_signal(30, "re".into());
+    assert_eq!(*result.borrow(), vec!["0: 30 re", "2: 30 re", "0: 31 nested", "2: 31 nested"]);
 
-/* Panic test are a bad idea as the exception has to cross the C++ boundaries, and Qt is not exception safe
-#[derive(QObject, Default)]
-struct StupidObject {
-    base: qt_base_class!(trait QObject),
-    prop_x: qt_property!(u32; READ prop_x_getter CONST),
-    prop_y: qt_property!(u32; WRITE prop_y_setter),
-    method: qt_method!(fn method(&mut self) { *self = StupidObject::default(); }),
+    drop(multiplexer);
+    result.borrow_mut().clear();
+    f.borrow().my_signal(25, "foo".into());
+    assert!(result.borrow().is_empty());
 }
-impl StupidObject {
-    fn prop_x_getter(&mut self) -> u32 {
-        *self = StupidObject::default();
-        0
-    }
-    fn prop_y_setter(&mut self, _: u32) {
-        *self = StupidObject::default();
+
+#[test]
+fn property_change_notifier() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+        value: qt_property!(u32; NOTIFY value_changed),
+        value_changed: qt_signal!(),
+        constant: qt_property!(u32; CONST),
     }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = Rc::new(RefCell::new(Vec::new()));
+    let result2 = result.clone();
+    let handle = unsafe {
+        PropertyChangeNotifier::observe(obj_ptr, "value", move |v: QVariant| {
+            result2.borrow_mut().push(u32::from_qvariant(v).unwrap())
+        })
+    };
+    assert!(handle.is_some());
+    assert!(unsafe { PropertyChangeNotifier::observe(obj_ptr, "constant", |_| {}) }.is_none());
+    assert!(unsafe { PropertyChangeNotifier::observe(obj_ptr, "missing", |_| {}) }.is_none());
+
+    f.borrow_mut().value = 5;
+    f.borrow().value_changed();
+    f.borrow_mut().value = 8;
+    f.borrow().value_changed();
+    assert_eq!(*result.borrow(), vec![5, 8]);
+
+    drop(handle);
+    f.borrow_mut().value = 13;
+    f.borrow().value_changed();
+    assert_eq!(*result.borrow(), vec![5, 8]);
 }
 
 #[test]
-#[should_panic(expected = "Internal pointer changed")]
-fn panic_when_moved_method() {
-    let my_obj = StupidObject::default();
-    do_test(my_obj, "Item { x: _obj.method(); }");
+fn subscribe_property_changes_by_name() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+        value: qt_property!(u32; NOTIFY value_changed),
+        value_changed: qt_signal!(),
+        constant: qt_property!(u32; CONST),
+    }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let count = Rc::new(Cell::new(0));
+    let count2 = count.clone();
+    let mut handle = unsafe {
+        subscribe_property_changes(obj_ptr, "value", move || count2.set(count2.get() + 1))
+    };
+    assert!(handle.is_valid());
+    assert!(!unsafe { subscribe_property_changes(obj_ptr, "constant", || {}) }.is_valid());
+    assert!(!unsafe { subscribe_property_changes(obj_ptr, "missing", || {}) }.is_valid());
+
+    f.borrow().value_changed();
+    f.borrow().value_changed();
+    assert_eq!(count.get(), 2);
+
+    handle.disconnect();
+    f.borrow().value_changed();
+    assert_eq!(count.get(), 2);
+    assert_eq!(Rc::strong_count(&count), 1);
+}
+
+#[test]
+fn connect_signal_by_signature() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+        my_signal: qt_signal!(xx: u32, yy: QString),
+    }
+
+    cpp_signal! {
+        fn my_signal(u32, QString) = "2my_signal(uint,QString)";
+        fn destroyed() = "2destroyed()";
+        fn missing() = "missing()";
+    }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = Rc::new(RefCell::new(Vec::new()));
+    let result2 = result.clone();
+    let con = unsafe {
+        connect_cpp_signal(obj_ptr, my_signal(), move |xx: &u32, yy: &QString| {
+            result2.borrow_mut().push(format!("{} {}", xx, yy))
+        })
+    };
+    assert!(con.is_valid());
+    let result2 = result.clone();
+    let con = unsafe {
+        connect_cpp_signal(obj_ptr, destroyed(), move || {
+            result2.borrow_mut().push("destroyed".into())
+        })
+    };
+    assert!(con.is_valid());
+    assert!(!unsafe { connect_cpp_signal(obj_ptr, missing(), || {}) }.is_valid());
+
+    f.borrow().my_signal(12, "goo".into());
+    assert_eq!(*result.borrow(), vec!["12 goo"]);
+    drop(f);
+    assert_eq!(*result.borrow(), vec!["12 goo", "destroyed"]);
+}
+
+#[test]
+fn connect_signal_by_signature_disconnect() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+        my_signal: qt_signal!(xx: u32),
+    }
+
+    cpp_signal! {
+        fn my_signal(u32) = "2my_signal(uint)";
+    }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = Rc::new(RefCell::new(Vec::new()));
+    let result2 = result.clone();
+    let mut con = unsafe {
+        connect_cpp_signal(obj_ptr, my_signal(), move |xx: &u32| result2.borrow_mut().push(*xx))
+    };
+    assert!(con.is_valid());
+    assert_eq!(Rc::strong_count(&result), 2);
+
+    f.borrow().my_signal(1);
+    con.disconnect();
+    f.borrow().my_signal(2);
+    assert_eq!(*result.borrow(), vec![1]);
+    // the slot was dropped together with the receiver
+    assert_eq!(Rc::strong_count(&result), 1);
+    // disconnecting twice does nothing
+    con.disconnect();
+}
+
+#[test]
+fn block_signals() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+        my_signal: qt_signal!(xx: u32),
+    }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = RefCell::new(Vec::new());
+    let _con = unsafe {
+        connect(obj_ptr, f.borrow().my_signal.to_cpp_representation(&*f.borrow()), |xx: &u32| {
+            result.borrow_mut().push(*xx)
+        })
+    };
+
+    let obj = f.borrow();
+    let qobj: &dyn QObject = &*obj;
+    {
+        let _blocker = qobj.block_signals();
+        assert!(qobj.signals_blocked());
+        obj.my_signal(1);
+        {
+            let _nested = qobj.block_signals();
+            obj.my_signal(2);
+        }
+        // still blocked by the outer blocker
+        assert!(qobj.signals_blocked());
+        obj.my_signal(3);
+    }
+    assert!(!qobj.signals_blocked());
+    obj.my_signal(4);
+    assert_eq!(*result.borrow(), vec![4]);
+}
+
+#[test]
+fn connect_cpp_signal() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+    }
+
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let mut result = None;
+    let con = unsafe {
+        connect(obj_ptr, <dyn QObject>::object_name_changed_signal(), |name: &QString| {
+            result = Some(name.clone());
+        })
+    };
+    assert!(con.is_valid());
+    (&*f.borrow() as &dyn QObject).set_object_name("YOYO".into());
+    assert_eq!(result, Some("YOYO".into()));
+}
+
+#[test]
+fn with_life_time() {
+    #[derive(QObject, Default)]
+    struct WithLT<'a> {
+        base: qt_base_class!(trait QObject),
+        _something: Option<&'a u32>,
+        my_signal: qt_signal!(xx: u32, yy: String),
+        my_method: qt_method!(
+            fn my_method(&self, _x: u32) {}
+        ),
+        my_property: qt_property!(u32),
+    }
+
+    #[derive(QObject, Default)]
+    struct WithWhereClose<T>
+    where
+        T: Clone + 'static,
+    {
+        #[qt_base_class = "QObject"] // FIXME
+        base: QObjectCppWrapper,
+        _something: Option<T>,
+    }
+}
+
+#[test]
+fn qpointer() {
+    let ptr;
+    let pt2;
+    {
+        let obj = RefCell::new(MyObject::default());
+        obj.borrow_mut().prop_x = 23;
+        unsafe { QObjectPinned::new(&obj).get_or_create_cpp_object() };
+        ptr = QPointer::from(&*obj.borrow());
+        pt2 = ptr.clone();
+        assert_eq!(ptr.as_ref().map_or(898, |x| x.prop_x), 23);
+        assert_eq!(pt2.as_ref().map_or(898, |x| x.prop_x), 23);
+        assert_eq!(
+            ptr.as_pinned().map_or(989, |x| {
+                let old = x.borrow().prop_x;
+                x.borrow_mut().prop_x = 42;
+                old
+            }),
+            23
+        );
+        assert_eq!(pt2.as_ref().map_or(898, |x| x.prop_x), 42);
+    }
+    assert!(ptr.as_ref().is_none());
+    assert!(pt2.as_ref().is_none());
+
+    let ptr;
+    let pt2;
+    {
+        #[derive(Default)]
+        struct XX(QString);
+        impl SimpleListItem for XX {
+            fn get(&self, _idx: i32) -> QVariant {
+                self.0.clone().into()
+            }
+            fn names() -> Vec<QByteArray> {
+                vec![QByteArray::from("a")]
+            }
+        }
+        let mut obj = SimpleListModel::<XX>::default();
+        obj.push(XX("foo".into()));
+        let obj = RefCell::new(obj);
+        unsafe { QObjectPinned::new(&obj).get_or_create_cpp_object() };
+        let obj_ref: &dyn QAbstractListModel = &*obj.borrow();
+        ptr = QPointer::<dyn QAbstractListModel>::from(obj_ref);
+        pt2 = ptr.clone();
+        assert_eq!(ptr.as_ref().map_or(898, |x| x.row_count()), 1);
+        assert_eq!(pt2.as_ref().map_or(898, |x| x.row_count()), 1);
+    }
+    assert!(ptr.as_ref().is_none());
+    assert!(pt2.as_ref().is_none());
+}
+
+/* Panic test are a bad idea as the exception has to cross the C++ boundaries, and Qt is not exception safe
+#[derive(QObject, Default)]
+struct StupidObject {
+    base: qt_base_class!(trait QObject),
+    prop_x: qt_property!(u32; READ prop_x_getter CONST),
+    prop_y: qt_property!(u32; WRITE prop_y_setter),
+    method: qt_method!(fn method(&mut self) { *self = StupidObject::default(); }),
+}
+impl StupidObject {
+    fn prop_x_getter(&mut self) -> u32 {
+        *self = StupidObject::default();
+        0
+    }
+    fn prop_y_setter(&mut self, _: u32) {
+        *self = StupidObject::default();
+    }
+}
+
+#[test]
+#[should_panic(expected = "Internal pointer changed")]
+fn panic_when_moved_method() {
+    let my_obj = StupidObject::default();
+    do_test(my_obj, "Item { x: _obj.method(); }");
 }
 #[test]
 #[should_panic(expected = "Internal pointer changed")]
@@ -979,3 +1472,2419 @@ fn test_qvariant_qimage_qpixmap() {
     img3.set_pixel_color(8, 8, QColor::from_name("black"));
     assert!(img2 != img3);
 }
+
+#[test]
+fn qlibrary_missing() {
+    let mut lib = QLibrary::new("this_library_does_not_exist_qmetaobject");
+    assert!(!lib.load());
+    assert!(!lib.is_loaded());
+    assert!(lib.resolve("some_symbol").is_null());
+    assert!(!lib.error_string().to_string().is_empty());
+}
+
+#[test]
+fn qpluginloader_missing() {
+    let mut loader = QPluginLoader::new("this_plugin_does_not_exist_qmetaobject");
+    assert!(!loader.load());
+    assert!(loader.instance().is_none());
+    assert!(loader.instance_interface("org.qt-project.Qt.QQmlExtensionInterface/1.0").is_none());
+    assert!(loader.meta_data().is_empty());
+}
+
+#[test]
+#[cfg(qt_5_12)]
+fn cbor_roundtrip() {
+    let mut map = QCborMap::default();
+    map.insert("answer", 42i64);
+    map.insert("name", "qt");
+    let mut array = QCborArray::default();
+    array.push(true);
+    array.push(QCborValue::null());
+    map.insert("list", array);
+
+    let bytes = QCborValue::from(map.clone()).to_cbor();
+    let decoded = QCborValue::from_cbor(bytes.to_slice()).unwrap().to_map().unwrap();
+    assert!(decoded == map);
+    assert_eq!(decoded.value("answer").to_integer(), Some(42));
+    assert_eq!(decoded.value("name").to_string().unwrap().to_string(), "qt");
+    let list = decoded.value("list").to_array().unwrap();
+    assert_eq!(list.at(0).to_bool(), Some(true));
+    assert!(list.at(1).is_null());
+    assert!(decoded.value("missing").is_undefined());
+    assert_eq!(decoded.to_qvariant_map().len(), 3);
+    assert!(QCborValue::from_cbor(&[0xff]).is_none());
+
+    let mut writer = QCborStreamWriter::new();
+    writer.append_i64(1);
+    writer.start_array(None);
+    writer.append_string("a");
+    writer.append_f64(2.5);
+    assert!(writer.end_array());
+    let mut reader = QCborStreamReader::new(writer.data().to_slice());
+    assert_eq!(reader.read_value().unwrap().to_integer(), Some(1));
+    let array = reader.read_value().unwrap().to_array().unwrap();
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.at(1).to_double(), Some(2.5));
+    assert!(reader.read_value().is_none());
+}
+
+#[test]
+fn text_stream() {
+    let mut s = QString::from("first line\nsecond line\n");
+    {
+        let mut stream = QTextStream::from_string(&mut s);
+        assert_eq!(stream.read_line().unwrap().to_string(), "first line");
+        assert_eq!(stream.read_all().to_string(), "second line\n");
+        assert!(stream.at_end());
+        assert!(stream.read_line().is_none());
+    }
+
+    let mut out = QTextStream::new();
+    out.write("Hello ");
+    out.write("World");
+    assert_eq!(out.to_string_output().to_string(), "Hello World");
+
+    let mut bytes = QByteArray::from("caf\u{e9}");
+    {
+        let mut stream = QTextStream::from_byte_array(&mut bytes);
+        assert!(stream.set_codec("UTF-8"));
+        assert!(!stream.set_codec("not-an-encoding"));
+        assert_eq!(stream.read_all().to_string(), "caf\u{e9}");
+    }
+}
+
+#[test]
+fn qbuffer_io() {
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    let mut buffer = QBuffer::new();
+    assert!(buffer.open(OpenMode::ReadWrite));
+    buffer.write_all(b"Hello World").unwrap();
+    assert_eq!(buffer.data().to_slice(), b"Hello World");
+
+    assert_eq!(buffer.seek(SeekFrom::Start(6)).unwrap(), 6);
+    let mut s = String::new();
+    buffer.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "World");
+
+    assert_eq!(buffer.seek(SeekFrom::End(-5)).unwrap(), 6);
+    buffer.write_all(b"Qt").unwrap();
+    assert_eq!(buffer.seek(SeekFrom::Current(-8)).unwrap(), 0);
+    let mut out = Vec::new();
+    buffer.read_to_end(&mut out).unwrap();
+    assert_eq!(out, b"Hello Qtrld");
+    assert!(buffer.seek(SeekFrom::Current(-100)).is_err());
+    buffer.close();
+    assert!(!buffer.is_open());
+}
+
+#[test]
+#[cfg(feature = "network")]
+fn network_connect_to_host() {
+    use qmetaobject::network::*;
+
+    let _lock = lock_for_test();
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let open_port = listener.local_addr().unwrap().port();
+    let closed_port = {
+        let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        l.local_addr().unwrap().port()
+    };
+
+    let engine = Rc::new(QmlEngine::new());
+    let results = Rc::new(RefCell::new(Vec::new()));
+    {
+        let engine2 = engine.clone();
+        let results2 = results.clone();
+        future::execute_async(async move {
+            let mut socket = QTcpSocket::new();
+            results2.borrow_mut().push(connect_to_host(&mut socket, "127.0.0.1", open_port).await);
+            let mut socket = QTcpSocket::new();
+            results2
+                .borrow_mut()
+                .push(connect_to_host(&mut socket, "127.0.0.1", closed_port).await);
+            engine2.quit();
+        });
+    }
+    engine.exec();
+
+    assert_eq!(*results.borrow(), vec![Ok(()), Err(SocketError::ConnectionRefusedError)]);
+}
+
+#[test]
+#[cfg(feature = "network")]
+fn local_socket_and_server() {
+    use qmetaobject::network::*;
+
+    let _lock = lock_for_test();
+    let name = format!("qmetaobject-test-{}", std::process::id());
+    let engine = Rc::new(QmlEngine::new());
+    let received = Rc::new(RefCell::new(QByteArray::default()));
+    {
+        let engine2 = engine.clone();
+        let received2 = received.clone();
+        future::execute_async(async move {
+            let mut server = QLocalServer::new();
+            QLocalServer::remove_server(&name);
+            assert!(server.listen(&name));
+            let new_connection = unsafe {
+                future::wait_on_signal(server.cpp_ptr(), QLocalServer::new_connection_signal())
+            };
+
+            let mut client = QLocalSocket::new();
+            let connected = unsafe {
+                future::wait_on_signal(client.cpp_ptr(), QLocalSocket::connected_signal())
+            };
+            client.connect_to_server(&name);
+            if !client.is_connected() {
+                connected.await;
+            }
+            new_connection.await;
+            let mut peer = server.next_pending_connection().unwrap();
+            assert!(server.next_pending_connection().is_none());
+
+            let ready_read = unsafe {
+                future::wait_on_signal(peer.cpp_ptr(), QLocalSocket::ready_read_signal())
+            };
+            assert_eq!(client.write(b"ping"), 4);
+            client.flush();
+            ready_read.await;
+            *received2.borrow_mut() = peer.read_all();
+            engine2.quit();
+        });
+    }
+    engine.exec();
+
+    assert_eq!(received.borrow().to_slice(), b"ping");
+}
+
+#[test]
+fn event_loop() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let event_loop = Rc::new(QEventLoop::new());
+    assert!(!event_loop.is_running());
+    let event_loop2 = event_loop.clone();
+    single_shot(std::time::Duration::from_millis(0), move || {
+        assert!(event_loop2.is_running());
+        event_loop2.exit(42);
+    });
+    assert_eq!(event_loop.exec(), 42);
+
+    let called = Rc::new(std::cell::Cell::new(false));
+    let called2 = called.clone();
+    single_shot(std::time::Duration::from_millis(0), move || called2.set(true));
+    while !called.get() {
+        event_loop.process_events(ProcessEventsFlags::AllEvents);
+    }
+}
+
+#[test]
+fn app_process_events() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let called = Rc::new(std::cell::Cell::new(0));
+    let called2 = called.clone();
+    queued_callback(move |()| called2.set(called2.get() + 1))(());
+    assert_eq!(called.get(), 0);
+    app::process_events();
+    assert_eq!(called.get(), 1);
+    app::process_events_with_flags(ProcessEventsFlags::ExcludeUserInputEvents);
+    assert_eq!(called.get(), 1);
+}
+
+#[test]
+fn time_line() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut time_line = QTimeLine::new(50);
+    time_line.set_frame_range(0, 10);
+    time_line.set_update_interval(5);
+    time_line.set_easing_curve(EasingCurveType::Linear.into());
+    assert_eq!(time_line.state(), TimeLineState::NotRunning);
+
+    let event_loop = Rc::new(QEventLoop::new());
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let values2 = values.clone();
+    let _value_changed = unsafe {
+        connect(time_line.cpp_ptr(), QTimeLine::value_changed_signal(), move |v: &qreal| {
+            values2.borrow_mut().push(*v)
+        })
+    };
+    let event_loop2 = event_loop.clone();
+    let _finished = unsafe {
+        connect(time_line.cpp_ptr(), QTimeLine::finished_signal(), move || event_loop2.quit())
+    };
+    time_line.start();
+    assert_eq!(time_line.state(), TimeLineState::Running);
+    event_loop.exec();
+
+    assert_eq!(time_line.state(), TimeLineState::NotRunning);
+    assert_eq!(time_line.current_value(), 1.);
+    assert_eq!(time_line.current_frame(), 10);
+    assert!(!values.borrow().is_empty());
+    assert_eq!(*values.borrow().last().unwrap(), 1.);
+}
+
+#[test]
+fn variant_animation_interpolator() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut animation = QVariantAnimation::new();
+    animation.set_start_value(QString::from("").into());
+    animation.set_end_value(QString::from("abcdef").into());
+    animation.set_duration(30);
+    animation.set_interpolator(|from, to, progress| {
+        let from = QString::from_qvariant(from).unwrap_or_default().to_string();
+        let to = QString::from_qvariant(to).unwrap_or_default().to_string();
+        let len = from.len() + ((to.len() - from.len()) as f64 * progress).round() as usize;
+        QString::from(&to[..len]).into()
+    });
+
+    let event_loop = Rc::new(QEventLoop::new());
+    let values = Rc::new(RefCell::new(Vec::<String>::new()));
+    let values2 = values.clone();
+    let _value_changed = unsafe {
+        connect(
+            animation.cpp_ptr(),
+            QVariantAnimation::value_changed_signal(),
+            move |v: &QVariant| values2.borrow_mut().push(v.to_qbytearray().to_string()),
+        )
+    };
+    let event_loop2 = event_loop.clone();
+    let _finished = unsafe {
+        connect(animation.cpp_ptr(), QAbstractAnimation::finished_signal(), move || {
+            event_loop2.quit()
+        })
+    };
+    animation.start();
+    event_loop.exec();
+
+    assert_eq!(animation.current_value().to_qbytearray().to_string(), "abcdef");
+    assert_eq!(values.borrow().last().map(String::as_str), Some("abcdef"));
+}
+
+#[test]
+fn animation_groups() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let make_animation = |duration| {
+        let mut animation = QVariantAnimation::new();
+        animation.set_start_value(0.into());
+        animation.set_end_value(10.into());
+        animation.set_duration(duration);
+        animation
+    };
+
+    let mut parallel = QParallelAnimationGroup::new();
+    let first = make_animation(20);
+    let second = make_animation(30);
+    parallel.add_animation(&first);
+    parallel.add_animation(&second);
+    assert_eq!(parallel.animation_count(), 2);
+    assert_eq!(parallel.total_duration(), 30);
+
+    let mut sequential = QSequentialAnimationGroup::new();
+    let third = make_animation(10);
+    sequential.add_animation(&parallel);
+    sequential.add_pause(5);
+    sequential.add_animation(&third);
+    assert_eq!(sequential.animation_count(), 3);
+    assert_eq!(sequential.total_duration(), 45);
+    assert!(sequential.current_animation().is_some());
+    assert!(sequential.animation_at(3).is_none());
+
+    // The groups own their animations, dropping the Rust objects must not delete them
+    drop(first);
+    drop(parallel);
+    assert_eq!(sequential.animation_count(), 3);
+
+    let event_loop = Rc::new(QEventLoop::new());
+    let event_loop2 = event_loop.clone();
+    let _finished = unsafe {
+        connect(sequential.cpp_ptr(), QAbstractAnimation::finished_signal(), move || {
+            event_loop2.quit()
+        })
+    };
+    sequential.start();
+    assert_eq!(sequential.state(), AnimationState::Running);
+    event_loop.exec();
+    assert_eq!(sequential.state(), AnimationState::Stopped);
+    assert_eq!(i32::from_qvariant(second.current_value()), Some(10));
+    assert_eq!(i32::from_qvariant(third.current_value()), Some(10));
+
+    sequential.remove_animation(&third);
+    assert_eq!(sequential.animation_count(), 2);
+}
+
+#[test]
+fn standard_item_model() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut model = QStandardItemModel::new(1, 2);
+    assert_eq!(model.row_count(), 1);
+    assert_eq!(model.column_count(), 2);
+    assert!(model.item(0, 0).is_none());
+
+    let mut item = QStandardItem::new();
+    item.set_text("foo".into());
+    item.set_data(42.into(), USER_ROLE);
+    model.set_item(0, 1, item);
+    model.append_row(vec!["bar".into(), "baz".into()]);
+    model.append_row(vec!["foo".into()]);
+    assert_eq!(model.row_count(), 3);
+
+    let item = model.item(0, 1).unwrap();
+    assert_eq!(item.text(), QString::from("foo"));
+    assert_eq!(i32::from_qvariant(item.data(USER_ROLE)), Some(42));
+    item.set_text("qux".into());
+    assert_eq!(model.item(0, 1).unwrap().text(), QString::from("qux"));
+    assert_eq!(model.item(1, 1).unwrap().text(), QString::from("baz"));
+
+    let found = model.find_items("foo");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].row(), 2);
+    assert_eq!(found[0].column(), 0);
+    assert!(model.find_items("qux").is_empty()); // only the first column is searched
+}
+
+#[test]
+fn item_selection_model() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut model = QStandardItemModel::new(0, 1);
+    model.append_row(vec!["a".into()]);
+    model.append_row(vec!["b".into()]);
+    model.append_row(vec!["c".into()]);
+    let mut selection_model = unsafe { QItemSelectionModel::from_model_ptr(model.cpp_ptr()) };
+    assert!(!selection_model.has_selection());
+
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let changes2 = changes.clone();
+    let _con = unsafe {
+        connect(
+            selection_model.cpp_ptr(),
+            QItemSelectionModel::selection_changed_signal(),
+            move |selected: &QItemSelection, deselected: &QItemSelection| {
+                changes2.borrow_mut().push((selected.len(), deselected.len()))
+            },
+        )
+    };
+
+    let first = model.item(0, 0).unwrap().index();
+    let last = model.item(2, 0).unwrap().index();
+    selection_model.select(&first, SelectionCommand::Select);
+    selection_model.set_current_index(&last, SelectionCommand::SelectCurrent);
+    assert!(selection_model.is_selected(&first));
+    assert!(selection_model.is_selected(&last));
+    assert!(selection_model.current_index() == last);
+    assert_eq!(selection_model.selected_indexes().len(), 2);
+
+    selection_model.select(&first, SelectionCommand::Deselect);
+    let selection = selection_model.selection();
+    assert!(!selection.contains(&first));
+    assert!(selection.contains(&last));
+    assert_eq!(&*changes.borrow(), &[(1, 0), (1, 0), (0, 1)]);
+
+    selection_model.clear_selection();
+    assert!(!selection_model.has_selection());
+}
+
+#[test]
+fn table_view_header() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut model = QStandardItemModel::new(0, 3);
+    model.append_row(vec!["a".into(), "b".into(), "c".into()]);
+    let view = QTableView::new();
+    unsafe { view.set_model_ptr(model.cpp_ptr()) };
+    view.resize(400, 300);
+
+    let header = view.horizontal_header();
+    assert_eq!(header.count(), 3);
+    header.set_default_section_size(50);
+    header.resize_section(1, 80);
+    assert_eq!(header.section_size(0), 50);
+    assert_eq!(header.section_size(1), 80);
+    assert_eq!(header.logical_index_at(60), 1);
+    header.set_section_resize_mode(2, ResizeMode::Fixed);
+    assert_eq!(header.section_resize_mode(2), ResizeMode::Fixed);
+    header.hide_section(0);
+    assert!(header.is_section_hidden(0));
+    assert_eq!(header.logical_index_at(10), 1);
+    header.show_section(0);
+    assert!(!header.is_section_hidden(0));
+
+    // The header is owned by the view
+    drop(header);
+    assert_eq!(view.horizontal_header().count(), 3);
+    drop(view);
+}
+
+#[test]
+fn data_widget_mapper() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut model = QStandardItemModel::new(0, 1);
+    model.append_row(vec!["first".into()]);
+    model.append_row(vec!["second".into()]);
+    let widget = QTableView::new();
+
+    let mut mapper = QDataWidgetMapper::new();
+    unsafe { mapper.set_model_ptr(model.cpp_ptr()) };
+    mapper.set_submit_policy(SubmitPolicy::ManualSubmit);
+    mapper.add_mapping_with_property(&widget, 0, "windowTitle");
+    mapper.to_first();
+    assert_eq!(widget.window_title().to_string(), "first");
+    mapper.to_next();
+    assert_eq!(mapper.current_index(), 1);
+    assert_eq!(widget.window_title().to_string(), "second");
+
+    widget.set_window_title("edited".into());
+    mapper.revert();
+    assert_eq!(widget.window_title().to_string(), "second");
+    widget.set_window_title("edited".into());
+    assert!(mapper.submit());
+    assert_eq!(model.item(1, 0).unwrap().text().to_string(), "edited");
+    mapper.to_previous();
+    assert_eq!(widget.window_title().to_string(), "first");
+}
+
+#[test]
+fn completer() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut model = QStandardItemModel::new(0, 1);
+    for word in &["apple", "Apricot", "banana", "pineapple"] {
+        model.append_row(vec![(*word).into()]);
+    }
+    let mut completer = unsafe { QCompleter::from_model_ptr(model.cpp_ptr()) };
+    completer.set_case_sensitivity(CaseSensitivity::CaseInsensitive);
+    completer.set_completion_prefix("ap");
+    assert_eq!(completer.completion_prefix(), QString::from("ap"));
+    assert_eq!(completer.completion_count(), 2);
+    assert_eq!(completer.current_completion(), QString::from("apple"));
+
+    completer.set_case_sensitivity(CaseSensitivity::CaseSensitive);
+    completer.set_completion_prefix("Ap");
+    assert_eq!(completer.completion_count(), 1);
+    assert_eq!(completer.current_completion(), QString::from("Apricot"));
+
+    completer.set_filter_mode(MatchFlags::MatchContains);
+    completer.set_completion_prefix("apple");
+    assert_eq!(completer.completion_count(), 2);
+    assert!(completer.set_current_row(1));
+    assert_eq!(completer.current_completion(), QString::from("pineapple"));
+
+    assert_ne!(completer.to_qvariant().user_type(), 0);
+}
+
+#[test]
+fn meta_object_by_type_name() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    assert!(meta_object_for_type_name("NoSuchType").is_none());
+    let meta = meta_object_for_type_name("QObject*").expect("QObject* is a builtin meta type");
+    // QObject has no Q_INVOKABLE constructor
+    assert!(meta_object_new_instance(meta, &[]).is_none());
+    assert!(meta_object_new_instance(meta, &[QVariant::from(1)]).is_none());
+}
+
+#[test]
+fn meta_object_reflection() {
+    #[derive(QObject, Default)]
+    struct Reflected {
+        base: qt_base_class!(trait QObject),
+        prop_x: qt_property!(u32),
+        prop_y: qt_property!(QString),
+        my_signal: qt_signal!(),
+    }
+
+    let obj = Reflected::default();
+    let meta = get_meta_object(&obj);
+    assert_eq!(meta.class_name(), "Reflected");
+    let sup = meta.super_class().expect("Reflected derives from QObject");
+    assert_eq!(sup.class_name(), "QObject");
+    assert!(sup.super_class().is_none());
+    assert!(meta.inherits(sup));
+    assert!(meta.inherits(meta));
+    assert!(!sup.inherits(meta));
+    // objectName from QObject
+    assert_eq!(meta.property_count(), sup.property_count() + 2);
+    assert_eq!(meta.method_count(), sup.method_count() + 1);
+}
+
+#[test]
+fn downcast_qobject() {
+    #[derive(QObject, Default)]
+    struct First {
+        base: qt_base_class!(trait QObject),
+        value: u32,
+    }
+
+    #[derive(QObject, Default)]
+    struct Second {
+        base: qt_base_class!(trait QObject),
+    }
+
+    let mut first = First { value: 42, ..Default::default() };
+    let obj: &dyn QObject = &first;
+    assert_eq!(downcast_ref::<First>(obj).map(|f| f.value), Some(42));
+    assert!(downcast_ref::<Second>(obj).is_none());
+    assert_eq!(QMetaObject::of::<First>().class_name(), "First");
+
+    let obj: &mut dyn QObject = &mut first;
+    downcast_mut::<First>(obj).unwrap().value = 12;
+    assert!(downcast_mut::<Second>(obj).is_none());
+    assert_eq!(first.value, 12);
+}
+
+#[derive(QEnum)]
+#[repr(u32)]
+enum RelatedShape {
+    Circle = 3,
+    Square,
+}
+
+#[test]
+fn qt_enums_on_class() {
+    #[derive(QObject, Default)]
+    #[qt_enums(RelatedShape)]
+    struct Canvas {
+        base: qt_base_class!(trait QObject),
+    }
+
+    qml_register_type::<Canvas>(
+        CStr::from_bytes_with_nul(b"TestRelatedEnums\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"Canvas\0").unwrap(),
+    );
+    let obj = Canvas::default();
+    assert!(do_test(
+        obj,
+        "
+        import TestRelatedEnums 1.0
+        Item {
+            function doTest() {
+                return Canvas.Circle === 3 && Canvas.Square === 4;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn lazy_qobject() {
+    #[derive(QObject, Default)]
+    struct Heavy {
+        base: qt_base_class!(trait QObject),
+        value: qt_property!(u32),
+    }
+
+    let _lock = lock_for_test();
+    let constructed = Rc::new(Cell::new(0));
+    let counter = constructed.clone();
+    let lazy = LazyQObject::new(move || {
+        counter.set(counter.get() + 1);
+        Heavy { value: 42, ..Default::default() }
+    });
+    assert!(!lazy.is_initialized());
+    assert_eq!(constructed.get(), 0);
+
+    let mut engine = QmlEngine::new();
+    engine.set_object_property("_obj".into(), lazy.as_pinned());
+    assert!(lazy.is_initialized());
+    assert!(!lazy.as_pinned().borrow().get_cpp_object().is_null());
+    engine.load_data(
+        "import QtQuick 2.0
+        Item {
+            function doTest() { return _obj.value === 42; }
+        }"
+        .into(),
+    );
+    assert!(engine.invoke_method("doTest".into(), &[]).to_bool());
+    assert_eq!(constructed.get(), 1);
+}
+
+#[test]
+fn qml_engine_builder() {
+    #[derive(QObject, Default)]
+    struct Backend {
+        base: qt_base_class!(trait QObject),
+        value: qt_property!(u32),
+    }
+
+    let _lock = lock_for_test();
+    let first = QObjectBox::new(Backend { value: 12, ..Default::default() });
+    let second = QObjectBox::new(Backend { value: 30, ..Default::default() });
+    let mut engine = QmlEngineBuilder::new()
+        .context_object("first", first.pinned())
+        .context_object("second", second.pinned())
+        .context_property("offset", QVariant::from(100))
+        .build();
+    engine.load_data(
+        "import QtQuick 2.0
+        Item {
+            function doTest() { return first.value + second.value + offset; }
+        }"
+        .into(),
+    );
+    assert_eq!(engine.invoke_method("doTest".into(), &[]).to_qbytearray().to_string(), "142");
+}
+
+#[test]
+fn qml_engine_context_properties() {
+    let _lock = lock_for_test();
+    let mut engine = QmlEngine::new();
+    assert!(engine.context_object_names().is_empty());
+    engine.set_property("answer".into(), 42.into());
+    engine.set_property("name".into(), QString::from("foo").into());
+    engine.set_property("answer".into(), 43.into());
+    assert_eq!(engine.context_object_names(), vec!["answer".to_string(), "name".to_string()]);
+    assert!(engine.get_context_property("answer") == 43.into());
+    assert!(engine.get_context_property("missing") == QVariant::default());
+}
+
+#[test]
+fn qml_engine_on_warning() {
+    let _lock = lock_for_test();
+    let warnings = Rc::new(RefCell::new(Vec::new()));
+    let warnings2 = warnings.clone();
+    let mut engine = QmlEngine::new();
+    engine.on_warning(move |e: &QQmlError| {
+        warnings2.borrow_mut().push((e.description().to_string(), e.line()))
+    });
+    engine.load_data(
+        "import QtQuick 2.0
+        Item {
+            property int value: no_such_symbol
+        }"
+        .into(),
+    );
+    let warnings = warnings.borrow();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].0.contains("no_such_symbol"), "{}", warnings[0].0);
+    assert_eq!(warnings[0].1, 3);
+}
+
+#[test]
+fn qml_incubator() {
+    let _lock = lock_for_test();
+    let engine = QmlEngine::new();
+    let mut component = QmlComponent::new(&engine);
+    component.set_data("import QtQuick 2.0\nItem {}".into());
+
+    let initial = Rc::new(Cell::new(std::ptr::null_mut()));
+    let initial2 = initial.clone();
+    let mut incubator = QQmlIncubator::new(IncubationMode::Synchronous);
+    incubator.set_initial_state(move |obj| initial2.set(obj));
+    component.create_async(&mut incubator);
+    assert_eq!(incubator.status(), IncubationStatus::Ready);
+    let obj = incubator.object().unwrap();
+    assert_eq!(initial.get(), obj);
+    assert!(incubator.errors().is_empty());
+
+    let mut invalid = QmlComponent::new(&engine);
+    invalid.set_data("import QtQuick 2.0\nItem { NoSuchType {} }".into());
+    let result = Rc::new(RefCell::new(None));
+    let result2 = result.clone();
+    let mut empty = QmlComponent::new(&engine);
+    let errors =
+        |e: Vec<QQmlError>| e.iter().map(|e| e.to_string().to_string()).collect::<Vec<_>>();
+    future::execute_async(async move {
+        let r = create_component_async(&mut component).await;
+        let e = create_component_async(&mut invalid).await;
+        let n = create_component_async(&mut empty).await;
+        *result2.borrow_mut() =
+            Some((r.map(|o| !o.is_null()), e.map_err(errors), n.map_err(errors)));
+    });
+    // Without an incubation controller, the engine completes the incubation immediately.
+    let result = result.borrow();
+    let (r, e, n) = result.as_ref().unwrap();
+    assert_eq!(r.as_ref().ok(), Some(&true));
+    let e = e.as_ref().unwrap_err();
+    assert!(e.iter().any(|e| e.contains("NoSuchType")), "{:?}", e);
+    assert_eq!(n.as_ref().unwrap_err().len(), 1);
+}
+
+#[test]
+#[cfg(feature = "object_registry")]
+fn object_registry_lookup() {
+    #[derive(QObject, Default)]
+    struct Registered {
+        base: qt_base_class!(trait QObject),
+        value: u32,
+    }
+    #[derive(QObject, Default)]
+    struct Other {
+        base: qt_base_class!(trait QObject),
+    }
+
+    let _lock = lock_for_test();
+    let obj = Rc::new(RefCell::new(Registered { value: 42, ..Default::default() }));
+    let ptr = ObjectRegistry::register(&obj);
+    assert!(!ptr.is_null());
+    let found = ObjectRegistry::lookup::<Registered>(ptr).unwrap();
+    assert!(Rc::ptr_eq(&found, &obj));
+    assert_eq!(found.borrow().value, 42);
+    assert!(ObjectRegistry::lookup::<Other>(ptr).is_none());
+    drop(found);
+    drop(obj);
+    assert!(ObjectRegistry::lookup::<Registered>(ptr).is_none());
+}
+
+#[test]
+fn qobject_property_bridge() {
+    let _lock = lock_for_test();
+    let engine = QmlEngine::new();
+    let mut component = QmlComponent::new(&engine);
+    component.set_data("import QtQuick 2.0\nItem { property string hint: 'foo' }".into());
+    let obj = component.create();
+    assert!(!obj.is_null());
+    unsafe {
+        assert!(qobject_property(obj, "hint") == QString::from("foo").into());
+        assert!(qobject_set_property(obj, "hint", QString::from("bar").into()));
+        assert!(qobject_property(obj, "hint") == QString::from("bar").into());
+        assert!(qobject_property(obj, "no_such_property") == QVariant::default());
+        assert!(!qobject_set_property(obj, "dynamic", 42.into()));
+        assert!(qobject_property(obj, "dynamic") == 42.into());
+    }
+}
+
+#[test]
+#[cfg(qt_5_14)]
+fn attached_property() {
+    #[derive(QObject, Default)]
+    struct KeysItem {
+        base: qt_base_class!(trait QQuickItem),
+        keys_enabled: qt_method!(
+            fn keys_enabled(&self) -> QVariant {
+                get_attached_property::<bool, _>(self, "QQuickKeysAttached", "enabled")
+                    .map_or_else(QVariant::default, QVariant::from)
+            }
+        ),
+    }
+    impl QQuickItem for KeysItem {}
+
+    qml_register_type::<KeysItem>(
+        CStr::from_bytes_with_nul(b"TestAttached\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"KeysItem\0").unwrap(),
+    );
+    let obj = MyObject::default(); // not used but needed for do_test
+    assert!(do_test(
+        obj,
+        r"
+        import TestAttached 1.0
+
+        Item {
+            KeysItem {
+                id: withKeys
+                Keys.enabled: false
+            }
+            KeysItem {
+                id: withoutKeys
+            }
+            function doTest() {
+                return withKeys.keys_enabled() === false
+                    && withoutKeys.keys_enabled() === undefined;
+            }
+        }
+        "
+    ));
+
+    // Not created by QML: there is no C++ object yet
+    assert!(get_attached_property::<bool, _>(
+        &KeysItem::default(),
+        "QQuickKeysAttached",
+        "enabled"
+    )
+    .is_none());
+}
+
+#[test]
+fn qml_engine_global_object() {
+    let _lock = lock_for_test();
+    let mut engine = QmlEngine::new();
+    let mut global = engine.global_object();
+    global.set_property("rustAnswer", QJSValue::from(42));
+    let list: QVariantList = vec![1, 2].into_iter().collect();
+    global.set_property("rustList", engine.to_script_value(list.into()));
+
+    let max = global.property("Math").property("max");
+    assert!(max.is_callable());
+    let r = max.call(&[QJSValue::from(3), QJSValue::from(8)]).ok().unwrap();
+    assert_eq!(r.to_number(), 8.);
+    let err =
+        global.property("decodeURIComponent").call(&[QString::from("%").into()]).err().unwrap();
+    assert_eq!(err.name().to_string(), "URIError");
+
+    engine.load_data(
+        "import QtQuick 2.0
+        Item {
+            function doTest() { return rustAnswer === 42 && rustList.length === 2; }
+        }"
+        .into(),
+    );
+    assert!(engine.invoke_method("doTest".into(), &[]).to_bool());
+}
+
+#[test]
+fn font_metrics() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut font = QFont::default();
+    font.set_pixel_size(20);
+    assert_eq!(font.pixel_size(), 20);
+    let metrics = QFontMetrics::from_font(&font);
+    assert!(metrics.height() > 0);
+    assert!(metrics.ascent() > 0 && metrics.descent() >= 0);
+    assert!(metrics.average_char_width() > 0);
+    let short = metrics.horizontal_advance("ab");
+    let long = metrics.horizontal_advance("abab");
+    assert!(short > 0 && long > short);
+    assert!(metrics.bounding_rect("abab").width() > 0);
+
+    let metrics_f = QFontMetricsF::from_font(&font);
+    assert!(metrics_f.height() > 0.);
+    assert!(metrics_f.horizontal_advance("abab") > metrics_f.horizontal_advance("ab"));
+    assert!(metrics_f.bounding_rect("abab").width > 0.);
+
+    assert!(QFont::from_qvariant(font.to_qvariant()).unwrap() == font);
+}
+
+#[test]
+fn widget_size_policy() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut policy = QSizePolicy::new(SizePolicy::Expanding, SizePolicy::Fixed);
+    policy.set_horizontal_stretch(2);
+    policy.set_vertical_stretch(1);
+    let widget = QTableView::new();
+    widget.set_size_policy(policy);
+    let result = widget.size_policy();
+    assert_eq!(result.horizontal_policy(), SizePolicy::Expanding);
+    assert_eq!(result.vertical_policy(), SizePolicy::Fixed);
+    assert_eq!(result.horizontal_stretch(), 2);
+    assert_eq!(result.vertical_stretch(), 1);
+    assert!(result == policy);
+    let _variant: QVariant = policy.into();
+}
+
+#[test]
+fn widget_layouts() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let window = QTableView::new();
+    let column = QVBoxLayout::new();
+    let first = QTableView::new();
+    column.add_widget(&first);
+    column.add_spacing(10);
+    let row = QHBoxLayout::new();
+    row.add_stretch(1);
+    column.add_layout(&row);
+    let grid = QGridLayout::new();
+    let second = QTableView::new();
+    grid.add_widget(&second, 1, 2, 1, 1, Alignment::AlignLeft | Alignment::AlignTop);
+    column.add_layout(&grid);
+    column.set_spacing(4);
+    column.set_contents_margins(1, 2, 3, 4);
+    window.set_layout(&column);
+
+    assert_eq!(column.count(), 4);
+    assert_eq!(column.spacing(), 4);
+    assert_eq!(grid.row_count(), 2);
+    assert_eq!(grid.column_count(), 3);
+    assert_eq!(window.layout().unwrap().cpp_ptr(), column.cpp_ptr());
+    assert_eq!(first.parent_widget().unwrap().cpp_ptr(), window.cpp_ptr());
+    assert_eq!(second.parent_widget().unwrap().cpp_ptr(), window.cpp_ptr());
+
+    // The layouts and widgets are owned by the window now
+    drop(column);
+    drop(grid);
+    assert!(!first.is_null());
+    drop(window);
+    assert!(first.is_null());
+    assert!(second.is_null());
+}
+
+#[test]
+fn form_layout() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let window = QTableView::new();
+    let form = QFormLayout::new();
+    window.set_layout(&form);
+    let name = QTableView::new();
+    form.add_row("Name:", &name);
+    let label = QHeaderView::new(Orientation::Horizontal);
+    let value = QTableView::new();
+    form.add_row_widget(&label, &value);
+    let first = QTableView::new();
+    form.insert_row(0, "First:", &first);
+    form.set_field_growth_policy(FieldGrowthPolicy::AllNonFixedFieldsGrow);
+    form.set_row_wrap_policy(RowWrapPolicy::WrapAllRows);
+
+    assert_eq!(form.row_count(), 3);
+    assert_eq!(form.field_growth_policy(), FieldGrowthPolicy::AllNonFixedFieldsGrow);
+    assert_eq!(form.row_wrap_policy(), RowWrapPolicy::WrapAllRows);
+    assert_eq!(label.parent_widget().unwrap().cpp_ptr(), window.cpp_ptr());
+}
+
+#[test]
+fn scroll_area() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let area = QScrollArea::new();
+    let content = QTableView::new();
+    content.resize(1000, 1000);
+    area.set_widget(&content);
+    area.set_widget_resizable(false);
+    area.set_horizontal_scroll_bar_policy(ScrollBarPolicy::ScrollBarAlwaysOff);
+    area.set_vertical_scroll_bar_policy(ScrollBarPolicy::ScrollBarAlwaysOn);
+    area.resize(100, 100);
+    assert!(!content.parent_widget().unwrap().is_null());
+
+    let bar = area.vertical_scroll_bar();
+    bar.set_range(0, 500);
+    bar.set_value(42);
+    assert_eq!(bar.value(), 42);
+    assert_eq!(bar.maximum(), 500);
+    area.ensure_visible(0, 0, 0, 0);
+    assert_eq!(bar.value(), 0);
+
+    drop(bar);
+    assert!(!area.vertical_scroll_bar().is_null());
+    drop(area);
+    assert!(content.is_null());
+}
+
+#[test]
+fn tab_widget() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let tabs = QTabWidget::new();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let changes2 = changes.clone();
+    let _current_changed = unsafe {
+        connect(tabs.cpp_ptr(), QTabWidget::current_changed_signal(), move |i: &i32| {
+            changes2.borrow_mut().push(*i)
+        })
+    };
+    let first = QTableView::new();
+    let second = QTableView::new();
+    let third = QTableView::new();
+    assert_eq!(tabs.add_tab(&first, "First"), 0);
+    assert_eq!(tabs.add_tab(&third, "Third"), 1);
+    assert_eq!(tabs.insert_tab(1, &second, "Second"), 1);
+    assert_eq!(tabs.count(), 3);
+    assert_eq!(tabs.tab_text(2).to_string(), "Third");
+    assert_eq!(tabs.tab_bar().tab_text(1).to_string(), "Second");
+    tabs.set_current_index(2);
+    assert_eq!(tabs.current_index(), 2);
+    tabs.remove_tab(0);
+    assert_eq!(tabs.count(), 2);
+    assert_eq!(tabs.current_index(), 1);
+    assert!(!first.is_null());
+    assert_eq!(*changes.borrow(), vec![0, 2, 1]);
+}
+
+#[test]
+fn stacked_widget() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let stack = QStackedWidget::new();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let changes2 = changes.clone();
+    let _current_changed = unsafe {
+        connect(stack.cpp_ptr(), QStackedWidget::current_changed_signal(), move |i: &i32| {
+            changes2.borrow_mut().push(*i)
+        })
+    };
+    assert!(stack.current_widget().is_none());
+    let first = QTableView::new();
+    let second = QTableView::new();
+    let third = QTableView::new();
+    assert_eq!(stack.add_widget(&first), 0);
+    assert_eq!(stack.add_widget(&third), 1);
+    assert_eq!(stack.insert_widget(1, &second), 1);
+    assert_eq!(stack.count(), 3);
+    stack.set_current_index(2);
+    assert_eq!(stack.current_index(), 2);
+    assert_eq!(stack.current_widget().unwrap().cpp_ptr(), third.cpp_ptr());
+    stack.remove_widget(&first);
+    assert_eq!(stack.count(), 2);
+    assert_eq!(stack.current_index(), 1);
+    assert!(!first.is_null());
+    assert_eq!(changes.borrow()[..2], [0, 2]);
+}
+
+#[test]
+fn splitter() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let splitter = QSplitter::new(Orientation::Horizontal);
+    let left = QTableView::new();
+    let right = QTableView::new();
+    let middle = QTableView::new();
+    splitter.add_widget(&left);
+    splitter.add_widget(&right);
+    splitter.insert_widget(1, &middle);
+    assert_eq!(splitter.count(), 3);
+    splitter.set_collapsible(0, false);
+    assert!(!splitter.is_collapsible(0));
+    assert!(splitter.is_collapsible(1));
+    splitter.set_sizes(&[100, 200, 300]);
+    assert_eq!(splitter.sizes().len(), 3);
+    drop(splitter);
+    assert!(middle.is_null());
+}
+
+#[test]
+fn status_bar() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let window = QMainWindow::new();
+    let central = QTableView::new();
+    window.set_central_widget(&central);
+    let status = window.status_bar();
+    assert_eq!(window.status_bar().cpp_ptr(), status.cpp_ptr());
+    let permanent = QTableView::new();
+    status.add_permanent_widget(&permanent);
+    let normal = QTableView::new();
+    status.add_widget(&normal);
+    status.show_message("Loading", 0);
+    assert_eq!(status.current_message().to_string(), "Loading");
+    status.clear_message();
+    assert_eq!(status.current_message().to_string(), "");
+    status.remove_widget(&normal);
+    assert!(!normal.is_null());
+    drop(window);
+    assert!(status.is_null());
+    assert!(central.is_null());
+    assert!(permanent.is_null());
+}
+
+#[test]
+fn progress_bar() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let bar = QProgressBar::new();
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let values2 = values.clone();
+    let _value_changed = unsafe {
+        connect(bar.cpp_ptr(), QProgressBar::value_changed_signal(), move |v: &i32| {
+            values2.borrow_mut().push(*v)
+        })
+    };
+    bar.set_range(0, 10);
+    bar.set_maximum(20);
+    bar.set_minimum(10);
+    assert_eq!((bar.minimum(), bar.maximum()), (10, 20));
+    bar.set_format("%v of %m");
+    bar.set_text_visible(true);
+    bar.set_value(15);
+    assert_eq!(bar.value(), 15);
+    assert_eq!(bar.text().to_string(), "15 of 10");
+    bar.reset();
+    assert_eq!(bar.value(), 9);
+    assert_eq!(*values.borrow(), vec![15]);
+}
+
+#[test]
+fn line_edit() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let edit = QLineEdit::new_with_text("hello");
+    assert_eq!(edit.text().to_string(), "hello");
+    edit.set_max_length(4);
+    assert_eq!(edit.text().to_string(), "hell");
+    let texts = Rc::new(RefCell::new(Vec::new()));
+    let texts2 = texts.clone();
+    let _text_changed = unsafe {
+        connect(edit.cpp_ptr(), QLineEdit::text_changed_signal(), move |t: &QString| {
+            texts2.borrow_mut().push(t.to_string())
+        })
+    };
+    edit.set_placeholder_text("Number");
+    edit.set_text("123456");
+    assert_eq!(edit.text().to_string(), "1234");
+
+    let validator = QValidator::new_int(0, 100);
+    assert_eq!(validator.validate("42"), ValidatorState::Acceptable);
+    assert_eq!(validator.validate("x"), ValidatorState::Invalid);
+    edit.set_validator(&validator);
+    assert!(!edit.has_acceptable_input());
+    edit.set_text("42");
+    assert!(edit.has_acceptable_input());
+    drop(validator);
+    edit.set_text("abc");
+    assert_eq!(edit.text().to_string(), "abc");
+
+    edit.set_echo_mode(EchoMode::NoEcho);
+    assert_eq!(edit.display_text().to_string(), "");
+    assert_eq!(*texts.borrow(), vec!["1234", "42", "abc"]);
+}
+
+#[test]
+fn combo_box() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let combo = QComboBox::new();
+    let indexes = Rc::new(RefCell::new(Vec::new()));
+    let indexes2 = indexes.clone();
+    let _index_changed = unsafe {
+        connect(combo.cpp_ptr(), QComboBox::current_index_changed_signal(), move |i: &i32| {
+            indexes2.borrow_mut().push(*i)
+        })
+    };
+    combo.add_item("red");
+    combo.add_items(&["green", "blue"]);
+    assert_eq!(combo.count(), 3);
+    assert_eq!(combo.current_text().to_string(), "red");
+    combo.set_current_index(2);
+    assert_eq!(combo.current_text().to_string(), "blue");
+
+    let mut model = QStandardItemModel::new(0, 1);
+    model.append_row(vec!["one".into()]);
+    model.append_row(vec!["two".into()]);
+    unsafe { combo.set_model_ptr(model.cpp_ptr()) };
+    assert_eq!(combo.count(), 2);
+    assert_eq!(combo.current_text().to_string(), "one");
+    assert_eq!(combo.current_index(), 0);
+    assert_eq!(*indexes.borrow(), vec![0, 2, 0]);
+}
+
+#[test]
+fn spin_boxes() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let spin = QSpinBox::new();
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let values2 = values.clone();
+    let _value_changed = unsafe {
+        connect(spin.cpp_ptr(), QSpinBox::value_changed_signal(), move |v: &i32| {
+            values2.borrow_mut().push(*v)
+        })
+    };
+    spin.set_range(-5, 5);
+    spin.set_step(2);
+    spin.set_suffix(" px");
+    spin.set_value(3);
+    spin.set_value(12);
+    assert_eq!(spin.value(), 5);
+    assert_eq!((spin.minimum(), spin.maximum()), (-5, 5));
+    assert_eq!(spin.text().to_string(), "5 px");
+    assert_eq!(*values.borrow(), vec![3, 5]);
+
+    let double = QDoubleSpinBox::new();
+    double.set_range(0., 1.);
+    double.set_decimals(3);
+    double.set_prefix("~");
+    double.set_value(0.12345);
+    assert_eq!(double.decimals(), 3);
+    assert_eq!(double.value(), 0.123);
+    assert_eq!(double.text().to_string(), "~0.123");
+}
+
+#[test]
+fn check_box_and_radio_button() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let check = QCheckBox::new("Enable");
+    assert_eq!(check.text().to_string(), "Enable");
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let states2 = states.clone();
+    let _state_changed = unsafe {
+        connect(check.cpp_ptr(), QCheckBox::state_changed_signal(), move |s: &i32| {
+            states2.borrow_mut().push(*s)
+        })
+    };
+    check.set_checked(true);
+    assert_eq!(check.check_state(), CheckState::Checked);
+    check.set_tristate(true);
+    assert!(check.is_tristate());
+    check.set_check_state(CheckState::PartiallyChecked);
+    assert!(!check.is_checked());
+    assert_eq!(
+        *states.borrow(),
+        vec![CheckState::Checked as i32, CheckState::PartiallyChecked as i32]
+    );
+
+    let group = QTableView::new();
+    let first = QRadioButton::new("First");
+    let second = QRadioButton::new("Second");
+    first.set_parent(Some(&group));
+    second.set_parent(Some(&group));
+    let toggles = Rc::new(RefCell::new(Vec::new()));
+    let toggles2 = toggles.clone();
+    let _toggled = unsafe {
+        connect(first.cpp_ptr(), QAbstractButton::toggled_signal(), move |t: &bool| {
+            toggles2.borrow_mut().push(*t)
+        })
+    };
+    first.set_checked(true);
+    second.set_checked(true);
+    assert!(!first.is_checked());
+    assert!(second.is_checked());
+    assert_eq!(*toggles.borrow(), vec![true, false]);
+}
+
+#[test]
+fn label() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let label = QLabel::new("Hello");
+    assert_eq!(label.text().to_string(), "Hello");
+    label.set_text("<a href='https://example.com'>link</a>");
+    assert_eq!(label.text().to_string(), "<a href='https://example.com'>link</a>");
+    label.set_word_wrap(true);
+    label.set_open_external_links(false);
+    label.set_alignment(Alignment::AlignRight | Alignment::AlignVCenter);
+    assert!(label.alignment().contains(Alignment::AlignRight));
+    assert!(!label.alignment().contains(Alignment::AlignLeft));
+
+    let mut image = QImage::new(QSize { width: 8, height: 8 }, ImageFormat::ARGB32);
+    image.fill(QColor::from_name("red"));
+    label.set_pixmap(&image.into());
+    assert_eq!(label.text().to_string(), "");
+}
+
+#[test]
+fn push_and_tool_buttons() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let push = QPushButton::new("OK");
+    let clicks = Rc::new(Cell::new(0));
+    let clicks2 = clicks.clone();
+    let _clicked = unsafe {
+        connect(push.cpp_ptr(), QAbstractButton::clicked_signal(), move |_: &bool| {
+            clicks2.set(clicks2.get() + 1)
+        })
+    };
+    push.set_flat(true);
+    push.set_default(true);
+    assert!(push.is_flat() && push.is_default());
+    let mut image = QImage::new(QSize { width: 4, height: 4 }, ImageFormat::ARGB32);
+    image.fill(QColor::from_name("blue"));
+    push.set_icon(QPixmap::from(image).into());
+    assert!(!push.icon().is_null());
+    push.click();
+    push.click();
+    assert_eq!(clicks.get(), 2);
+
+    let menu = QMenu::new("More");
+    let first = menu.add_action_with_text("First");
+    menu.add_separator();
+    let second = menu.add_action_with_text("Second");
+    assert_eq!(second.text().to_string(), "Second");
+    let tool = QToolButton::new();
+    tool.set_menu(&menu);
+    tool.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+    assert_eq!(tool.popup_mode(), ToolButtonPopupMode::MenuButtonPopup);
+    tool.set_default_action(&first);
+    assert_eq!(tool.text().to_string(), "First");
+    let triggered = Rc::new(RefCell::new(Vec::new()));
+    let triggered2 = triggered.clone();
+    let _triggered = unsafe {
+        connect(
+            tool.cpp_ptr(),
+            QToolButton::triggered_signal(),
+            move |a: &*mut std::os::raw::c_void| {
+                triggered2.borrow_mut().push(QAction::from_ptr(*a).text().to_string())
+            },
+        )
+    };
+    tool.click();
+    assert_eq!(*triggered.borrow(), vec!["First"]);
+
+    let own = QAction::new("Own", &tool);
+    tool.add_action(&own);
+    drop(tool);
+    assert!(own.is_null());
+    drop(menu);
+    assert!(first.is_null());
+}
+
+#[test]
+fn file_dialog_async() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.txt");
+    std::fs::write(&path, "hello").unwrap();
+
+    let dialog = Rc::new(QFileDialog::new(None, "Open", dir.path().to_str().unwrap(), "*.txt"));
+    dialog.set_file_mode(FileMode::ExistingFile);
+    let result = Rc::new(Cell::new(None));
+    let (dialog2, result2) = (dialog.clone(), result.clone());
+    future::execute_async(async move {
+        result2.set(Some(dialog2.exec_async().await));
+    });
+    app::process_events();
+    assert!(dialog.is_visible());
+    assert_eq!(result.get(), None);
+    dialog.select_file(path.to_str().unwrap());
+    dialog.accept();
+    app::process_events();
+    assert_eq!(result.get(), Some(DialogCode::Accepted as i32));
+    let files = dialog.selected_files();
+    assert_eq!(files.len(), 1);
+    assert!(files[0].to_string().ends_with("data.txt"));
+}
+
+#[test]
+fn color_dialog_async() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let red = QColor::from_name("red");
+    QColorDialog::set_custom_color(1, red);
+    assert!(QColorDialog::custom_colors()[1] == red);
+
+    let dialog = Rc::new(QColorDialog::new(None, QColor::from_name("blue")));
+    let result = Rc::new(Cell::new(None));
+    let (dialog2, result2) = (dialog.clone(), result.clone());
+    future::execute_async(async move {
+        result2.set(Some(dialog2.exec_async().await));
+    });
+    app::process_events();
+    assert!(dialog.current_color() == QColor::from_name("blue"));
+    dialog.set_current_color(red);
+    dialog.accept();
+    app::process_events();
+    assert_eq!(result.get(), Some(DialogCode::Accepted as i32));
+    assert!(dialog.selected_color() == red);
+}
+
+#[test]
+fn font_dialog_async() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut font = QFont::default();
+    font.set_point_size(17);
+    let dialog = Rc::new(QFontDialog::new(None, &font));
+    assert_eq!(dialog.current_font().point_size(), 17);
+    let result = Rc::new(Cell::new(None));
+    let (dialog2, result2) = (dialog.clone(), result.clone());
+    future::execute_async(async move {
+        result2.set(Some(dialog2.exec_async().await));
+    });
+    app::process_events();
+    font.set_point_size(23);
+    dialog.set_current_font(&font);
+    dialog.reject();
+    app::process_events();
+    assert_eq!(result.get(), Some(DialogCode::Rejected as i32));
+    assert_eq!(dialog.current_font().point_size(), 23);
+}
+
+#[test]
+fn input_dialog_async() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let dialog = Rc::new(QInputDialog::new(None));
+    dialog.set_label_text("Count:");
+    dialog.set_int_range(0, 10);
+    dialog.set_int_value(5);
+    let result = Rc::new(Cell::new(None));
+    let (dialog2, result2) = (dialog.clone(), result.clone());
+    future::execute_async(async move {
+        result2.set(Some(dialog2.exec_async().await));
+    });
+    app::process_events();
+    dialog.set_int_value(42);
+    assert_eq!(dialog.int_value(), 10);
+    dialog.accept();
+    app::process_events();
+    assert_eq!(result.get(), Some(DialogCode::Accepted as i32));
+
+    dialog.set_combo_box_items(&["a", "b", "c"]);
+    dialog.set_text_value("b");
+    assert_eq!(dialog.text_value().to_string(), "b");
+    dialog.set_double_range(0., 1.);
+    dialog.set_double_decimals(2);
+    dialog.set_double_value(0.456);
+    assert_eq!(dialog.double_value(), 0.46);
+}
+
+#[test]
+fn message_box_async() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let buttons = StandardButtons::Yes | StandardButtons::No;
+    assert!(buttons.contains(StandardButton::No.into()));
+    let message_box =
+        Rc::new(QMessageBox::new(None, MessageBoxIcon::Question, "Title", "Sure?", buttons));
+    message_box.set_default_button(StandardButton::No);
+    assert_eq!(message_box.text().to_string(), "Sure?");
+    let result = Rc::new(Cell::new(None));
+    let (message_box2, result2) = (message_box.clone(), result.clone());
+    future::execute_async(async move {
+        result2.set(Some(message_box2.exec_async().await));
+    });
+    app::process_events();
+    assert_eq!(result.get(), None);
+    message_box.click_button(StandardButton::Yes);
+    app::process_events();
+    assert_eq!(result.get(), Some(StandardButton::Yes));
+}
+
+#[test]
+fn wizard() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let wizard = QWizard::new();
+    let first = QWizardPage::new();
+    first.set_title("First");
+    let complete = Rc::new(Cell::new(false));
+    let complete2 = complete.clone();
+    first.set_is_complete(move || complete2.get());
+    assert!(!first.is_complete());
+    let second = QWizardPage::new();
+    second.set_subtitle("Second");
+    let first_id = wizard.add_page(&first);
+    wizard.set_page(10, &second);
+    wizard.set_page(5, &QWizardPage::new());
+    wizard.set_next_id(|current| if current == 0 { 10 } else { -1 });
+
+    wizard.open();
+    let ids = Rc::new(RefCell::new(Vec::new()));
+    let ids2 = ids.clone();
+    unsafe {
+        connect(wizard.cpp_ptr(), QWizard::current_id_changed_signal(), move |id: &i32| {
+            ids2.borrow_mut().push(*id)
+        });
+    }
+    assert_eq!(wizard.current_id(), first_id);
+    assert_eq!(wizard.next_id(), 10);
+    complete.set(true);
+    first.emit_complete_changed();
+    assert!(first.is_complete());
+    assert!(wizard.button(WizardButton::NextButton).is_enabled());
+    wizard.next();
+    assert_eq!(wizard.current_id(), 10);
+    assert_eq!(wizard.next_id(), -1);
+    wizard.back();
+    assert_eq!(*ids.borrow(), vec![10, 0]);
+    wizard.reject();
+}
+
+#[test]
+fn dock_widget() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let window = QMainWindow::new();
+    let dock = QDockWidget::new("Dock");
+    dock.set_widget(&QTableView::new());
+    let areas = DockWidgetAreas::LeftDockWidgetArea | DockWidgetArea::RightDockWidgetArea.into();
+    dock.set_allowed_areas(areas);
+    assert_eq!(dock.allowed_areas(), areas);
+    assert!(!dock.allowed_areas().contains(DockWidgetAreas::TopDockWidgetArea));
+    let features = DockWidgetFeatures::DockWidgetMovable | DockWidgetFeatures::DockWidgetFloatable;
+    dock.set_features(features);
+    assert_eq!(dock.features(), features);
+
+    let locations = Rc::new(RefCell::new(Vec::new()));
+    let locations2 = locations.clone();
+    unsafe {
+        connect(
+            dock.cpp_ptr(),
+            QDockWidget::dock_location_changed_signal(),
+            move |area: &DockWidgetArea| locations2.borrow_mut().push(*area),
+        );
+    }
+    window.add_dock_widget(DockWidgetArea::RightDockWidgetArea, &dock);
+    assert_eq!(window.dock_widget_area(&dock), DockWidgetArea::RightDockWidgetArea);
+    assert_eq!(*locations.borrow(), vec![DockWidgetArea::RightDockWidgetArea]);
+    assert!(!dock.is_floating());
+    dock.set_floating(true);
+    assert!(dock.is_floating());
+}
+
+#[test]
+fn tool_bar() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let window = QMainWindow::new();
+    let tool_bar = QToolBar::new("Tools");
+    window.add_tool_bar(&tool_bar);
+    let action = QAction::new("Run", &tool_bar);
+    tool_bar.add_action(&action);
+    tool_bar.add_separator();
+    let widget_action = tool_bar.add_widget(&QLineEdit::new());
+    assert!(!widget_action.is_null());
+    tool_bar.set_icon_size(QSize { width: 24, height: 24 });
+    assert_eq!(tool_bar.icon_size(), QSize { width: 24, height: 24 });
+    tool_bar.set_tool_button_style(ToolButtonStyle::ToolButtonTextUnderIcon);
+    assert_eq!(tool_bar.tool_button_style(), ToolButtonStyle::ToolButtonTextUnderIcon);
+    tool_bar.set_movable(false);
+    assert!(!tool_bar.is_movable());
+    tool_bar.set_floatable(false);
+    assert!(!tool_bar.is_floatable());
+    assert_eq!(tool_bar.orientation(), Orientation::Horizontal);
+
+    let triggered = Rc::new(RefCell::new(Vec::new()));
+    let triggered2 = triggered.clone();
+    unsafe {
+        connect(
+            tool_bar.cpp_ptr(),
+            QToolBar::action_triggered_signal(),
+            move |a: &*mut std::os::raw::c_void| {
+                triggered2.borrow_mut().push(QAction::from_ptr(*a).text().to_string())
+            },
+        );
+    }
+    action.trigger();
+    assert_eq!(*triggered.borrow(), vec!["Run".to_string()]);
+}
+
+#[test]
+fn graphics_scene() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let scene = QGraphicsScene::new();
+    let pen = QPen::new(QColor::from_name("black"), 1.);
+    let brush = QBrush::from(QColor::from_name("red"));
+    let rect = scene.add_rect(QRectF { x: 0., y: 0., width: 10., height: 10. }, &pen, &brush);
+    let text = scene.add_text("Hello");
+    text.set_pos(QPointF { x: 100., y: 100. });
+    assert_eq!(text.pos(), QPointF { x: 100., y: 100. });
+    assert_eq!(text.text().to_string(), "Hello");
+    let line = scene
+        .add_line(QLineF { p1: QPointF { x: 0., y: 50. }, p2: QPointF { x: 50., y: 50. } }, &pen);
+    line.set_z_value(2.);
+    assert_eq!(line.z_value(), 2.);
+    assert_eq!(scene.items().len(), 3);
+    let at = scene.items_at(QPointF { x: 5., y: 5. });
+    assert_eq!(at.len(), 1);
+    assert_eq!(at[0].scene_bounding_rect(), rect.scene_bounding_rect());
+
+    let view = QGraphicsView::new();
+    view.set_scene(&scene);
+    view.set_drag_mode(DragMode::RubberBandDrag);
+    assert_eq!(view.drag_mode(), DragMode::RubberBandDrag);
+    view.fit_in_view(scene.items_bounding_rect());
+
+    scene.delete_item(&rect);
+    assert!(rect.is_null());
+    assert_eq!(scene.items().len(), 2);
+    // A new item, possibly at the same address, is not accessed through the old reference
+    let rect2 = scene.add_rect(QRectF { x: 0., y: 0., width: 20., height: 20. }, &pen, &brush);
+    assert!(rect.is_null());
+    assert_eq!(rect.scene_bounding_rect(), QRectF::default());
+    assert!(!rect2.is_null());
+    scene.delete_item(&rect2);
+    drop(scene);
+    // The scene is owned by the view
+    assert!(!text.is_null());
+    drop(view);
+    assert!(text.is_null());
+}
+
+#[test]
+fn painter_on_image() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut img = QImage::new(QSize { width: 20, height: 20 }, ImageFormat::ARGB32);
+    img.fill(QColor::from_name("white"));
+    {
+        let mut painter = QPainter::begin(&mut img).unwrap();
+        painter
+            .fill_rect(QRectF { x: 0., y: 0., width: 10., height: 10. }, QColor::from_name("red"));
+        painter.set_pen(&QPen::new(QColor::from_name("blue"), 1.));
+        painter
+            .draw_line(QLineF { p1: QPointF { x: 0., y: 15.5 }, p2: QPointF { x: 20., y: 15.5 } });
+        assert!(painter.end());
+    }
+    assert!(img.get_pixel_color(5, 5) == QColor::from_name("red"));
+    assert!(img.get_pixel_color(15, 5) == QColor::from_name("white"));
+    assert!(img.get_pixel_color(5, 15) == QColor::from_name("blue"));
+
+    let mut null_img = QImage::default();
+    assert!(QPainter::begin(&mut null_img).is_none());
+}
+
+#[test]
+#[cfg(feature = "printsupport")]
+fn printer_to_pdf() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("out.pdf");
+    let mut printer = QPrinter::new();
+    printer.set_output_file_name(path.to_str().unwrap());
+    assert_eq!(printer.output_format(), OutputFormat::PdfFormat);
+    assert!(printer.set_page_size(PageSizeId::A4.into()));
+    assert!(printer.page_size() == QPageSize::new(PageSizeId::A4));
+    printer.set_full_page(true);
+    assert!(printer.full_page());
+    let rect = printer.page_rect(PrinterUnit::Millimeter);
+    assert!((rect.width - 210.).abs() < 1.);
+    {
+        let mut painter = QPainter::begin(&mut printer).unwrap();
+        painter.draw_text(QPointF { x: 100., y: 100. }, "Hello");
+        assert!(painter.end());
+    }
+    assert!(std::fs::metadata(&path).unwrap().len() > 0);
+}
+
+#[test]
+#[cfg(feature = "svg")]
+fn svg_generator() {
+    use qmetaobject::svg::*;
+
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let mut buffer = QBuffer::new();
+    {
+        let mut generator = QSvgGenerator::new();
+        generator.set_output_device(&mut buffer);
+        generator.set_size(QSize { width: 100, height: 50 });
+        assert_eq!(generator.size(), QSize { width: 100, height: 50 });
+        let view_box = QRectF { x: 0., y: 0., width: 100., height: 50. };
+        generator.set_view_box(view_box);
+        assert_eq!(generator.view_box(), view_box);
+        generator.set_title("Drawing");
+        generator.set_description("A red rectangle");
+        assert_eq!(generator.title().to_string(), "Drawing");
+        assert_eq!(generator.description().to_string(), "A red rectangle");
+
+        let mut painter = QPainter::begin(&mut generator).unwrap();
+        painter.fill_rect(
+            QRectF { x: 10., y: 10., width: 20., height: 20. },
+            QColor::from_name("red"),
+        );
+        assert!(painter.end());
+    }
+    let svg = buffer.data().to_string();
+    assert!(svg.contains("<svg"));
+    assert!(svg.contains("<title>Drawing</title>"));
+}
+
+#[test]
+#[cfg(feature = "charts")]
+fn chart_view() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let series = QLineSeries::new();
+    series.set_name("values");
+    series.append(0., 1.);
+    series.append(1., 3.);
+    assert_eq!(series.count(), 2);
+    series.replace_all(&[(0., 2.), (1., 4.), (2., 8.)]);
+    assert_eq!(series.count(), 3);
+    assert_eq!(series.at(2), QPointF { x: 2., y: 8. });
+
+    let chart = QChart::new();
+    chart.set_title("Chart");
+    assert_eq!(chart.title().to_string(), "Chart");
+    chart.add_series(&series);
+    chart.create_default_axes();
+    let x_axes = chart.axes(Orientation::Horizontal);
+    assert_eq!(x_axes.len(), 1);
+    assert_eq!(x_axes[0].orientation(), Orientation::Horizontal);
+    x_axes[0].set_title_text("x");
+    assert_eq!(x_axes[0].title_text().to_string(), "x");
+    assert_eq!(chart.axes(Orientation::Vertical).len(), 1);
+    let legend = chart.legend();
+    legend.set_alignment(Alignment::AlignBottom);
+    assert_eq!(legend.alignment(), Alignment::AlignBottom);
+
+    let view = QChartView::new();
+    view.set_chart(&chart);
+    view.set_render_hints(RenderHints::Antialiasing);
+    assert!(view.render_hints().contains(RenderHints::Antialiasing));
+    drop(chart);
+    drop(series);
+    // Owned by the view
+    assert!(!x_axes[0].is_null());
+    drop(view);
+    assert!(x_axes[0].is_null());
+}
+
+#[test]
+#[cfg(feature = "webengine-widgets")]
+#[cfg(not(any(qt_6_0, qt_6_1)))]
+#[cfg(not(all(target_os = "windows", not(target_env = "msvc"))))]
+fn web_engine_view() {
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let view = QWebEngineView::new();
+    view.set_zoom_factor(1.5);
+    assert_eq!(view.zoom_factor(), 1.5);
+    let page = view.page();
+    assert!(!page.is_null());
+    let result = Rc::new(RefCell::new(None));
+    let result2 = result.clone();
+    let page2 = page.clone();
+    let view_ptr = view.cpp_ptr();
+    future::execute_async(async move {
+        let (ok,) =
+            unsafe { future::wait_on_signal(view_ptr, QWebEngineView::load_finished_signal()) }
+                .await;
+        assert!(ok);
+        *result2.borrow_mut() = Some(page2.run_javascript("document.title + '!'").await);
+    });
+    view.set_html("<title>Hello</title>", QUrl::from(QString::from("about:blank")));
+    while result.borrow().is_none() {
+        app::process_events();
+    }
+    assert_eq!(view.title().to_string(), "Hello");
+    assert_eq!(result.borrow().as_ref().unwrap().to_qbytearray().to_string(), "Hello!");
+}
+
+#[derive(Default, Clone, QGadget)]
+struct ValueGadget {
+    x: qt_property!(i32),
+    label: qt_property!(QString),
+}
+
+#[derive(QObject, Default)]
+struct ObjectWithValueGadget {
+    base: qt_base_class!(trait QObject),
+    gadget: qt_property!(ValueGadget),
+}
+
+#[test]
+fn value_type_registration() {
+    qml_register_value_type::<ValueGadget>(
+        CStr::from_bytes_with_nul(b"ValueLib\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"valueGadget\0").unwrap(),
+    );
+    let mut obj = ObjectWithValueGadget::default();
+    obj.gadget.x = 42;
+    obj.gadget.label = "hello".into();
+    assert!(do_test(
+        obj,
+        "Item {
+            function doTest() {
+                return _obj.gadget.x === 42 && _obj.gadget.label === 'hello';
+            }
+        }"
+    ));
+}
+
+#[test]
+#[cfg(qt_6_0)]
+fn value_type_qml_property() {
+    qml_register_value_type::<ValueGadget>(
+        CStr::from_bytes_with_nul(b"ValueLib\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"valueGadget\0").unwrap(),
+    );
+    let mut obj = ObjectWithValueGadget::default();
+    obj.gadget.x = 7;
+    assert!(do_test(
+        obj,
+        "import ValueLib 1.0
+        Item {
+            property valueGadget copy: _obj.gadget
+            function doTest() {
+                return copy.x === 7;
+            }
+        }"
+    ));
+}
+
+#[test]
+fn pinned_get_cpp_object() {
+    let _lock = lock_for_test();
+    let obj = QObjectBox::new(MyObject::default());
+    let pinned = obj.pinned();
+    assert_eq!(pinned.get_cpp_object(), None);
+    assert_eq!(pinned.get_cpp_object(), None);
+    let ptr = pinned.get_or_create_cpp_object();
+    assert!(!ptr.is_null());
+    assert_eq!(pinned.get_cpp_object(), Some(ptr));
+}
+
+#[test]
+fn destroyed_with_object_signal() {
+    #[derive(QObject, Default)]
+    struct Foo {
+        base: qt_base_class!(trait QObject),
+    }
+
+    let _lock = lock_for_test();
+    let f = RefCell::new(Foo::default());
+    let obj_ptr = unsafe { QObjectPinned::new(&f).get_or_create_cpp_object() };
+    let result = Rc::new(RefCell::new(None));
+    let result2 = result.clone();
+    let con = unsafe {
+        connect(
+            obj_ptr,
+            <dyn QObject>::destroyed_with_object_signal(),
+            move |ptr: &*mut std::os::raw::c_void| {
+                *result2.borrow_mut() = Some(*ptr);
+            },
+        )
+    };
+    assert!(con.is_valid());
+    assert_eq!(*result.borrow(), None);
+    drop(f);
+    assert_eq!(*result.borrow(), Some(obj_ptr));
+}
+
+#[test]
+fn watch_children() {
+    use qmetaobject::hierarchy::{watch_children, ChildEventKind};
+
+    let _lock = lock_for_test();
+    let _engine = QmlEngine::new();
+
+    let parent = QLabel::new("Parent");
+    let child = QLabel::new("Child");
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events2 = events.clone();
+    let handle = unsafe {
+        watch_children(parent.cpp_ptr(), move |kind, c| {
+            // Polish events depend on the state of the widgets, only check the others
+            if kind != ChildEventKind::Polished {
+                events2.borrow_mut().push((kind, c));
+            }
+        })
+    };
+    child.set_parent(Some(&parent));
+    child.set_parent(None);
+    assert_eq!(
+        *events.borrow(),
+        vec![(ChildEventKind::Added, child.cpp_ptr()), (ChildEventKind::Removed, child.cpp_ptr())]
+    );
+    drop(handle);
+    child.set_parent(Some(&parent));
+    assert_eq!(events.borrow().len(), 2);
+}
+
+#[test]
+fn register_meta_type_for_rust_types() {
+    #[derive(Default, Clone, PartialEq, Debug)]
+    struct Custom(u32, String);
+    impl QMetaType for Custom {}
+
+    #[derive(Default, Clone, QGadget)]
+    struct CustomGadget {
+        x: qt_property!(i32),
+    }
+
+    let _lock = lock_for_test();
+    let id = register_meta_type::<Custom>();
+    assert!(id > 0);
+    assert_eq!(register_meta_type::<Custom>(), id);
+    assert_ne!(register_meta_type::<CustomGadget>(), id);
+
+    let value = Custom(42, "foo".into());
+    let variant = value.to_qvariant();
+    assert_eq!(variant.type_id(), id);
+    assert_eq!(Custom::from_qvariant(variant), Some(value));
+}
+
+#[test]
+fn qml_property_map() {
+    let _lock = lock_for_test();
+    let mut map = QQmlPropertyMap::new();
+    map.insert("answer", QVariant::from(42));
+    map.insert("name", QVariant::from(QString::from("foo")));
+    assert!(map.contains("answer"));
+    assert!(!map.contains("missing"));
+    assert_eq!(map.len(), 2);
+    let mut keys = map.keys();
+    keys.sort();
+    assert_eq!(keys, vec![QString::from("answer"), QString::from("name")]);
+
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let changes2 = changes.clone();
+    unsafe {
+        connect(
+            map.cpp_ptr(),
+            QQmlPropertyMap::value_changed_signal(),
+            move |key: &QString, value: &QVariant| {
+                changes2.borrow_mut().push((key.to_string(), value.to_qbytearray().to_string()))
+            },
+        );
+    }
+
+    let mut engine = QmlEngine::new();
+    engine.set_property("config".into(), map.to_qvariant());
+    engine.load_data(
+        "import QtQuick 2.0
+        Item {
+            function doTest() {
+                var ok = config.answer === 42 && config.name === 'foo';
+                config.name = 'bar';
+                return ok;
+            }
+        }"
+        .into(),
+    );
+    assert!(engine.invoke_method("doTest".into(), &[]).to_bool());
+    assert_eq!(*changes.borrow(), vec![("name".to_string(), "bar".to_string())]);
+    assert_eq!(map.value("name").to_qbytearray().to_string(), "bar");
+
+    map.clear("name");
+    assert!(map.contains("name"));
+    assert!(!map.value("name").is_valid());
+}
+
+#[derive(Default, QObject)]
+struct ListEntry {
+    base: qt_base_class!(trait QObject),
+    name: qt_property!(QString),
+}
+
+#[derive(Default, QObject)]
+struct ListContainer {
+    base: qt_base_class!(trait QObject),
+    entries: qt_list_property!(ListEntry),
+    entry_name: qt_method!(
+        fn entry_name(&self, index: u32) -> QString {
+            self.entries.at(index as usize).map(|e| e.borrow().name.clone()).unwrap_or_default()
+        }
+    ),
+}
+
+#[test]
+fn list_property() {
+    qml_register_type::<ListEntry>(
+        CStr::from_bytes_with_nul(b"TestListProperty\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"ListEntry\0").unwrap(),
+    );
+    qml_register_type::<ListContainer>(
+        CStr::from_bytes_with_nul(b"TestListProperty\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"ListContainer\0").unwrap(),
+    );
+
+    let obj = MyObject::default(); // not used but needed for do_test
+    assert!(do_test(
+        obj,
+        r"
+        import TestListProperty 1.0
+
+        Item {
+            ListContainer {
+                id: container
+                entries: [
+                    ListEntry { name: 'a' },
+                    ListEntry { name: 'b' }
+                ]
+            }
+            function doTest() {
+                return container.entries.length === 2
+                    && container.entries[1].name === 'b'
+                    && container.entry_name(0) === 'a'
+                    && container.entry_name(2) === '';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn simple_list_item_custom_role_names() {
+    struct Item(QString);
+    impl SimpleListItem for Item {
+        fn get(&self, role: i32) -> QVariant {
+            match role {
+                10 => self.0.clone().into(),
+                _ => QVariant::default(),
+            }
+        }
+        fn names() -> Vec<QByteArray> {
+            vec![QByteArray::from("label")]
+        }
+        fn role_names() -> std::collections::HashMap<i32, QByteArray> {
+            std::iter::once((USER_ROLE + 10, QByteArray::from("label"))).collect()
+        }
+    }
+
+    let _lock = lock_for_test();
+    let mut model = SimpleListModel::<Item>::default();
+    model.push(Item("foo".into()));
+    let model = RefCell::new(model);
+    unsafe { QObjectPinned::new(&model).get_or_create_cpp_object() };
+    let model = model.borrow();
+    let model: &dyn QAbstractListModel = &*model;
+    let names = model.role_names();
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[&(USER_ROLE + 10)].to_string(), "label");
+    assert_eq!(model.data(model.row_index(0), USER_ROLE + 10).to_qstring(), QString::from("foo"));
+}
+
+#[test]
+fn list_model_layout_change() {
+    #[derive(Clone)]
+    struct Item(i32);
+    impl SimpleListItem for Item {
+        fn get(&self, _role: i32) -> QVariant {
+            self.0.into()
+        }
+        fn names() -> Vec<QByteArray> {
+            vec![QByteArray::from("value")]
+        }
+    }
+
+    let _lock = lock_for_test();
+    let mut model = SimpleListModel::<Item>::default();
+    for i in &[3, 1, 2] {
+        model.push(Item(*i));
+    }
+    let model = RefCell::new(model);
+    unsafe { QObjectPinned::new(&model).get_or_create_cpp_object() };
+    let mut model = model.borrow_mut();
+    let model: &mut dyn QAbstractListModel = &mut *model;
+    model.layout_about_to_be_changed(&[], LayoutChangeHint::VerticalSortHint);
+    let mut calls = 0;
+    model.update_model_indexes(&mut |idx| {
+        calls += 1;
+        idx
+    });
+    model.layout_changed(&[], LayoutChangeHint::VerticalSortHint);
+    // No view is attached, so there is no persistent index to update
+    assert_eq!(calls, 0);
+    assert_eq!(model.row_count(), 3);
+}
+
+#[derive(QObject, Default)]
+struct SignalWithNamedArgs {
+    base: qt_base_class!(trait QObject),
+    my_signal: qt_signal!(xx: u32, yy: String),
+    emit_it: qt_method!(
+        fn emit_it(&self) {
+            self.my_signal(42, "foo".into());
+        }
+    ),
+}
+
+#[test]
+fn signal_parameter_names_in_qml() {
+    assert!(do_test(
+        SignalWithNamedArgs::default(),
+        r"
+        Item {
+            property string result
+            Connections {
+                target: _obj
+                onMy_signal: result = xx + ':' + yy
+            }
+            function doTest() {
+                _obj.emit_it();
+                return result === '42:foo';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn register_type_range() {
+    qml_register_type_range::<RegisteredObj>(
+        CStr::from_bytes_with_nul(b"TestRegisterRange\0").unwrap(),
+        1,
+        0,
+        2,
+        CStr::from_bytes_with_nul(b"RegisteredObj\0").unwrap(),
+    );
+
+    for version in &["1.0", "1.1", "1.2"] {
+        let obj = MyObject::default(); // not used but needed for do_test
+        assert!(do_test(
+            obj,
+            &format!(
+                r"
+                import TestRegisterRange {}
+
+                Item {{
+                    RegisteredObj {{
+                        id: test
+                        value: 55
+                    }}
+                    function doTest() {{
+                        return test.square(2) === 110;
+                    }}
+                }}
+                ",
+                version
+            )
+        ));
+    }
+}
+
+#[cfg(qt_5_15)]
+#[derive(QObject, Default)]
+struct ObjectWithRequiredProperty {
+    base: qt_base_class!(trait QObject),
+    name: qt_property!(QString; REQUIRED),
+}
+
+#[test]
+#[cfg(qt_5_15)]
+fn required_property() {
+    qml_register_type::<ObjectWithRequiredProperty>(
+        CStr::from_bytes_with_nul(b"TestRequired\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"WithRequired\0").unwrap(),
+    );
+    assert!(test_loading_logs(
+        "
+        import TestRequired 1.0
+
+        Item {
+            WithRequired { }
+        }
+        ",
+        "Required property name was not initialized"
+    ));
+
+    let obj = MyObject::default(); // not used but needed for do_test
+    assert!(do_test(
+        obj,
+        r"
+        import TestRequired 1.0
+
+        Item {
+            WithRequired {
+                id: test
+                name: 'foo'
+            }
+            function doTest() {
+                return test.name === 'foo';
+            }
+        }
+        "
+    ));
+}
+
+#[derive(QObject, Default)]
+struct ParserStatusObj {
+    base: qt_base_class!(trait QQmlParserStatus),
+    value: qt_property!(u32),
+    began: qt_property!(bool),
+    value_at_begin: qt_property!(u32),
+    value_at_complete: qt_property!(u32),
+}
+
+impl QQmlParserStatus for ParserStatusObj {
+    fn class_begin(&mut self) {
+        self.began = true;
+        self.value_at_begin = self.value;
+    }
+    fn component_complete(&mut self) {
+        self.value_at_complete = self.value;
+    }
+}
+
+#[test]
+fn parser_status_component_complete() {
+    qml_register_type::<ParserStatusObj>(
+        CStr::from_bytes_with_nul(b"TestParserStatus\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"ParserStatusObj\0").unwrap(),
+    );
+
+    let obj = MyObject::default(); // not used but needed for do_test
+    assert!(do_test(
+        obj,
+        r"
+        import TestParserStatus 1.0
+
+        Item {
+            ParserStatusObj {
+                id: test
+                value: 42
+            }
+            function doTest() {
+                return test.began && test.value_at_begin === 0 && test.value_at_complete === 42;
+            }
+        }
+        "
+    ));
+}
+
+#[derive(QObject, Default)]
+struct ConstantValueSource {
+    base: qt_base_class!(trait QQmlPropertyValueSource),
+    target_name: qt_property!(QString),
+}
+
+impl QQmlPropertyValueSource for ConstantValueSource {
+    fn set_target(&mut self, property: QQmlProperty) {
+        assert!(property.is_valid());
+        assert!(!property.object().is_null());
+        self.target_name = property.name();
+        property.write(QVariant::from(42));
+    }
+}
+
+#[test]
+fn property_value_source() {
+    qml_register_type::<ConstantValueSource>(
+        CStr::from_bytes_with_nul(b"TestValueSource\0").unwrap(),
+        1,
+        0,
+        CStr::from_bytes_with_nul(b"ConstantValueSource\0").unwrap(),
+    );
+
+    let obj = MyObject::default(); // not used but needed for do_test
+    assert!(do_test(
+        obj,
+        r"
+        import TestValueSource 1.0
+
+        Item {
+            id: item
+            ConstantValueSource on width { id: source }
+            function doTest() {
+                return item.width === 42 && source.target_name === 'width';
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn list_property_deleted_item() {
+    let _lock = lock_for_test();
+    let mut list = QQmlListProperty::<ListEntry>::default();
+    let entry = RefCell::new(ListEntry::default());
+    entry.borrow_mut().name = "a".into();
+    list.append(unsafe { QObjectPinned::new(&entry) });
+    assert_eq!(list.len(), 1);
+    assert_eq!(list.at(0).map(|e| e.borrow().name.clone()), Some(QString::from("a")));
+    drop(entry);
+    assert_eq!(list.len(), 1);
+    assert!(list.at(0).is_none());
+    assert!(list.cpp_ptr_at(0).is_none());
+}
+
+#[test]
+fn property_read_method_with_same_name_as_field() {
+    #[derive(QObject, Default)]
+    struct ObjectWithGetter {
+        base: qt_base_class!(trait QObject),
+        value: u32,
+        doubled: qt_property!(u32; READ value CONST),
+    }
+    impl ObjectWithGetter {
+        fn value(&self) -> u32 {
+            self.value * 2
+        }
+    }
+
+    let my_obj = ObjectWithGetter { value: 5, ..Default::default() };
+    assert!(do_test(
+        my_obj,
+        "
+        Item {
+            function doTest() {
+                return _obj.doubled === 10;
+            }
+        }
+        "
+    ));
+}
+
+#[test]
+fn qobject_enum_of_boxed_objects() {
+    #[derive(QObject, Default)]
+    struct Circle {
+        base: qt_base_class!(trait QObject),
+        radius: qt_property!(u32),
+    }
+
+    #[derive(QObject, Default)]
+    struct Square {
+        base: qt_base_class!(trait QObject),
+        side: qt_property!(u32),
+    }
+
+    #[derive(QObject)]
+    enum Shape {
+        Circle(Box<RefCell<Circle>>),
+        Square(Box<RefCell<Square>>),
+    }
+
+    let circle = Shape::Circle(Box::new(RefCell::new(Circle { radius: 4, ..Default::default() })));
+    assert!(do_test(
+        circle,
+        "Item { function doTest() { return _obj.radius === 4 && _obj.side === undefined } }"
+    ));
+    let square = Shape::Square(Box::new(RefCell::new(Square { side: 3, ..Default::default() })));
+    assert!(do_test(
+        square,
+        "Item { function doTest() { return _obj.side === 3 && _obj.radius === undefined } }"
+    ));
+
+    let inner = Box::new(RefCell::new(Square::default()));
+    let inner_ptr: *const RefCell<Square> = &*inner;
+    let shape = RefCell::new(Shape::Square(inner));
+    assert!(downcast_ref::<Square>(&*shape.borrow()).is_none());
+    assert!(downcast_mut::<Square>(&mut *shape.borrow_mut()).is_none());
+    let cpp_object = unsafe { QObjectPinned::new(&shape).get_or_create_cpp_object() };
+    assert!(!cpp_object.is_null());
+    assert_eq!(unsafe { &*inner_ptr }.borrow().get_cpp_object(), cpp_object);
+    assert_eq!(shape.borrow().get_cpp_object(), cpp_object);
+}
+
+#[test]
+fn qformat_macro() {
+    let name = QString::from("wörld");
+    let bytes = QByteArray::from("bytes");
+    assert_eq!(qformat!("plain"), QString::from("plain"));
+    assert_eq!(qformat!("{}", name), name);
+    assert_eq!(qformat!("{} {} {{}}", &name, bytes), QString::from("wörld bytes {}"));
+    assert_eq!(qformat!("{1}-{0}-{n}", 1, name, n = 2.5), QString::from("wörld-1-2.5"));
+    assert_eq!(qformat!("{:03}|{:>w$}|{:?}", 7, 1, "q", w = 3), QString::from("007|  1|\"q\""));
+    let count = 3;
+    assert_eq!(qformat!("{count} {bytes}"), QString::from("3 bytes"));
+}
@@ -27,6 +27,7 @@ use quote::{quote, ToTokens};
 use syn::DeriveInput;
 
 mod qbjs;
+mod qformat_impl;
 mod qobject_impl;
 mod qrc_impl;
 mod simplelistitem_impl;
@@ -54,25 +55,25 @@ fn get_crate(input: &DeriveInput) -> impl ToTokens {
 }
 
 /// Implementation of #[derive(QObject)]
-#[proc_macro_derive(QObject, attributes(QMetaObjectCrate, qt_base_class))]
+#[proc_macro_derive(QObject, attributes(QMetaObjectCrate, qt_base_class, qt_enums))]
 pub fn qobject_impl(input: TokenStream) -> TokenStream {
     qobject_impl::generate(input, true, 5)
 }
 
 /// Implementation of #[derive(QObject)]
-#[proc_macro_derive(QObject6, attributes(QMetaObjectCrate, qt_base_class))]
+#[proc_macro_derive(QObject6, attributes(QMetaObjectCrate, qt_base_class, qt_enums))]
 pub fn qobject_impl6(input: TokenStream) -> TokenStream {
     qobject_impl::generate(input, true, 6)
 }
 
 /// Implementation of #[derive(QGadget)]
-#[proc_macro_derive(QGadget, attributes(QMetaObjectCrate))]
+#[proc_macro_derive(QGadget, attributes(QMetaObjectCrate, qt_enums, qt_skip))]
 pub fn qgadget_impl(input: TokenStream) -> TokenStream {
     qobject_impl::generate(input, false, 5)
 }
 
 /// Implementation of #[derive(QGadget)]
-#[proc_macro_derive(QGadget6, attributes(QMetaObjectCrate))]
+#[proc_macro_derive(QGadget6, attributes(QMetaObjectCrate, qt_enums, qt_skip))]
 pub fn qgadget_impl6(input: TokenStream) -> TokenStream {
     qobject_impl::generate(input, false, 6)
 }
@@ -95,8 +96,14 @@ pub fn qrc_internal(input: TokenStream) -> TokenStream {
     qrc_impl::process_qrc(input)
 }
 
+// Implementation of the qmetaobject::qformat! macro
+#[proc_macro]
+pub fn qformat_internal(input: TokenStream) -> TokenStream {
+    qformat_impl::process_qformat(input)
+}
+
 /// Implementation of #[derive(SimpleListItem)]
-#[proc_macro_derive(SimpleListItem, attributes(QMetaObjectCrate))]
+#[proc_macro_derive(SimpleListItem, attributes(QMetaObjectCrate, qt_list_role))]
 pub fn simplelistitem(input: TokenStream) -> TokenStream {
     simplelistitem_impl::derive(input)
 }
@@ -0,0 +1,243 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, Ident, LitStr, Token};
+
+/// An argument of the `qformat!` macro, optionally named: `$( $name:ident = )? $value:expr`
+struct FormatArg {
+    name: Option<Ident>,
+    value: Expr,
+}
+
+impl Parse for FormatArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Some(name)
+        } else {
+            None
+        };
+        Ok(FormatArg { name, value: input.parse()? })
+    }
+}
+
+/// ```txt
+/// QFormat ::= $format:literal $( , $arg:FormatArg )* $(,)?
+/// ```
+struct QFormat {
+    format: LitStr,
+    args: Punctuated<FormatArg, Token![,]>,
+}
+
+impl Parse for QFormat {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let format = input.parse()?;
+        let args = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+        Ok(QFormat { format, args })
+    }
+}
+
+/// A piece of the format string.
+enum Piece {
+    Literal(String),
+    /// A placeholder, referring to the index of the argument, with the format spec after
+    /// the `:`, if any.
+    Argument(usize, String),
+}
+
+/// Split the format string in pieces, resolving the placeholders to the index of their argument.
+///
+/// Returns None for anything this does not handle (errors, or width and precision taken from
+/// arguments), so the caller can fall back to `format_args!`, which reports the errors.
+fn parse_format(
+    format: &str,
+    positional: usize,
+    names: &[String],
+    captured: &mut Vec<String>,
+) -> Option<Vec<Piece>> {
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut next_index = 0;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' => return None,
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        '{' => return None,
+                        c => placeholder.push(c),
+                    }
+                }
+                let (arg, spec) = match placeholder.find(':') {
+                    Some(pos) => (placeholder[..pos].trim(), &placeholder[pos + 1..]),
+                    None => (placeholder.trim(), ""),
+                };
+                if spec.contains('*') || spec.contains('$') {
+                    return None;
+                }
+                let index = if arg.is_empty() {
+                    next_index += 1;
+                    if next_index > positional {
+                        return None;
+                    }
+                    next_index - 1
+                } else if let Ok(index) = arg.parse::<usize>() {
+                    if index >= positional {
+                        return None;
+                    }
+                    index
+                } else if let Some(index) = names.iter().position(|n| n == arg) {
+                    positional + index
+                } else if syn::parse_str::<Ident>(arg).is_ok() {
+                    // An implicitly captured variable
+                    let index = match captured.iter().position(|n| n == arg) {
+                        Some(index) => index,
+                        None => {
+                            captured.push(arg.to_owned());
+                            captured.len() - 1
+                        }
+                    };
+                    positional + names.len() + index
+                } else {
+                    return None;
+                };
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Argument(index, spec.to_owned()));
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Some(pieces)
+}
+
+pub fn process_qformat(input: TokenStream) -> TokenStream {
+    let QFormat { format, args } = parse_macro_input!(input as QFormat);
+
+    let positional = args.iter().take_while(|a| a.name.is_none()).count();
+    let names: Vec<String> = args
+        .iter()
+        .skip(positional)
+        .filter_map(|a| a.name.as_ref().map(|n| n.to_string()))
+        .collect();
+    let mut captured = vec![];
+    let pieces = if positional + names.len() == args.len() {
+        parse_format(&format.value(), positional, &names, &mut captured)
+    } else {
+        // positional arguments after named ones
+        None
+    };
+    let pieces = match pieces {
+        // Every argument must be used, like with format_args!
+        Some(pieces)
+            if (0..args.len()).all(|i| {
+                pieces.iter().any(|p| matches!(p, Piece::Argument(index, _) if *index == i))
+            }) =>
+        {
+            pieces
+        }
+        _ => {
+            let args = args.iter().map(|a| match &a.name {
+                Some(name) => {
+                    let value = &a.value;
+                    quote!(#name = #value)
+                }
+                None => {
+                    let value = &a.value;
+                    quote!(#value)
+                }
+            });
+            return quote! {{
+                let mut __qformat_out = ::qmetaobject::QString::default();
+                // Writing to a QString never fails
+                ::std::fmt::Write::write_fmt(
+                    &mut __qformat_out,
+                    ::std::format_args!(#format #(, #args)*),
+                ).unwrap();
+                __qformat_out
+            }}
+            .into();
+        }
+    };
+
+    let values = args.iter().map(|a| a.value.clone()).chain(captured.iter().map(|c| {
+        let ident = Ident::new(c, Span::call_site());
+        syn::parse_quote!(#ident)
+    }));
+    let idents: Vec<Ident> = (0..args.len() + captured.len())
+        .map(|i| format_ident!("__qformat_arg{}", i, span = Span::call_site()))
+        .collect();
+
+    let appends = pieces.iter().map(|p| match p {
+        Piece::Literal(literal) => quote! {
+            ::std::fmt::Write::write_str(&mut __qformat_out, #literal).unwrap();
+        },
+        Piece::Argument(index, spec) if spec.is_empty() => {
+            let ident = &idents[*index];
+            quote! {
+                (&::qmetaobject::qformat::QFormatArg(#ident)).append_to(&mut __qformat_out);
+            }
+        }
+        Piece::Argument(index, spec) => {
+            let ident = &idents[*index];
+            let format = LitStr::new(&format!("{{:{}}}", spec), format.span());
+            quote! {
+                ::std::fmt::Write::write_fmt(&mut __qformat_out, ::std::format_args!(#format, #ident))
+                    .unwrap();
+            }
+        }
+    });
+
+    let q = quote! {{
+        #[allow(unused_imports)]
+        use ::qmetaobject::qformat::{AppendDisplay as _, AppendQString as _};
+        let mut __qformat_out = ::qmetaobject::QString::default();
+        match (#(&#values,)*) {
+            (#(#idents,)*) => {
+                #(#appends)*
+            }
+        }
+        __qformat_out
+    }};
+    q.into()
+}
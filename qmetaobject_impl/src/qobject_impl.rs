@@ -16,8 +16,9 @@ OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream, Parser, Result};
+use syn::spanned::Spanned;
 use syn::{parse_macro_input, parse_quote, DeriveInput, Token};
 
 use super::qbjs;
@@ -117,9 +118,41 @@ struct MetaProperty {
     typ: syn::Type,
     flags: u32,
     notify_signal: Option<syn::Ident>,
-    getter: Option<syn::Ident>,
-    setter: Option<syn::Ident>,
+    getter: Option<PropertyAccessor>,
+    setter: Option<PropertyAccessor>,
     alias: Option<syn::Ident>,
+    /// Declared with `qt_list_property!`: the getter also needs the C++ object
+    is_list: bool,
+}
+
+/// Target of a `READ` or `WRITE` flag of a property
+#[derive(Clone)]
+enum PropertyAccessor {
+    /// A method of the object: `fn getter(&self) -> T` or `fn setter(&mut self, T)`
+    Method(syn::Ident),
+    /// A field of the struct, or a path to a field of a nested struct (`a.b`), accessed directly
+    Field(syn::punctuated::Punctuated<syn::Ident, Token![.]>),
+    /// A field of a tuple struct, accessed by its index (`self.0`)
+    TupleField(syn::Index),
+}
+
+impl Parse for PropertyAccessor {
+    /// A single identifier is a method. A field is written `self.field`, and a field of a
+    /// nested struct `self.a.b` or `a.b`.
+    fn parse(input: ParseStream) -> Result<Self> {
+        let has_self = input.peek(Token![self]);
+        if has_self {
+            input.parse::<Token![self]>()?;
+            input.parse::<Token![.]>()?;
+        }
+        let path: syn::punctuated::Punctuated<syn::Ident, Token![.]> =
+            syn::punctuated::Punctuated::parse_separated_nonempty(input)?;
+        if !has_self && path.len() == 1 {
+            Ok(PropertyAccessor::Method(path[0].clone()))
+        } else {
+            Ok(PropertyAccessor::Field(path))
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -398,22 +431,62 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
     let mut base_prop: syn::Ident = parse_quote!(missing_base_class_property);
     let mut has_base_property = false;
 
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let mut generics = ast.generics.clone();
 
     if let syn::Data::Struct(ref data) = ast.data {
-        for f in data.fields.iter() {
+        if let syn::Fields::Unnamed(ref fields) = data.fields {
+            // A tuple struct gadget, such as a newtype wrapper: the first field is exposed
+            // as a property called `value`.
+            if is_qobject {
+                panic!("#[derive(QObject)] is not supported on tuple structs");
+            }
+            let field =
+                fields.unnamed.first().expect("#[derive(QGadget)] needs at least one field");
+            let typ = field.ty.clone();
+            let value: syn::Ident = parse_quote!(value);
+            properties.push(MetaProperty {
+                name: value.clone(),
+                typ: typ.clone(),
+                flags: 1 | 2 | 0x00004000 | 0x00001000 | 0x00010000,
+                notify_signal: None,
+                getter: Some(PropertyAccessor::TupleField(syn::Index::from(0))),
+                setter: Some(PropertyAccessor::TupleField(syn::Index::from(0))),
+                alias: Some(value),
+                is_list: false,
+            });
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#typ: ::std::cmp::PartialEq + ::std::clone::Clone + ::std::default::Default));
+        }
+        for f in data.fields.iter().filter(|f| f.ident.is_some()) {
+            if f.attrs.iter().any(|a| a.path.is_ident("qt_skip")) {
+                continue;
+            }
             use syn::Type::Macro;
+            match f.ty {
+                Macro(_) => {}
+                ref typ if !is_qobject => {
+                    // Plain fields of a gadget must be usable as a meta type, unless they
+                    // are marked with #[qt_skip]
+                    generics
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote!(#typ: #crate_::QMetaType));
+                }
+                _ => {}
+            }
             if let Macro(ref mac) = f.ty {
                 if let Some(ref segment) = mac.mac.path.segments.last() {
                     match segment.ident.to_string().as_ref() {
                         "qt_property" => {
-                            #[derive(Debug)]
                             enum Flag {
                                 Notify(syn::Ident),
-                                Read(syn::Ident),
-                                Write(syn::Ident),
+                                Read(PropertyAccessor),
+                                Write(PropertyAccessor),
                                 Alias(syn::Ident),
                                 Const,
+                                Required,
                             }
                             impl Parse for Flag {
                                 fn parse(input: ParseStream) -> Result<Self> {
@@ -422,6 +495,8 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                                         Ok(Flag::Notify(input.parse()?))
                                     } else if &k == "CONST" {
                                         Ok(Flag::Const)
+                                    } else if &k == "REQUIRED" {
+                                        Ok(Flag::Required)
                                     } else if &k == "READ" {
                                         Ok(Flag::Read(input.parse()?))
                                     } else if &k == "WRITE" {
@@ -473,6 +548,9 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                                         flags |= 0x00000400; // Constant
                                         flags &= !2; // Writable
                                     }
+                                    Flag::Required => {
+                                        flags |= 0x01000000; // Required
+                                    }
                                     Flag::Read(i) => {
                                         assert!(getter.is_none(), "Two READ for a property");
                                         getter = Some(i);
@@ -487,6 +565,11 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                                     }
                                 }
                             }
+                            if let (Some(PropertyAccessor::Field(path)), None) = (&getter, &setter)
+                            {
+                                // Write to the field that is read, not to the placeholder field
+                                setter = Some(PropertyAccessor::Field(path.clone()));
+                            }
                             properties.push(MetaProperty {
                                 name: f.ident.clone().expect("Property does not have a name"),
                                 typ: parsed.0,
@@ -495,6 +578,25 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                                 getter,
                                 setter,
                                 alias,
+                                is_list: false,
+                            });
+                        }
+                        "qt_list_property" => {
+                            if !is_qobject {
+                                panic!("qt_list_property! can only be used in a QObject");
+                            }
+                            let item_type: syn::Type =
+                                unwrap_parse_error!(syn::parse(mac.mac.tokens.clone().into()));
+                            properties.push(MetaProperty {
+                                name: f.ident.clone().expect("Property does not have a name"),
+                                typ: parse_quote!(#crate_::QQmlListProperty<#item_type>),
+                                // Readable, Constant, Scriptable, Stored, Designable
+                                flags: 1 | 0x00000400 | 0x00004000 | 0x00001000 | 0x00010000,
+                                notify_signal: None,
+                                getter: None,
+                                setter: None,
+                                alias: None,
+                                is_list: true,
                             });
                         }
                         "qt_method" => {
@@ -572,13 +674,27 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                 }
             }
         }
+    } else if let (true, syn::Data::Enum(ref data)) = (is_qobject, &ast.data) {
+        return generate_variant_delegate(&ast, data);
     } else {
-        //Nope. This is an Enum. We cannot handle these!
-        panic!("#[derive(QObject)] is only defined for structs, not for enums!");
+        // Nope. This is a Union, or an Enum for a QGadget. We cannot handle these!
+        let msg = if is_qobject {
+            "#[derive(QObject)] is only defined for structs and enums of boxed objects"
+        } else {
+            "#[derive(QGadget)] is only defined for structs"
+        };
+        return syn::Error::new_spanned(name, msg).to_compile_error().into();
     }
 
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     if is_qobject && !has_base_property {
-        panic!("#[derive(QObject)] needs at least one field of type qt_base_class!");
+        return syn::Error::new_spanned(
+            name,
+            "QObject derive requires a `base: qt_base_class!(trait QObject)` field",
+        )
+        .to_compile_error()
+        .into();
     }
 
     // prepend the methods in the signal
@@ -657,6 +773,18 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
         }
     };
 
+    // Checks that the property types implement PropertyType, so that the error points to the
+    // type of the field rather than to the generated code.
+    let property_type_checks: Vec<_> = properties
+        .iter()
+        .map(|prop| {
+            let typ = &prop.typ;
+            quote_spanned! {typ.span()=>
+                let _ = assert_property_type::<#typ>;
+            }
+        })
+        .collect();
+
     let property_meta_call: Vec<_> = properties
         .iter()
         .enumerate()
@@ -671,9 +799,14 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                     .find(|x| x.name == *signal && (x.flags & 0x4) != 0)
                     .map_or(0, |s| s.args.len());
                 let signal: syn::Ident = signal.clone();
+                let value = match prop.setter {
+                    Some(PropertyAccessor::Field(ref path)) => quote!{ obj.#path },
+                    Some(PropertyAccessor::TupleField(ref index)) => quote!{ obj.#index },
+                    _ => quote!{ obj.#property_name },
+                };
                 notify = match args_count {
                     0 => quote!{ obj.#signal() },
-                    1 => quote!{ obj.#signal(obj.#property_name.clone()) },
+                    1 => quote!{ obj.#signal(#value.clone()) },
                     _ => panic!("NOTIFY signal {} for property {} has too many arguments",
                                 signal, property_name),
                 };
@@ -693,26 +826,41 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                 quote!{}
             };
 
-            let getter = if let Some(ref getter) = prop.getter {
-                let getter_ident: syn::Ident = getter.clone();
-                quote!{
-                    let mut tmp : #typ = obj.#getter_ident();
+            let getter = match prop.getter {
+                None if prop.is_list => quote!{
+                    #crate_::QQmlListProperty::pass_to_qt_with_owner(&mut obj.#property_name, o, *a);
+                },
+                Some(PropertyAccessor::Method(ref getter)) => quote!{
+                    let mut tmp : #typ = obj.#getter();
                     <#typ as #crate_::PropertyType>::pass_to_qt(&mut tmp, *a);
-                }
-            } else {
-                quote!{ <#typ as #crate_::PropertyType>::pass_to_qt(&mut obj.#property_name, *a); }
+                },
+                Some(PropertyAccessor::Field(ref path)) => quote!{
+                    <#typ as #crate_::PropertyType>::pass_to_qt(&mut obj.#path, *a);
+                },
+                Some(PropertyAccessor::TupleField(ref index)) => quote!{
+                    <#typ as #crate_::PropertyType>::pass_to_qt(&mut obj.#index, *a);
+                },
+                None => quote!{
+                    <#typ as #crate_::PropertyType>::pass_to_qt(&mut obj.#property_name, *a);
+                },
             };
 
-            let setter = if let Some(ref setter) = prop.setter {
-                let setter_ident: syn::Ident = setter.clone();
-                quote!{
-                    obj.#setter_ident(<#typ as #crate_::PropertyType>::read_from_qt(*a));
-                }
-            } else {
-                quote! {
-                    obj.#property_name = <#typ as #crate_::PropertyType>::read_from_qt(*a);
+            let setter = match prop.setter {
+                Some(PropertyAccessor::Method(ref setter)) => quote!{
+                    obj.#setter(<#typ as #crate_::PropertyType>::read_from_qt(*a));
+                },
+                Some(PropertyAccessor::Field(ref path)) => quote! {
+                    <#typ as #crate_::PropertyType>::write_from_qt(&mut obj.#path, *a);
                     #notify
-                }
+                },
+                Some(PropertyAccessor::TupleField(ref index)) => quote! {
+                    <#typ as #crate_::PropertyType>::write_from_qt(&mut obj.#index, *a);
+                    #notify
+                },
+                None => quote! {
+                    <#typ as #crate_::PropertyType>::write_from_qt(&mut obj.#property_name, *a);
+                    #notify
+                },
             };
 
             quote! { #i => match c {
@@ -861,6 +1009,25 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
         }
     });
 
+    // Enums declared with #[qt_enums(...)] are exposed as related meta objects, which is how
+    // QML finds enums of other classes, and what makes `MyClass.Value` work.
+    let mut related_enums = Vec::<syn::Path>::new();
+    for attr in ast.attrs.iter().filter(|a| a.path.is_ident("qt_enums")) {
+        let paths = unwrap_parse_error!(attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Path, Token![,]>::parse_terminated
+        ));
+        related_enums.extend(paths);
+    }
+    let related_meta_objects = if related_enums.is_empty() {
+        quote! { ::std::ptr::null() }
+    } else {
+        quote! {
+            #crate_::related_meta_objects(&[
+                #(<#related_enums as #crate_::QEnum>::static_meta_object()),*
+            ])
+        }
+    };
+
     let base_meta_object = if is_qobject {
         quote! { <#name #ty_generics as #base>::get_object_description().meta_object }
     } else {
@@ -875,7 +1042,7 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                 string_data: STRING_DATA.as_ptr(),
                 data: INT_DATA.as_ptr(),
                 static_metacall: Some(static_metacall),
-                related_meta_objects: ::std::ptr::null(),
+                related_meta_objects: #related_meta_objects,
                 meta_types: #meta_types_init,
                 extra_data: ::std::ptr::null(),
             };};
@@ -906,7 +1073,7 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                     string_data: STRING_DATA.as_ptr(),
                     data: INT_DATA.as_ptr(),
                     static_metacall: Some(static_metacall #turbo_generics),
-                    related_meta_objects: ::std::ptr::null(),
+                    related_meta_objects: #related_meta_objects,
                     meta_types: #meta_types_init,
                     extra_data: ::std::ptr::null(),
             }));
@@ -968,6 +1135,14 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
             fn cpp_size() -> usize {
                 <#name #ty_generics as #base>::get_object_description().size
             }
+
+            fn parser_status_cast() -> i32 {
+                <#name #ty_generics as #base>::get_object_description().parser_status_cast
+            }
+
+            fn value_source_cast() -> i32 {
+                <#name #ty_generics as #base>::get_object_description().value_source_cast
+            }
         }
     } else {
         quote! {}
@@ -1004,6 +1179,10 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
                     a: *const *mut ::std::os::raw::c_void
                 ) #where_clause
                 {
+                    #[allow(dead_code)]
+                    fn assert_property_type<T: #crate_::PropertyType>() {}
+                    #(#property_type_checks)*
+
                     if c == #InvokeMetaMethod { unsafe {
                         #get_object
                         match idx {
@@ -1072,6 +1251,97 @@ pub fn generate(input: TokenStream, is_qobject: bool, qt_version: QtVersion) ->
     body.into()
 }
 
+/// Implementation of `#[derive(QObject)]` for an enum whose variants each hold a different
+/// object in a `Box<RefCell<T>>`.
+///
+/// The C++ object is the one of the current variant. The meta object is not forwarded: it is
+/// compared by `downcast_ref`, which must not reinterpret the enum as one of its variants. The
+/// static functions of the QObject trait cannot depend on the variant, so such an enum cannot be
+/// registered as a QML type.
+fn generate_variant_delegate(ast: &DeriveInput, data: &syn::DataEnum) -> TokenStream {
+    let name = &ast.ident;
+    let crate_ = super::get_crate(ast);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut variants = vec![];
+    for variant in data.variants.iter() {
+        match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                variants.push(variant.ident.clone())
+            }
+            _ => {
+                return syn::Error::new_spanned(
+                    variant,
+                    "#[derive(QObject)] on an enum requires each variant to hold a single \
+                     `Box<RefCell<T>>` where T is a QObject",
+                )
+                .to_compile_error()
+                .into()
+            }
+        }
+    }
+    let not_registrable = format!("{} is an enum of objects and cannot be registered", name);
+
+    let body = quote! {
+        impl #impl_generics #crate_::QObject for #name #ty_generics #where_clause {
+            fn meta_object(&self) -> *const #crate_::QMetaObject {
+                <Self as #crate_::QObject>::static_meta_object()
+            }
+
+            fn static_meta_object() -> *const #crate_::QMetaObject {
+                <Self as #crate_::QObject>::get_object_description().meta_object
+            }
+
+            fn get_cpp_object(&self) -> *mut ::std::os::raw::c_void {
+                match self {
+                    #(#name::#variants(x) => unsafe { #crate_::QObjectPinned::new(&**x) }
+                        .get_cpp_object()
+                        .unwrap_or(::std::ptr::null_mut()),)*
+                }
+            }
+
+            unsafe fn get_from_cpp<'pinned_ref>(
+                _ptr: *mut ::std::os::raw::c_void
+            ) -> #crate_::QObjectPinned<'pinned_ref, Self>
+            {
+                panic!(#not_registrable)
+            }
+
+            unsafe fn cpp_construct(
+                pinned: &::std::cell::RefCell<Self>
+            ) -> *mut ::std::os::raw::c_void
+            {
+                // The boxed object does not move when the enum moves.
+                match &*pinned.borrow() {
+                    #(#name::#variants(x) =>
+                        #crate_::QObjectPinned::new(&**x).get_or_create_cpp_object(),)*
+                }
+            }
+
+            unsafe fn qml_construct(
+                _pinned: &::std::cell::RefCell<Self>,
+                _mem: *mut ::std::os::raw::c_void,
+                _extra_destruct: extern fn(*mut ::std::os::raw::c_void)
+            ) {
+                panic!(#not_registrable)
+            }
+
+            fn cpp_size() -> usize {
+                panic!(#not_registrable)
+            }
+
+            fn parser_status_cast() -> i32 {
+                -1
+            }
+
+            fn value_source_cast() -> i32 {
+                -1
+            }
+        }
+    };
+    body.into()
+}
+
 fn is_valid_repr_attribute(attribute: &syn::Attribute) -> bool {
     match attribute.parse_meta() {
         Ok(syn::Meta::List(list)) => {
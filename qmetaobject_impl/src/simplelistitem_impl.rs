@@ -17,14 +17,57 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Ident, Visibility};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Field, Ident, Lit, LitStr, Meta, MetaNameValue,
+    Visibility,
+};
+
+/// Returns the role name given with the `#[qt_list_role = "name"]` attribute, if any.
+fn list_role_attribute(field: &Field) -> syn::Result<Option<LitStr>> {
+    for attr in &field.attrs {
+        if !attr.path.is_ident("qt_list_role") {
+            continue;
+        }
+        return match attr.parse_meta()? {
+            Meta::NameValue(MetaNameValue { lit: Lit::Str(name), .. }) => Ok(Some(name)),
+            meta => Err(syn::Error::new_spanned(
+                meta,
+                "expected a role name: #[qt_list_role = \"name\"]",
+            )),
+        };
+    }
+    Ok(None)
+}
 
 pub fn derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let crate_ = super::get_crate(&input);
 
-    let values = if let Data::Struct(ref data) = input.data {
-        data.fields
+    let fields = if let Data::Struct(ref data) = input.data {
+        &data.fields
+    } else {
+        panic!("#[derive(SimpleListItem)] is only defined for structs");
+    };
+
+    let mut roles = vec![];
+    for field in fields.iter() {
+        match list_role_attribute(field) {
+            Ok(Some(name)) => match field.ident {
+                Some(ref ident) => roles.push((ident.clone(), name)),
+                None => {
+                    return syn::Error::new_spanned(field, "#[qt_list_role] needs a named field")
+                        .to_compile_error()
+                        .into()
+                }
+            },
+            Ok(None) => {}
+            Err(e) => return e.to_compile_error().into(),
+        }
+    }
+
+    // Without any #[qt_list_role] attribute, all the public members are exposed with their name.
+    let (values, names) = if roles.is_empty() {
+        let values = fields
             .iter()
             .filter_map(|field| {
                 if let Visibility::Public(_) = field.vis {
@@ -33,9 +76,12 @@ pub fn derive(input: TokenStream) -> TokenStream {
                     None
                 }
             })
-            .collect::<Vec<Ident>>()
+            .collect::<Vec<Ident>>();
+        let names =
+            values.iter().map(|ident| LitStr::new(&ident.to_string(), ident.span())).collect();
+        (values, names)
     } else {
-        panic!("#[derive(SimpleListItem)] is only defined for structs");
+        roles.into_iter().unzip::<_, _, Vec<_>, Vec<_>>()
     };
 
     if values.is_empty() {
@@ -63,7 +109,7 @@ pub fn derive(input: TokenStream) -> TokenStream {
                 }
             }
             fn names() -> Vec<#crate_::QByteArray> {
-                vec![ #(#crate_::QByteArray::from(stringify!(#values))),* ]
+                vec![ #(#crate_::QByteArray::from(#names)),* ]
             }
         }
     ).into()
@@ -18,42 +18,17 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use std::io::prelude::*;
 use std::io::BufReader;
-use std::path::{Path, PathBuf};
-use std::process::Command;
 
-use semver::Version;
-
-fn qmake_query(var: &str) -> Result<String, std::io::Error> {
-    let qmake = std::env::var("QMAKE").unwrap_or("qmake".to_string());
-    let stdout: Vec<u8> = Command::new(qmake).args(&["-query", var]).output()?.stdout;
-    let stdout = String::from_utf8(stdout).expect("UTF-8 conversion failed");
-    Ok(stdout.trim().to_string())
-}
-
-fn open_core_header(file: &str, qt_include_path: &str, qt_library_path: &str) -> BufReader<std::fs::File> {
-    let cargo_target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
-
-    let mut path = PathBuf::from(qt_include_path);
-    path.push("QtCore");
-    path.push(file);
-    if cargo_target_os == "macos" {
-        if !path.exists() {
-            path = Path::new(qt_library_path).join("QtCore.framework/Headers");
-            path.push(file);
-        }
-    }
-    let f = std::fs::File::open(&path).expect(&format!("Cannot open `{:?}`", path));
-    BufReader::new(f)
-}
+use qttypes_build::{DetectError, QtInstall, Version};
 
 // qreal is a double, unless QT_COORD_TYPE says otherwise:
 // https://doc.qt.io/qt-5/qtglobal.html#qreal-typedef
-fn detect_qreal_size(qt_include_path: &str, qt_library_path: &str) {
-    const CONFIG_HEADER: &'static str = "qconfig.h";
-    let b = open_core_header(CONFIG_HEADER, qt_include_path, qt_library_path);
+fn detect_qreal_size(qt: &QtInstall) {
+    let path = qt.core_header("qconfig.h");
+    let f = std::fs::File::open(&path).expect(&format!("Cannot open `{:?}`", path));
 
     // Find declaration of QT_COORD_TYPE
-    for line in b.lines() {
+    for line in BufReader::new(f).lines() {
         let line = line.expect("UTF-8 conversion failed for qconfig.h");
         if line.contains("QT_COORD_TYPE") {
             if line.contains("float") {
@@ -66,66 +41,33 @@ fn detect_qreal_size(qt_include_path: &str, qt_library_path: &str) {
     }
 }
 
-fn detect_version_from_header(qt_include_path: &str, qt_library_path: &str) -> String {
-    const VERSION_HEADER: &'static str = "qtcoreversion.h";
-    let b = open_core_header(VERSION_HEADER, qt_include_path, qt_library_path);
-
-    // Find declaration of QTCORE_VERSION_STR
-    for line in b.lines() {
-        let line = line.expect("UTF-8 conversion failed for qtcoreversion.h");
-        if line.contains("QTCORE_VERSION_STR") {
-            return line.split('\"').nth(1).expect("Parsing QTCORE_VERSION_STR").into();
-        }
-    }
-    panic!("Could not detect Qt version from include paths")
-}
-
 fn main() {
     // Simple cfg!(target_* = "...") doesn't work in build scripts the way it does in crate's code.
     // https://doc.rust-lang.org/cargo/reference/environment-variables.html#environment-variables-cargo-sets-for-build-scripts
     let cargo_target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
     let cargo_target_env = std::env::var("CARGO_CFG_TARGET_ENV").unwrap();
 
-    println!("cargo:rerun-if-env-changed=QT_INCLUDE_PATH");
-    println!("cargo:rerun-if-env-changed=QT_LIBRARY_PATH");
-    let (qt_version, qt_include_path, qt_library_path) = match (
-        std::env::var("QT_INCLUDE_PATH").ok().filter(|x| !x.is_empty()),
-        std::env::var("QT_LIBRARY_PATH").ok().filter(|x| !x.is_empty()),
-    ) {
-        (Some(qt_include_path), Some(qt_library_path)) => {
-            let qt_version = detect_version_from_header(&qt_include_path, &qt_library_path);
-            (qt_version, qt_include_path, qt_library_path)
-        }
-        (None, None) => {
-            let qt_version = match qmake_query("QT_VERSION") {
-                Ok(v) => v,
-                Err(_err) => {
-                    #[cfg(feature = "required")]
-                    panic!(
-                        "Error: Failed to execute qmake. Make sure 'qmake' is in your path!\n{:?}",
-                        _err
-                    );
-                    #[cfg(not(feature = "required"))]
-                    {
-                        println!("cargo:rerun-if-env-changed=QMAKE");
-                        println!("cargo:rustc-cfg=no_qt");
-                        println!("cargo:FOUND=0");
-                        return;
-                    }
-                }
-            };
-            let qt_include_path = qmake_query("QT_INSTALL_HEADERS").unwrap();
-            let qt_library_path = qmake_query("QT_INSTALL_LIBS").unwrap();
-            println!("cargo:rerun-if-env-changed=QMAKE");
-            (qt_version, qt_include_path, qt_library_path)
-        }
-        (Some(_), None) | (None, Some(_)) => {
-            panic!("QT_INCLUDE_PATH and QT_LIBRARY_PATH env variable must be either both empty or both set.")
+    let qt = match QtInstall::detect() {
+        Ok(install) => install,
+        Err(DetectError::IncompleteEnv) => panic!("{}", DetectError::IncompleteEnv),
+        Err(_err) => {
+            #[cfg(feature = "required")]
+            panic!("{}", _err);
+            #[cfg(not(feature = "required"))]
+            {
+                println!("cargo:rustc-cfg=no_qt");
+                println!("cargo:FOUND=0");
+                return;
+            }
         }
     };
-    let qt_version = qt_version
-        .parse::<Version>()
-        .expect("Parsing Qt version failed");
+
+    detect_qreal_size(&qt);
+    let QtInstall {
+        version: qt_version,
+        include_path: qt_include_path,
+        library_path: qt_library_path,
+    } = qt;
 
     let mut config = cpp_build::Config::new();
 
@@ -134,8 +76,6 @@ fn main() {
         config.flag(&qt_library_path);
     }
 
-    detect_qreal_size(&qt_include_path, &qt_library_path);
-
     if qt_version >= Version::new(6, 0, 0) {
         config.flag_if_supported("-std=c++17");
         config.flag_if_supported("/std:c++17");
@@ -185,6 +125,8 @@ fn main() {
     link_lib("Quick");
     #[cfg(feature = "qtquick")]
     link_lib("Qml");
+    #[cfg(feature = "qtcharts")]
+    link_lib("Charts");
     #[cfg(feature = "qtwebengine")]
     if qt_version >= Version::new(6, 0, 0) && qt_version < Version::new(6, 2, 0) {
         println!("cargo:warning=WebEngine is not supported on Qt {} yet. It is planned for Qt 6.2 LTS.", qt_version);
@@ -193,14 +135,30 @@ fn main() {
     } else {
         link_lib("WebEngine");
     }
+    // When WebEngine is not available, the qtwebengine feature already emitted a warning.
+    #[cfg(feature = "qtwebenginewidgets")]
+    if !(qt_version >= Version::new(6, 0, 0) && qt_version < Version::new(6, 2, 0))
+        && !((cargo_target_os == "windows") && (cargo_target_env != "msvc"))
+    {
+        link_lib("WebEngineWidgets");
+        if qt_version >= Version::new(6, 0, 0) {
+            link_lib("WebEngineCore");
+        }
+    }
     #[cfg(feature = "qtquickcontrols2")]
     link_lib("QuickControls2");
     #[cfg(feature = "qtmultimedia")]
     link_lib("Multimedia");
     #[cfg(feature = "qtmultimediawidgets")]
     link_lib("MultimediaWidgets");
+    #[cfg(feature = "qtnetwork")]
+    link_lib("Network");
+    #[cfg(feature = "qtprintsupport")]
+    link_lib("PrintSupport");
     #[cfg(feature = "qtsql")]
     link_lib("Sql");
+    #[cfg(feature = "qtsvg")]
+    link_lib("Svg");
     #[cfg(feature = "qttest")]
     link_lib("Test");
 
@@ -39,7 +39,12 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 //!   directory where the Qt headers and Qt libraries are installed.
 //! - Otherwise youo can specify a `QMAKE` environment variable with the absolute path of the
 //!   `qmake` executable which will be used to querty these paths
-//! - If none of these environment variable is set, the `qmake` executable found in `$PATH`
+//! - If none of these environment variable is set, `pkg-config` is asked for `Qt6Core` or `Qt5Core`
+//!   (the `PKG_CONFIG` environment variable can be used to select another executable)
+//! - Then the `qmake` executable found in `$PATH` is used
+//! - Finally, the usual installation directories are searched: `C:\Qt\<version>\<compiler>` on
+//!   Windows, Homebrew on macOS, and `/usr/include/qt5` or `/usr/include/qt6` (and their multiarch
+//!   variants) on Linux
 //!
 //! ## Philosophy
 //!
@@ -108,21 +113,25 @@ CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 //!
 //! | Cargo feature             | Qt module             |
 //! | ------------------------- | --------------------- |
+//! | **`qtcharts`**            | Qt Charts             |
 //! | **`qtmultimedia`**        | Qt Multimedia         |
 //! | **`qtmultimediawidgets`** | Qt Multimedia Widgets |
+//! | **`qtnetwork`**           | Qt Network            |
+//! | **`qtprintsupport`**      | Qt Print Support      |
 //! | **`qtquick`**             | Qt Quick              |
 //! | **`qtquickcontrols2`**    | Qt Quick Controls     |
 //! | **`qtsql`**               | Qt SQL                |
+//! | **`qtsvg`**               | Qt SVG                |
 //! | **`qttest`**              | Qt Test               |
 //! | **`qtwebengine`**         | Qt WebEngine          |
 //!
 
 #![cfg_attr(no_qt, allow(unused))]
 
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::fmt::Display;
 use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, BitOr, BitOrAssign, Div, Index, IndexMut, Mul, Sub};
 use std::os::raw::c_char;
 use std::str::Utf8Error;
 
@@ -162,12 +171,21 @@ macro_rules! cpp_class {
 cpp! {{
     #include <QtCore/QByteArray>
     #include <QtCore/QDateTime>
+    #include <QtCore/QEasingCurve>
+    #include <QtCore/QItemSelection>
+    #include <QtCore/QJsonDocument>
+    #include <QtCore/QJsonObject>
     #include <QtCore/QModelIndex>
     #include <QtCore/QString>
     #include <QtCore/QUrl>
     #include <QtCore/QVariant>
 
+    #include <QtGui/QBrush>
+    #include <QtGui/QFont>
+    #include <QtGui/QFontMetrics>
+    #include <QtGui/QIcon>
     #include <QtGui/QImage>
+    #include <QtGui/QPen>
     #include <QtGui/QPixmap>
 }}
 
@@ -192,6 +210,62 @@ impl QByteArray {
     pub fn to_str(&self) -> Result<&str, Utf8Error> {
         std::str::from_utf8(self.to_slice())
     }
+
+    /// Returns a copy of the content of the byte array.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.to_slice().to_vec()
+    }
+
+    /// Returns the content of the byte array, without copying. Same as `to_slice`.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.to_slice()
+    }
+
+    /// Returns the content of the byte array as a mutable slice, without copying.
+    ///
+    /// Wrapper around [`data()`][method] method, which detaches the byte array if its data
+    /// is shared with another QByteArray.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qbytearray.html#data
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            let mut size: usize = 0;
+            let c_ptr = cpp!([self as "QByteArray*", mut size as "size_t"] -> *mut u8 as "char*" {
+                size = self->size();
+                return self->data();
+            });
+            std::slice::from_raw_parts_mut(c_ptr, size)
+        }
+    }
+
+    /// Creates an empty byte array with room for at least `capacity` bytes.
+    ///
+    /// Wrapper around [`reserve(int size)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qbytearray.html#reserve
+    pub fn with_capacity(capacity: usize) -> QByteArray {
+        cpp!(unsafe [capacity as "size_t"] -> QByteArray as "QByteArray" {
+            QByteArray array;
+            array.reserve(capacity);
+            return array;
+        })
+    }
+
+    /// Wrapper around [`resize(int size)`][method] method.
+    ///
+    /// # Wrapper-specific
+    ///
+    /// Unlike in Qt, the bytes added when growing the array are set to zero.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qbytearray.html#resize
+    pub fn resize(&mut self, size: usize) {
+        cpp!(unsafe [self as "QByteArray*", size as "size_t"] {
+            auto old_size = self->size();
+            self->resize(size);
+            if (self->size() > old_size)
+                memset(self->data() + old_size, 0, self->size() - old_size);
+        })
+    }
 }
 impl<'a> From<&'a [u8]> for QByteArray {
     /// Constructs a `QByteArray` from a slice. (Copy the slice.)
@@ -203,6 +277,29 @@ impl<'a> From<&'a [u8]> for QByteArray {
         })
     }
 }
+impl<'a> From<&'a Vec<u8>> for QByteArray {
+    /// Constructs a `QByteArray` from a `&Vec<u8>`. (Copy the data.)
+    fn from(v: &'a Vec<u8>) -> QByteArray {
+        QByteArray::from(v.as_slice())
+    }
+}
+impl From<Vec<u8>> for QByteArray {
+    /// Constructs a `QByteArray` from a `Vec<u8>`. (Copy the data.)
+    fn from(v: Vec<u8>) -> QByteArray {
+        QByteArray::from(v.as_slice())
+    }
+}
+impl From<QByteArray> for Vec<u8> {
+    /// Copy the data of the `QByteArray`.
+    fn from(a: QByteArray) -> Vec<u8> {
+        a.to_vec()
+    }
+}
+impl AsRef<[u8]> for QByteArray {
+    fn as_ref(&self) -> &[u8] {
+        self.to_slice()
+    }
+}
 impl<'a> From<&'a str> for QByteArray {
     /// Constructs a `QByteArray` from a `&str`. (Copy the string.)
     fn from(s: &'a str) -> QByteArray {
@@ -647,6 +744,48 @@ impl From<String> for QString {
         QString::from(&*s)
     }
 }
+impl<'a> From<&'a std::path::Path> for QString {
+    /// Converts a path, using UTF-16 on Windows and UTF-8 on the other platforms. Invalid
+    /// UTF-8 sequences are replaced by the replacement character.
+    fn from(path: &'a std::path::Path) -> QString {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            let wide: Vec<u16> = path.as_os_str().encode_wide().collect();
+            let len = wide.len();
+            let ptr = wide.as_ptr();
+            cpp!(unsafe [len as "size_t", ptr as "const QChar*"] -> QString as "QString" {
+                return QString(ptr, len);
+            })
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let bytes = path.as_os_str().as_bytes();
+            let len = bytes.len();
+            let ptr = bytes.as_ptr();
+            cpp!(unsafe [len as "size_t", ptr as "char*"] -> QString as "QString" {
+                return QString::fromUtf8(ptr, len);
+            })
+        }
+        #[cfg(not(any(windows, unix)))]
+        {
+            QString::from(&*path.to_string_lossy())
+        }
+    }
+}
+impl From<std::path::PathBuf> for QString {
+    fn from(path: std::path::PathBuf) -> QString {
+        QString::from(path.as_path())
+    }
+}
+impl TryFrom<QString> for std::path::PathBuf {
+    type Error = std::string::FromUtf16Error;
+    /// Converts the string to a path. Fails if the string is not valid UTF-16.
+    fn try_from(s: QString) -> Result<std::path::PathBuf, Self::Error> {
+        String::from_utf16(s.to_slice()).map(std::path::PathBuf::from)
+    }
+}
 impl Into<String> for QString {
     fn into(self) -> String {
         String::from_utf16_lossy(self.to_slice())
@@ -657,6 +796,18 @@ impl Display for QString {
         QByteArray::from(self.clone()).fmt(f)
     }
 }
+impl std::fmt::Write for QString {
+    /// Appends the UTF-8 string, converted to UTF-16 directly in the QString buffer.
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let len = s.len();
+        let ptr = s.as_ptr();
+        cpp!(unsafe [self as "QString*", len as "size_t", ptr as "char*"] {
+            self->append(QString::fromUtf8(ptr, len));
+        });
+        Ok(())
+    }
+}
+
 impl std::fmt::Debug for QString {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self)
@@ -698,8 +849,456 @@ impl QVariant {
         })
     }
 
+    /// Wrapper around [`toString()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toString
+    pub fn to_qstring(&self) -> QString {
+        cpp!(unsafe [self as "const QVariant*"] -> QString as "QString" {
+            return self->toString();
+        })
+    }
+
+    /// Returns the meta type id of the value contained in the variant, same as
+    /// [`userType()`][method]. Use `VariantType::from` to compare it with the built-in types.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#userType
+    pub fn type_id(&self) -> i32 {
+        self.user_type()
+    }
+
+    /// Wrapper around [`QMetaType::typeName()`][method] for the type of the variant.
+    ///
+    /// Returns an empty string for an invalid variant.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmetatype.html#typeName
+    pub fn type_name(&self) -> &'static str {
+        let type_id = self.type_id();
+        let c_ptr = cpp!(unsafe [type_id as "int"] -> *const c_char as "const char*" {
+        #if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+            return QMetaType(type_id).name();
+        #else
+            return QMetaType::typeName(type_id);
+        #endif
+        });
+        if c_ptr.is_null() {
+            return "";
+        }
+        // The names of the registered types are never freed.
+        unsafe { std::ffi::CStr::from_ptr(c_ptr) }.to_str().unwrap_or("")
+    }
+
+    /// Wrapper around [`isValid()`][method] method.
+    ///
+    /// A variant is valid if it contains a value of any type, even a null one. Only a
+    /// default constructed variant (`QVariant::default()`, which QML sees as `undefined`)
+    /// is invalid.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#isValid
+    pub fn is_valid(&self) -> bool {
+        cpp!(unsafe [self as "const QVariant*"] -> bool as "bool" {
+            return self->isValid();
+        })
+    }
+
+    /// Wrapper around [`isNull()`][method] method.
+    ///
+    /// Unlike `is_valid`, this also returns true for a variant containing a null value, such
+    /// as a null QString. An invalid variant is always null.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#isNull
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QVariant*"] -> bool as "bool" {
+            return self->isNull();
+        })
+    }
+
+    /// Returns a valid variant containing a `std::nullptr_t`, which is how QML
+    /// represents `null`, as opposed to `undefined` for an invalid variant.
+    pub fn null() -> QVariant {
+        cpp!(unsafe [] -> QVariant as "QVariant" {
+            return QVariant::fromValue(nullptr);
+        })
+    }
+
     // FIXME: do more wrappers
 }
+impl Display for QVariant {
+    /// Prints the result of `QVariant::toString()`, which is empty for the types that cannot
+    /// be converted to a string.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.to_qstring().fmt(f)
+    }
+}
+impl std::fmt::Debug for QVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "QVariant(type={}, value={})", self.type_name(), self)
+    }
+}
+cpp! {{
+    // Arithmetic on two numeric variants: integers stay integers, anything else is computed
+    // with doubles. Returns an invalid variant if one of the operands is not a number, or for
+    // an integer division by zero.
+    static bool qvariant_is_integer(const QVariant &v) {
+        switch (v.userType()) {
+            case QMetaType::Int: case QMetaType::UInt: case QMetaType::LongLong:
+            case QMetaType::ULongLong: case QMetaType::Long: case QMetaType::ULong:
+            case QMetaType::Short: case QMetaType::UShort: case QMetaType::SChar:
+            case QMetaType::UChar:
+                return true;
+            default:
+                return false;
+        }
+    }
+
+    // Arithmetic on doubles. The integer case is handled on the Rust side, with checked
+    // operations.
+    static QVariant qvariant_arithmetic(const QVariant &a, const QVariant &b, char op) {
+        auto isNumber = [](const QVariant &v) {
+            return qvariant_is_integer(v) || v.userType() == QMetaType::Double
+                || v.userType() == QMetaType::Float;
+        };
+        if (!isNumber(a) || !isNumber(b))
+            return QVariant();
+        double x = a.toDouble();
+        double y = b.toDouble();
+        switch (op) {
+            case '+': return QVariant(x + y);
+            case '-': return QVariant(x - y);
+            case '*': return QVariant(x * y);
+            default: return QVariant(x / y);
+        }
+    }
+}}
+
+impl QVariant {
+    /// Returns the value as a f64 if the variant contains a number, or a value that can
+    /// be converted to a number (such as a numeric string).
+    pub fn to_f64(&self) -> Option<f64> {
+        f64::try_from(self).ok()
+    }
+
+    /// Returns the value as a i64 if the variant contains an integer, or a value that can
+    /// be converted to an integer (such as a numeric string).
+    pub fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    fn arithmetic(&self, other: &QVariant, op: u8) -> QVariant {
+        let integers = cpp!(unsafe [self as "const QVariant*", other as "const QVariant*"] -> bool as "bool" {
+            return qvariant_is_integer(*self) && qvariant_is_integer(*other);
+        });
+        if integers {
+            let result = match (self.to_i64(), other.to_i64()) {
+                (Some(x), Some(y)) => match op {
+                    b'+' => x.checked_add(y),
+                    b'-' => x.checked_sub(y),
+                    b'*' => x.checked_mul(y),
+                    _ => x.checked_div(y),
+                },
+                _ => None,
+            };
+            return result.map_or_else(QVariant::default, QVariant::from);
+        }
+        cpp!(unsafe [self as "const QVariant*", other as "const QVariant*", op as "char"] -> QVariant as "QVariant" {
+            return qvariant_arithmetic(*self, *other, op);
+        })
+    }
+}
+
+macro_rules! impl_qvariant_arithmetic {
+    ($($trait:ident, $method:ident, $op:literal;)*) => {$(
+        impl<'a> $trait<&'a QVariant> for &'a QVariant {
+            type Output = QVariant;
+            /// Arithmetic on numeric variants. Both operands are promoted to a 64 bit integer
+            /// if they are integers, and to a double otherwise. The result is an invalid
+            /// variant if one of the operands is not a number, if the integer operation
+            /// overflows, or for an integer division by zero.
+            fn $method(self, other: &'a QVariant) -> QVariant {
+                self.arithmetic(other, $op)
+            }
+        }
+        impl $trait for QVariant {
+            type Output = QVariant;
+            /// Same as the operator on `&QVariant`.
+            fn $method(self, other: QVariant) -> QVariant {
+                self.arithmetic(&other, $op)
+            }
+        }
+    )*};
+}
+impl_qvariant_arithmetic! {
+    Add, add, b'+';
+    Sub, sub, b'-';
+    Mul, mul, b'*';
+    Div, div, b'/';
+}
+
+/// Bindings for the built-in types of the [`QMetaType::Type`][enum] enum, as returned by
+/// `QVariant::type_id()`.
+///
+/// The names are the ones of the former `QVariant::Type` enum. The ids of all the other
+/// types, including the ones registered at run time, are in `Other`.
+///
+/// [enum]: https://doc.qt.io/qt-5/qmetatype.html#Type-enum
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VariantType {
+    Invalid,
+    Bool,
+    Int,
+    UInt,
+    LongLong,
+    ULongLong,
+    Double,
+    Char,
+    Map,
+    List,
+    String,
+    StringList,
+    ByteArray,
+    BitArray,
+    Date,
+    Time,
+    DateTime,
+    Url,
+    Rect,
+    RectF,
+    Size,
+    SizeF,
+    Line,
+    LineF,
+    Point,
+    PointF,
+    Hash,
+    Float,
+    Other(i32),
+}
+impl From<i32> for VariantType {
+    fn from(id: i32) -> Self {
+        match id {
+            0 => VariantType::Invalid,
+            1 => VariantType::Bool,
+            2 => VariantType::Int,
+            3 => VariantType::UInt,
+            4 => VariantType::LongLong,
+            5 => VariantType::ULongLong,
+            6 => VariantType::Double,
+            7 => VariantType::Char,
+            8 => VariantType::Map,
+            9 => VariantType::List,
+            10 => VariantType::String,
+            11 => VariantType::StringList,
+            12 => VariantType::ByteArray,
+            13 => VariantType::BitArray,
+            14 => VariantType::Date,
+            15 => VariantType::Time,
+            16 => VariantType::DateTime,
+            17 => VariantType::Url,
+            19 => VariantType::Rect,
+            20 => VariantType::RectF,
+            21 => VariantType::Size,
+            22 => VariantType::SizeF,
+            23 => VariantType::Line,
+            24 => VariantType::LineF,
+            25 => VariantType::Point,
+            26 => VariantType::PointF,
+            28 => VariantType::Hash,
+            38 => VariantType::Float,
+            id => VariantType::Other(id),
+        }
+    }
+}
+
+/// Error returned by the `TryFrom<QVariant>` implementations when the variant cannot be
+/// converted to the requested type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidConversion;
+impl Display for InvalidConversion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("the QVariant cannot be converted to the requested type")
+    }
+}
+impl std::error::Error for InvalidConversion {}
+
+impl<'a> TryFrom<&'a QVariant> for bool {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toBool()`][method] method, if [`canConvert<bool>()`][can].
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toBool
+    /// [can]: https://doc.qt.io/qt-5/qvariant.html#canConvert-1
+    fn try_from(v: &'a QVariant) -> Result<bool, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> bool as "bool" {
+            ok = v->canConvert<bool>();
+            return v->toBool();
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for i32 {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toInt(bool *ok)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toInt
+    fn try_from(v: &'a QVariant) -> Result<i32, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> i32 as "int" {
+            return v->toInt(&ok);
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for u32 {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toUInt(bool *ok)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toUInt
+    fn try_from(v: &'a QVariant) -> Result<u32, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> u32 as "uint" {
+            return v->toUInt(&ok);
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for i64 {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toLongLong(bool *ok)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toLongLong
+    fn try_from(v: &'a QVariant) -> Result<i64, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> i64 as "qlonglong" {
+            return v->toLongLong(&ok);
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for u64 {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toULongLong(bool *ok)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toULongLong
+    fn try_from(v: &'a QVariant) -> Result<u64, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> u64 as "qulonglong" {
+            return v->toULongLong(&ok);
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for f32 {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toFloat(bool *ok)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toFloat
+    fn try_from(v: &'a QVariant) -> Result<f32, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> f32 as "float" {
+            return v->toFloat(&ok);
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for f64 {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toDouble(bool *ok)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toDouble
+    fn try_from(v: &'a QVariant) -> Result<f64, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> f64 as "double" {
+            return v->toDouble(&ok);
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for QString {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toString()`][method] method, if [`canConvert<QString>()`][can].
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toString
+    /// [can]: https://doc.qt.io/qt-5/qvariant.html#canConvert-1
+    fn try_from(v: &'a QVariant) -> Result<QString, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> QString as "QString" {
+            ok = v->canConvert<QString>();
+            return v->toString();
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for QByteArray {
+    type Error = InvalidConversion;
+    /// Wrapper around [`toByteArray()`][method] method, if [`canConvert<QByteArray>()`][can].
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qvariant.html#toByteArray
+    /// [can]: https://doc.qt.io/qt-5/qvariant.html#canConvert-1
+    fn try_from(v: &'a QVariant) -> Result<QByteArray, InvalidConversion> {
+        let mut ok = false;
+        let r = cpp!(unsafe [v as "const QVariant*", mut ok as "bool"] -> QByteArray as "QByteArray" {
+            ok = v->canConvert<QByteArray>();
+            return v->toByteArray();
+        });
+        if ok {
+            Ok(r)
+        } else {
+            Err(InvalidConversion)
+        }
+    }
+}
+impl<'a> TryFrom<&'a QVariant> for String {
+    type Error = InvalidConversion;
+    /// Same as the conversion to QString.
+    fn try_from(v: &'a QVariant) -> Result<String, InvalidConversion> {
+        QString::try_from(v).map(|s| s.into())
+    }
+}
+
+macro_rules! impl_try_from_qvariant {
+    ($($t:ty),*) => {$(
+        impl TryFrom<QVariant> for $t {
+            type Error = InvalidConversion;
+            /// Same as the conversion from `&QVariant`.
+            fn try_from(v: QVariant) -> Result<$t, InvalidConversion> {
+                <$t>::try_from(&v)
+            }
+        }
+    )*};
+}
+impl_try_from_qvariant!(bool, i32, u32, i64, u64, f32, f64, QString, QByteArray, String);
+
 impl From<QString> for QVariant {
     /// Wrapper around [`QVariant(const QString &)`][ctor] constructor.
     ///
@@ -770,6 +1369,16 @@ impl From<QVariantList> for QVariant {
         })
     }
 }
+impl From<QVariantMap> for QVariant {
+    /// Wrapper around [`QVariant(const QVariantMap &)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qvariant.html#QVariant-22
+    fn from(a: QVariantMap) -> QVariant {
+        cpp!(unsafe [a as "QVariantMap"] -> QVariant as "QVariant" {
+            return QVariant(a);
+        })
+    }
+}
 impl From<i32> for QVariant {
     /// Wrapper around [`QVariant(int)`][ctor] constructor.
     ///
@@ -973,37 +1582,246 @@ where
     }
 }
 
+cpp_class!(
+    /// Wrapper around [`QVariantMap`][type] typedef.
+    ///
+    /// [type]: https://doc.qt.io/qt-5/qvariant.html#QVariantMap-typedef
+    #[derive(PartialEq)]
+    pub unsafe struct QVariantMap as "QVariantMap"
+);
+impl QVariantMap {
+    /// Wrapper around [`insert(const Key &, const T &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#insert
+    pub fn insert(&mut self, key: QString, value: QVariant) {
+        cpp!(unsafe [self as "QVariantMap*", key as "QString", value as "QVariant"] {
+            self->insert(key, std::move(value));
+        })
+    }
+
+    /// Wrapper around [`value(const Key &)`][method] method.
+    ///
+    /// # Wrapper-specific
+    ///
+    /// Returns `None` if the map does not contain the key.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#value
+    pub fn value(&self, key: QString) -> Option<QVariant> {
+        if !self.contains(key.clone()) {
+            return None;
+        }
+        Some(
+            cpp!(unsafe [self as "const QVariantMap*", key as "QString"] -> QVariant as "QVariant" {
+                return self->value(key);
+            }),
+        )
+    }
+
+    /// Wrapper around [`take(const Key &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#take
+    pub fn remove(&mut self, key: QString) -> Option<QVariant> {
+        if !self.contains(key.clone()) {
+            return None;
+        }
+        Some(cpp!(unsafe [self as "QVariantMap*", key as "QString"] -> QVariant as "QVariant" {
+            return self->take(key);
+        }))
+    }
+
+    /// Wrapper around [`contains(const Key &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#contains
+    pub fn contains(&self, key: QString) -> bool {
+        cpp!(unsafe [self as "const QVariantMap*", key as "QString"] -> bool as "bool" {
+            return self->contains(key);
+        })
+    }
+
+    /// Wrapper around [`keys()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#keys
+    pub fn keys(&self) -> Vec<QString> {
+        let len = self.len();
+        let mut keys = Vec::with_capacity(len);
+        for i in 0..len {
+            keys.push(
+                cpp!(unsafe [self as "const QVariantMap*", i as "size_t"] -> QString as "QString" {
+                    return std::next(self->constBegin(), i).key();
+                }),
+            );
+        }
+        keys
+    }
+
+    /// Wrapper around [`size()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#size
+    pub fn len(&self) -> usize {
+        cpp!(unsafe [self as "const QVariantMap*"] -> usize as "size_t" {
+            return self->size();
+        })
+    }
+
+    /// Wrapper around [`isEmpty()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qmap.html#isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "const QVariantMap*"] -> bool as "bool" {
+            return self->isEmpty();
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
-    fn test_qvariantlist() {
-        let mut q = QVariantList::default();
-        q.push(42.into());
-        q.push(QString::from("Hello").into());
-        q.push(QByteArray::from("Hello").into());
-        assert_eq!(q[0].to_qbytearray().to_string(), "42");
-        assert_eq!(q[1].to_qbytearray().to_string(), "Hello");
-        assert_eq!(q[2].to_qbytearray().to_string(), "Hello");
-        let x: Vec<QByteArray> = q.into_iter().map(|x| x.to_qbytearray()).collect();
-        assert_eq!(x[0].to_string(), "42");
-        assert_eq!(x[1].to_string(), "Hello");
-        assert_eq!(x[2].to_string(), "Hello");
+    fn test_qvariant_fmt() {
+        let v = QVariant::from(QString::from("foo"));
+        assert_eq!(v, QVariant::from(QString::from("foo")));
+        assert_eq!(v.to_string(), "foo");
+        assert_eq!(format!("{:?}", v), "QVariant(type=QString, value=foo)");
+        assert_eq!(format!("{:?}", QVariant::from(42)), "QVariant(type=int, value=42)");
+        assert_eq!(QVariant::default().type_name(), "");
     }
 
     #[test]
-    fn test_qvariantlist_from_iter() {
-        let v = vec![1u32, 2u32, 3u32];
-        let qvl: QVariantList = v.iter().collect();
-        assert_eq!(qvl.len(), 3);
-        assert_eq!(qvl[1].to_qbytearray().to_string(), "2");
+    fn test_qvariant_type() {
+        let v = QVariant::from(QString::from("foo"));
+        assert_eq!(VariantType::from(v.type_id()), VariantType::String);
+        assert_eq!(v.type_name(), "QString");
+        assert_eq!(VariantType::from(QVariant::from(1.5).type_id()), VariantType::Double);
+        assert_eq!(VariantType::from(QVariant::from(true).type_id()), VariantType::Bool);
+        assert_eq!(VariantType::from(QVariant::default().type_id()), VariantType::Invalid);
+        assert_eq!(QVariant::default().type_name(), "");
+        assert_eq!(VariantType::from(1234), VariantType::Other(1234));
     }
 
     #[test]
-    fn test_qstring_and_qbytearray() {
-        let qba1: QByteArray = (b"hello" as &[u8]).into();
-        let qba2: QByteArray = "hello".into();
-        let s: String = "hello".into();
+    fn test_qvariant_null_valid() {
+        assert!(!QVariant::default().is_valid());
+        assert!(QVariant::default().is_null());
+        assert!(QVariant::null().is_valid());
+        let null_string = QVariant::from(QString::default());
+        assert!(null_string.is_valid());
+        assert!(null_string.is_null());
+        let v = QVariant::from(QString::from("foo"));
+        assert!(v.is_valid());
+        assert!(!v.is_null());
+    }
+
+    #[test]
+    fn test_qvariant_arithmetic() {
+        let sum = QVariant::from(40) + QVariant::from(2u32);
+        assert_eq!(sum.to_i64(), Some(42));
+        assert_eq!(VariantType::from(sum.type_id()), VariantType::LongLong);
+        let product = &QVariant::from(1.5) * &QVariant::from(2);
+        assert_eq!(product.to_f64(), Some(3.0));
+        assert_eq!(VariantType::from(product.type_id()), VariantType::Double);
+        assert_eq!((QVariant::from(7) - QVariant::from(10)).to_i64(), Some(-3));
+        assert_eq!((QVariant::from(7) / QVariant::from(2)).to_i64(), Some(3));
+        assert_eq!((QVariant::from(7.) / QVariant::from(2)).to_f64(), Some(3.5));
+        assert!(!(QVariant::from(7) / QVariant::from(0)).is_valid());
+        assert!(!(QVariant::from(i64::MAX) + QVariant::from(1)).is_valid());
+        assert!(!(QVariant::from(i64::MIN) - QVariant::from(1)).is_valid());
+        assert!(!(QVariant::from(i64::MAX) * QVariant::from(2)).is_valid());
+        assert!(!(QVariant::from(i64::MIN) / QVariant::from(-1)).is_valid());
+        assert!(!(QVariant::from(QString::from("1")) + QVariant::from(1)).is_valid());
+        assert!(!(QVariant::default() + QVariant::from(1)).is_valid());
+        assert_eq!(QVariant::from(QString::from("foo")).to_f64(), None);
+    }
+
+    #[test]
+    fn test_qbytearray_bytes() {
+        let mut a = QByteArray::with_capacity(16);
+        assert_eq!(a.as_bytes(), b"");
+        a.resize(4);
+        assert_eq!(a.as_bytes(), &[0, 0, 0, 0]);
+        a.as_bytes_mut().copy_from_slice(b"abcd");
+        let b = a.clone();
+        a.as_bytes_mut()[0] = b'x';
+        assert_eq!(a.as_bytes(), b"xbcd");
+        assert_eq!(b.as_bytes(), b"abcd");
+        a.resize(2);
+        assert_eq!(a.to_str(), Ok("xb"));
+    }
+
+    #[test]
+    fn test_qbytearray_vec() {
+        let v = vec![0u8, 1, 2, 255];
+        let a = QByteArray::from(&v);
+        assert_eq!(a, QByteArray::from(v.clone()));
+        assert_eq!(a.to_vec(), v);
+        fn len(data: impl AsRef<[u8]>) -> usize {
+            data.as_ref().len()
+        }
+        assert_eq!(len(&a), 4);
+        let v2: Vec<u8> = a.into();
+        assert_eq!(v2, v);
+    }
+
+    #[test]
+    fn test_qstring_path() {
+        use std::path::{Path, PathBuf};
+        let path = Path::new("some/dir/é.txt");
+        let s = QString::from(path);
+        assert_eq!(s, QString::from("some/dir/é.txt"));
+        assert_eq!(QString::from(path.to_path_buf()), s);
+        assert_eq!(PathBuf::try_from(s), Ok(path.to_path_buf()));
+    }
+
+    #[test]
+    fn test_qstring_write() {
+        use std::fmt::Write;
+        let mut s = QString::from("a");
+        write!(s, "{}-{:.2}-{}", 1, 1.5, QString::from("é")).unwrap();
+        assert_eq!(s, QString::from("a1-1.50-é"));
+    }
+
+    #[test]
+    fn test_qvariant_try_from() {
+        assert_eq!(i32::try_from(QVariant::from(42)), Ok(42));
+        assert_eq!(u64::try_from(&QVariant::from(42u64)), Ok(42));
+        assert_eq!(f64::try_from(QVariant::from(1.5)), Ok(1.5));
+        assert_eq!(bool::try_from(QVariant::from(true)), Ok(true));
+        assert_eq!(String::try_from(QVariant::from(QString::from("foo"))), Ok("foo".into()));
+        let v = QVariant::from(QString::from("12"));
+        assert_eq!(i32::try_from(&v), Ok(12));
+        assert_eq!(QString::try_from(&v), Ok(QString::from("12")));
+        assert_eq!(i32::try_from(QVariant::from(QString::from("foo"))), Err(InvalidConversion));
+        assert_eq!(i32::try_from(QVariant::default()), Err(InvalidConversion));
+        assert!(QByteArray::try_from(QVariant::default()).is_err());
+    }
+
+    #[test]
+    fn test_qvariantlist() {
+        let mut q = QVariantList::default();
+        q.push(42.into());
+        q.push(QString::from("Hello").into());
+        q.push(QByteArray::from("Hello").into());
+        assert_eq!(q[0].to_qbytearray().to_string(), "42");
+        assert_eq!(q[1].to_qbytearray().to_string(), "Hello");
+        assert_eq!(q[2].to_qbytearray().to_string(), "Hello");
+        let x: Vec<QByteArray> = q.into_iter().map(|x| x.to_qbytearray()).collect();
+        assert_eq!(x[0].to_string(), "42");
+        assert_eq!(x[1].to_string(), "Hello");
+        assert_eq!(x[2].to_string(), "Hello");
+    }
+
+    #[test]
+    fn test_qvariantlist_from_iter() {
+        let v = vec![1u32, 2u32, 3u32];
+        let qvl: QVariantList = v.iter().collect();
+        assert_eq!(qvl.len(), 3);
+        assert_eq!(qvl[1].to_qbytearray().to_string(), "2");
+    }
+
+    #[test]
+    fn test_qstring_and_qbytearray() {
+        let qba1: QByteArray = (b"hello" as &[u8]).into();
+        let qba2: QByteArray = "hello".into();
+        let s: String = "hello".into();
         let qba3: QByteArray = s.clone().into();
 
         assert_eq!(qba1.to_string(), "hello");
@@ -1018,6 +1836,124 @@ mod tests {
         assert_eq!(qs2.to_string(), "hello");
         assert_eq!(qba4.to_string(), "hello");
     }
+
+    #[test]
+    fn test_qvariantmap() {
+        let mut map = QVariantMap::default();
+        assert!(map.is_empty());
+        map.insert("b".into(), 42.into());
+        map.insert("a".into(), QString::from("Hello").into());
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.keys(), vec![QString::from("a"), QString::from("b")]);
+        assert_eq!(map.value("b".into()).unwrap().to_qbytearray().to_string(), "42");
+        assert!(map.value("c".into()).is_none());
+        assert_eq!(map.remove("a".into()).unwrap().to_qbytearray().to_string(), "Hello");
+        assert!(!map.contains("a".into()));
+    }
+
+    #[test]
+    fn test_qjsonobject() {
+        let mut obj = QJsonObject::default();
+        assert!(obj.is_empty());
+        obj.insert("name", QString::from("qt").into());
+        obj.insert("answer", 42.into());
+        assert_eq!(obj.len(), 2);
+        assert!(obj.contains("name"));
+        assert!(!obj.contains("missing"));
+        assert_eq!(obj.value("answer").to_qbytearray().to_string(), "42");
+        assert_eq!(obj.keys(), vec![QString::from("answer"), QString::from("name")]);
+        assert_eq!(obj.to_json().to_string(), r#"{"answer":42,"name":"qt"}"#);
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QJsonObject`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qjsonobject.html
+    #[derive(PartialEq, Eq)]
+    pub unsafe struct QJsonObject as "QJsonObject"
+);
+impl QJsonObject {
+    /// Wrapper around [`size()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsonobject.html#size
+    pub fn len(&self) -> usize {
+        cpp!(unsafe [self as "const QJsonObject*"] -> usize as "size_t" {
+            return self->size();
+        })
+    }
+
+    /// Wrapper around [`isEmpty()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsonobject.html#isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "const QJsonObject*"] -> bool as "bool" {
+            return self->isEmpty();
+        })
+    }
+
+    /// Wrapper around [`contains(const QString &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsonobject.html#contains
+    pub fn contains(&self, key: &str) -> bool {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "const QJsonObject*", key as "QString"] -> bool as "bool" {
+            return self->contains(key);
+        })
+    }
+
+    /// Wrapper around [`value(const QString &)`][method] method.
+    ///
+    /// # Wrapper-specific
+    ///
+    /// The `QJsonValue` is converted to a `QVariant`. Missing keys give an invalid `QVariant`.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsonobject.html#value
+    pub fn value(&self, key: &str) -> QVariant {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "const QJsonObject*", key as "QString"] -> QVariant as "QVariant" {
+            return self->value(key).toVariant();
+        })
+    }
+
+    /// Wrapper around [`insert(const QString &, const QJsonValue &)`][method] method.
+    ///
+    /// # Wrapper-specific
+    ///
+    /// The value is converted with `QJsonValue::fromVariant`.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsonobject.html#insert
+    pub fn insert(&mut self, key: &str, value: QVariant) {
+        let key = QString::from(key);
+        cpp!(unsafe [self as "QJsonObject*", key as "QString", value as "QVariant"] {
+            self->insert(key, QJsonValue::fromVariant(value));
+        })
+    }
+
+    /// Wrapper around [`keys()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsonobject.html#keys
+    pub fn keys(&self) -> Vec<QString> {
+        let len = self.len();
+        let mut keys = Vec::with_capacity(len);
+        for i in 0..len {
+            keys.push(
+                cpp!(unsafe [self as "const QJsonObject*", i as "size_t"] -> QString as "QString" {
+                    return (self->constBegin() + i).key();
+                }),
+            );
+        }
+        keys
+    }
+
+    /// Serialize the object to compact JSON text using [`QJsonDocument::toJson`][method].
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qjsondocument.html#toJson
+    pub fn to_json(&self) -> QByteArray {
+        cpp!(unsafe [self as "const QJsonObject*"] -> QByteArray as "QByteArray" {
+            return QJsonDocument(*self).toJson(QJsonDocument::Compact);
+        })
+    }
 }
 
 cpp_class!(
@@ -1057,6 +1993,69 @@ impl QModelIndex {
     }
 }
 
+cpp_class!(
+    /// Wrapper around [`QItemSelection`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qitemselection.html
+    #[derive(PartialEq)]
+    pub unsafe struct QItemSelection as "QItemSelection"
+);
+cpp_class!(unsafe struct QModelIndexList as "QModelIndexList");
+
+impl QItemSelection {
+    /// Wrapper around [`select(const QModelIndex &, const QModelIndex &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qitemselection.html#select
+    pub fn select(&mut self, top_left: &QModelIndex, bottom_right: &QModelIndex) {
+        cpp!(unsafe [self as "QItemSelection*", top_left as "const QModelIndex*", bottom_right as "const QModelIndex*"] {
+            self->select(*top_left, *bottom_right);
+        })
+    }
+
+    /// Wrapper around [`contains(const QModelIndex &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qitemselection.html#contains
+    pub fn contains(&self, index: &QModelIndex) -> bool {
+        cpp!(unsafe [self as "const QItemSelection*", index as "const QModelIndex*"] -> bool as "bool" {
+            return self->contains(*index);
+        })
+    }
+
+    /// Wrapper around [`indexes()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qitemselection.html#indexes
+    pub fn indexes(&self) -> Vec<QModelIndex> {
+        let list = cpp!(unsafe [self as "const QItemSelection*"] -> QModelIndexList as "QModelIndexList" {
+            return self->indexes();
+        });
+        let len =
+            cpp!(unsafe [list as "QModelIndexList"] -> usize as "size_t" { return list.size(); });
+        let mut indexes = Vec::with_capacity(len);
+        for i in 0..len {
+            indexes.push(cpp!(unsafe [list as "QModelIndexList", i as "size_t"] -> QModelIndex as "QModelIndex" {
+                return list.at(i);
+            }));
+        }
+        indexes
+    }
+
+    /// Returns the number of selection ranges.
+    ///
+    /// Wrapper around [`size()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qlist.html#size
+    pub fn len(&self) -> usize {
+        cpp!(unsafe [self as "const QItemSelection*"] -> usize as "size_t" { return self->size(); })
+    }
+
+    /// Wrapper around [`isEmpty()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qlist.html#isEmpty
+    pub fn is_empty(&self) -> bool {
+        cpp!(unsafe [self as "const QItemSelection*"] -> bool as "bool" { return self->isEmpty(); })
+    }
+}
+
 /// Bindings for [`qreal`][type] typedef.
 ///
 /// [type]: https://doc.qt.io/qt-5/qtglobal.html#qreal-typedef
@@ -1141,6 +2140,26 @@ pub struct QSizeF {
     pub height: qreal,
 }
 
+/// Bindings for [`QLineF`][class] class.
+///
+/// [class]: https://doc.qt.io/qt-5/qlinef.html
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct QLineF {
+    pub p1: QPointF,
+    pub p2: QPointF,
+}
+impl QLineF {
+    /// Wrapper around [`length()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qlinef.html#length
+    pub fn length(&self) -> qreal {
+        cpp!(unsafe [self as "const QLineF*"] -> qreal as "qreal" {
+            return self->length();
+        })
+    }
+}
+
 #[test]
 fn test_qpointf_qrectf() {
     let rect = QRectF { x: 200., y: 150., width: 60., height: 75. };
@@ -1234,6 +2253,92 @@ fn test_qcolor() {
     assert!(blue1 != red1);
 }
 
+cpp_class!(
+    /// Wrapper around [`QBrush`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qbrush.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QBrush as "QBrush"
+);
+impl QBrush {
+    /// Wrapper around [`color()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qbrush.html#color
+    pub fn color(&self) -> QColor {
+        cpp!(unsafe [self as "const QBrush*"] -> QColor as "QColor" {
+            return self->color();
+        })
+    }
+}
+impl From<QColor> for QBrush {
+    /// Wrapper around [`QBrush(const QColor &, Qt::BrushStyle)`][ctor] constructor, with a
+    /// solid pattern.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qbrush.html#QBrush-3
+    fn from(color: QColor) -> Self {
+        cpp!(unsafe [color as "QColor"] -> QBrush as "QBrush" {
+            return QBrush(color);
+        })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QPen`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qpen.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QPen as "QPen"
+);
+impl QPen {
+    /// Wrapper around [`QPen(const QBrush &, qreal width, ...)`][ctor] constructor, with a
+    /// solid line.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qpen.html#QPen-3
+    pub fn new(color: QColor, width: qreal) -> Self {
+        cpp!(unsafe [color as "QColor", width as "qreal"] -> QPen as "QPen" {
+            return QPen(QBrush(color), width);
+        })
+    }
+
+    /// Wrapper around [`color()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpen.html#color
+    pub fn color(&self) -> QColor {
+        cpp!(unsafe [self as "const QPen*"] -> QColor as "QColor" {
+            return self->color();
+        })
+    }
+
+    /// Wrapper around [`widthF()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qpen.html#widthF
+    pub fn width_f(&self) -> qreal {
+        cpp!(unsafe [self as "const QPen*"] -> qreal as "qreal" {
+            return self->widthF();
+        })
+    }
+}
+impl From<QColor> for QPen {
+    /// Wrapper around [`QPen(const QColor &)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qpen.html#QPen-2
+    fn from(color: QColor) -> Self {
+        cpp!(unsafe [color as "QColor"] -> QPen as "QPen" {
+            return QPen(color);
+        })
+    }
+}
+
+#[test]
+fn test_qpen_qbrush() {
+    let red = QColor::from_name("red");
+    let pen = QPen::new(red, 2.5);
+    assert!(pen.color() == red);
+    assert_eq!(pen.width_f(), 2.5);
+    assert!(QPen::from(red) != pen);
+    assert!(QBrush::from(red).color() == red);
+}
+
 /// Bindings for [`QSize`][class] class.
 ///
 /// [class]: https://doc.qt.io/qt-5/qsize.html
@@ -1266,6 +2371,331 @@ pub struct QMargins {
     pub bottom: i32,
 }
 
+cpp_class!(
+    /// Wrapper around [`QRect`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qrect.html
+    #[derive(Default, Clone, Copy, PartialEq)]
+    pub unsafe struct QRect as "QRect"
+);
+impl QRect {
+    /// Wrapper around [`QRect(int x, int y, int width, int height)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qrect.html#QRect-3
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> QRect {
+        cpp!(unsafe [x as "int", y as "int", width as "int", height as "int"] -> QRect as "QRect" {
+            return QRect(x, y, width, height);
+        })
+    }
+
+    /// Wrapper around [`x()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qrect.html#x
+    pub fn x(&self) -> i32 {
+        cpp!(unsafe [self as "const QRect*"] -> i32 as "int" { return self->x(); })
+    }
+
+    /// Wrapper around [`y()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qrect.html#y
+    pub fn y(&self) -> i32 {
+        cpp!(unsafe [self as "const QRect*"] -> i32 as "int" { return self->y(); })
+    }
+
+    /// Wrapper around [`width()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qrect.html#width
+    pub fn width(&self) -> i32 {
+        cpp!(unsafe [self as "const QRect*"] -> i32 as "int" { return self->width(); })
+    }
+
+    /// Wrapper around [`height()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qrect.html#height
+    pub fn height(&self) -> i32 {
+        cpp!(unsafe [self as "const QRect*"] -> i32 as "int" { return self->height(); })
+    }
+
+    /// Wrapper around [`isValid()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qrect.html#isValid
+    pub fn is_valid(&self) -> bool {
+        cpp!(unsafe [self as "const QRect*"] -> bool as "bool" { return self->isValid(); })
+    }
+}
+impl std::fmt::Debug for QRect {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("QRect")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .finish()
+    }
+}
+
+#[test]
+fn test_qrect() {
+    let rect = QRect::new(10, 20, 30, 40);
+    assert_eq!((rect.x(), rect.y(), rect.width(), rect.height()), (10, 20, 30, 40));
+    assert!(rect.is_valid());
+    assert!(!QRect::default().is_valid());
+    assert_eq!(rect, QRect::new(10, 20, 30, 40));
+}
+
+cpp_class!(
+    /// Wrapper around [`QFont`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfont.html
+    #[derive(Default, Clone, PartialEq)]
+    pub unsafe struct QFont as "QFont"
+);
+impl QFont {
+    /// Wrapper around [`QFont(const QString &family, int pointSize)`][ctor] constructor.
+    ///
+    /// A negative `point_size` keeps the default size.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qfont.html#QFont-1
+    pub fn new(family: &str, point_size: i32) -> QFont {
+        let family = QString::from(family);
+        cpp!(unsafe [family as "QString", point_size as "int"] -> QFont as "QFont" {
+            return QFont(family, point_size);
+        })
+    }
+
+    /// Wrapper around [`family()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#family
+    pub fn family(&self) -> QString {
+        cpp!(unsafe [self as "const QFont*"] -> QString as "QString" { return self->family(); })
+    }
+
+    /// Wrapper around [`pointSize()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#pointSize
+    pub fn point_size(&self) -> i32 {
+        cpp!(unsafe [self as "const QFont*"] -> i32 as "int" { return self->pointSize(); })
+    }
+
+    /// Wrapper around [`setPointSize(int)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setPointSize
+    pub fn set_point_size(&mut self, point_size: i32) {
+        cpp!(unsafe [self as "QFont*", point_size as "int"] { self->setPointSize(point_size); })
+    }
+
+    /// Wrapper around [`pixelSize()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#pixelSize
+    pub fn pixel_size(&self) -> i32 {
+        cpp!(unsafe [self as "const QFont*"] -> i32 as "int" { return self->pixelSize(); })
+    }
+
+    /// Wrapper around [`setPixelSize(int)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setPixelSize
+    pub fn set_pixel_size(&mut self, pixel_size: i32) {
+        cpp!(unsafe [self as "QFont*", pixel_size as "int"] { self->setPixelSize(pixel_size); })
+    }
+
+    /// Wrapper around [`bold()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#bold
+    pub fn bold(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->bold(); })
+    }
+
+    /// Wrapper around [`setBold(bool)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setBold
+    pub fn set_bold(&mut self, bold: bool) {
+        cpp!(unsafe [self as "QFont*", bold as "bool"] { self->setBold(bold); })
+    }
+
+    /// Wrapper around [`italic()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#italic
+    pub fn italic(&self) -> bool {
+        cpp!(unsafe [self as "const QFont*"] -> bool as "bool" { return self->italic(); })
+    }
+
+    /// Wrapper around [`setItalic(bool)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfont.html#setItalic
+    pub fn set_italic(&mut self, italic: bool) {
+        cpp!(unsafe [self as "QFont*", italic as "bool"] { self->setItalic(italic); })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QFontMetrics`][class] class, to measure text in integer pixels.
+    ///
+    /// A `QGuiApplication` must exist.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfontmetrics.html
+    #[derive(Clone)]
+    pub unsafe struct QFontMetrics as "QFontMetrics"
+);
+impl QFontMetrics {
+    /// Wrapper around [`QFontMetrics(const QFont &)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qfontmetrics.html#QFontMetrics
+    pub fn from_font(font: &QFont) -> QFontMetrics {
+        cpp!(unsafe [font as "const QFont*"] -> QFontMetrics as "QFontMetrics" {
+            return QFontMetrics(*font);
+        })
+    }
+
+    /// Wrapper around [`boundingRect(const QString &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#boundingRect-1
+    pub fn bounding_rect(&self, text: &str) -> QRect {
+        let text = QString::from(text);
+        cpp!(unsafe [self as "const QFontMetrics*", text as "QString"] -> QRect as "QRect" {
+            return self->boundingRect(text);
+        })
+    }
+
+    /// Wrapper around [`horizontalAdvance(const QString &)`][method] method.
+    ///
+    /// Before Qt 5.11, this calls `width(const QString &)`.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#horizontalAdvance
+    pub fn horizontal_advance(&self, text: &str) -> i32 {
+        let text = QString::from(text);
+        cpp!(unsafe [self as "const QFontMetrics*", text as "QString"] -> i32 as "int" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 11, 0)
+            return self->horizontalAdvance(text);
+        #else
+            return self->width(text);
+        #endif
+        })
+    }
+
+    /// Wrapper around [`height()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#height
+    pub fn height(&self) -> i32 {
+        cpp!(unsafe [self as "const QFontMetrics*"] -> i32 as "int" { return self->height(); })
+    }
+
+    /// Wrapper around [`ascent()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#ascent
+    pub fn ascent(&self) -> i32 {
+        cpp!(unsafe [self as "const QFontMetrics*"] -> i32 as "int" { return self->ascent(); })
+    }
+
+    /// Wrapper around [`descent()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#descent
+    pub fn descent(&self) -> i32 {
+        cpp!(unsafe [self as "const QFontMetrics*"] -> i32 as "int" { return self->descent(); })
+    }
+
+    /// Wrapper around [`averageCharWidth()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#averageCharWidth
+    pub fn average_char_width(&self) -> i32 {
+        cpp!(unsafe [self as "const QFontMetrics*"] -> i32 as "int" {
+            return self->averageCharWidth();
+        })
+    }
+
+    /// Wrapper around [`lineSpacing()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetrics.html#lineSpacing
+    pub fn line_spacing(&self) -> i32 {
+        cpp!(unsafe [self as "const QFontMetrics*"] -> i32 as "int" { return self->lineSpacing(); })
+    }
+}
+
+cpp_class!(
+    /// Wrapper around [`QFontMetricsF`][class] class, to measure text with floating point
+    /// precision.
+    ///
+    /// A `QGuiApplication` must exist.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qfontmetricsf.html
+    #[derive(Clone)]
+    pub unsafe struct QFontMetricsF as "QFontMetricsF"
+);
+impl QFontMetricsF {
+    /// Wrapper around [`QFontMetricsF(const QFont &)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qfontmetricsf.html#QFontMetricsF
+    pub fn from_font(font: &QFont) -> QFontMetricsF {
+        cpp!(unsafe [font as "const QFont*"] -> QFontMetricsF as "QFontMetricsF" {
+            return QFontMetricsF(*font);
+        })
+    }
+
+    /// Wrapper around [`boundingRect(const QString &)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#boundingRect
+    pub fn bounding_rect(&self, text: &str) -> QRectF {
+        let text = QString::from(text);
+        cpp!(unsafe [self as "const QFontMetricsF*", text as "QString"] -> QRectF as "QRectF" {
+            return self->boundingRect(text);
+        })
+    }
+
+    /// Wrapper around [`horizontalAdvance(const QString &)`][method] method.
+    ///
+    /// Before Qt 5.11, this calls `width(const QString &)`.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#horizontalAdvance
+    pub fn horizontal_advance(&self, text: &str) -> qreal {
+        let text = QString::from(text);
+        cpp!(unsafe [self as "const QFontMetricsF*", text as "QString"] -> qreal as "qreal" {
+        #if QT_VERSION >= QT_VERSION_CHECK(5, 11, 0)
+            return self->horizontalAdvance(text);
+        #else
+            return self->width(text);
+        #endif
+        })
+    }
+
+    /// Wrapper around [`height()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#height
+    pub fn height(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" { return self->height(); })
+    }
+
+    /// Wrapper around [`ascent()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#ascent
+    pub fn ascent(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" { return self->ascent(); })
+    }
+
+    /// Wrapper around [`descent()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#descent
+    pub fn descent(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" { return self->descent(); })
+    }
+
+    /// Wrapper around [`averageCharWidth()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#averageCharWidth
+    pub fn average_char_width(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" {
+            return self->averageCharWidth();
+        })
+    }
+
+    /// Wrapper around [`lineSpacing()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qfontmetricsf.html#lineSpacing
+    pub fn line_spacing(&self) -> qreal {
+        cpp!(unsafe [self as "const QFontMetricsF*"] -> qreal as "qreal" {
+            return self->lineSpacing();
+        })
+    }
+}
+
 /// Bindings for [`QImage::Format`][class] enum class.
 ///
 /// [class]: https://doc.qt.io/qt-5/qimage.html#Format-enum
@@ -1397,3 +2827,370 @@ impl From<QImage> for QPixmap {
         cpp!(unsafe [image as "QImage"] -> QPixmap as "QPixmap" { return QPixmap::fromImage(image); })
     }
 }
+
+cpp_class!(
+    /// Wrapper around [`QIcon`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qicon.html
+    #[derive(Default, Clone)]
+    pub unsafe struct QIcon as "QIcon"
+);
+
+impl QIcon {
+    /// Wrapper around [`QIcon(const QString &fileName)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qicon.html#QIcon-4
+    pub fn from_file(filename: QString) -> Self {
+        cpp!(unsafe [filename as "QString"] -> QIcon as "QIcon" { return QIcon(filename); })
+    }
+
+    /// Wrapper around [`QIcon::fromTheme(const QString &name)`][method] function.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qicon.html#fromTheme
+    pub fn from_theme(name: QString) -> Self {
+        cpp!(unsafe [name as "QString"] -> QIcon as "QIcon" { return QIcon::fromTheme(name); })
+    }
+
+    /// Wrapper around [`isNull()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qicon.html#isNull
+    pub fn is_null(&self) -> bool {
+        cpp!(unsafe [self as "const QIcon*"] -> bool as "bool" { return self->isNull(); })
+    }
+
+    /// Wrapper around [`pixmap(const QSize &size)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qicon.html#pixmap
+    pub fn pixmap(&self, size: QSize) -> QPixmap {
+        cpp!(unsafe [self as "const QIcon*", size as "QSize"] -> QPixmap as "QPixmap" {
+            return self->pixmap(size);
+        })
+    }
+}
+
+impl From<QPixmap> for QIcon {
+    fn from(pixmap: QPixmap) -> Self {
+        cpp!(unsafe [pixmap as "QPixmap"] -> QIcon as "QIcon" { return QIcon(pixmap); })
+    }
+}
+
+/// Bindings for [`QEasingCurve::Type`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qeasingcurve.html#Type-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EasingCurveType {
+    Linear = 0,
+    InQuad = 1,
+    OutQuad = 2,
+    InOutQuad = 3,
+    OutInQuad = 4,
+    InCubic = 5,
+    OutCubic = 6,
+    InOutCubic = 7,
+    OutInCubic = 8,
+    InQuart = 9,
+    OutQuart = 10,
+    InOutQuart = 11,
+    OutInQuart = 12,
+    InQuint = 13,
+    OutQuint = 14,
+    InOutQuint = 15,
+    OutInQuint = 16,
+    InSine = 17,
+    OutSine = 18,
+    InOutSine = 19,
+    OutInSine = 20,
+    InExpo = 21,
+    OutExpo = 22,
+    InOutExpo = 23,
+    OutInExpo = 24,
+    InCirc = 25,
+    OutCirc = 26,
+    InOutCirc = 27,
+    OutInCirc = 28,
+    InElastic = 29,
+    OutElastic = 30,
+    InOutElastic = 31,
+    OutInElastic = 32,
+    InBack = 33,
+    OutBack = 34,
+    InOutBack = 35,
+    OutInBack = 36,
+    InBounce = 37,
+    OutBounce = 38,
+    InOutBounce = 39,
+    OutInBounce = 40,
+    InCurve = 41,
+    OutCurve = 42,
+    SineCurve = 43,
+    CosineCurve = 44,
+    BezierSpline = 45,
+    TCBSpline = 46,
+    Custom = 47,
+}
+
+cpp_class!(
+    /// Wrapper around [`QEasingCurve`][class] class.
+    ///
+    /// [class]: https://doc.qt.io/qt-5/qeasingcurve.html
+    #[derive(PartialEq)]
+    pub unsafe struct QEasingCurve as "QEasingCurve"
+);
+impl QEasingCurve {
+    /// Wrapper around [`QEasingCurve(QEasingCurve::Type)`][ctor] constructor.
+    ///
+    /// [ctor]: https://doc.qt.io/qt-5/qeasingcurve.html#QEasingCurve
+    pub fn new(curve_type: EasingCurveType) -> Self {
+        cpp!(unsafe [curve_type as "QEasingCurve::Type"] -> QEasingCurve as "QEasingCurve" {
+            return QEasingCurve(curve_type);
+        })
+    }
+
+    /// Wrapper around [`type()`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qeasingcurve.html#type
+    pub fn curve_type(&self) -> EasingCurveType {
+        cpp!(unsafe [self as "const QEasingCurve*"] -> EasingCurveType as "QEasingCurve::Type" {
+            return self->type();
+        })
+    }
+
+    /// Wrapper around [`valueForProgress(qreal)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qeasingcurve.html#valueForProgress
+    pub fn value_for_progress(&self, progress: qreal) -> qreal {
+        cpp!(unsafe [self as "const QEasingCurve*", progress as "qreal"] -> qreal as "qreal" {
+            return self->valueForProgress(progress);
+        })
+    }
+
+    /// Wrapper around [`setAmplitude(qreal)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qeasingcurve.html#setAmplitude
+    pub fn set_amplitude(&mut self, amplitude: qreal) {
+        cpp!(unsafe [self as "QEasingCurve*", amplitude as "qreal"] {
+            self->setAmplitude(amplitude);
+        })
+    }
+
+    /// Wrapper around [`setOvershoot(qreal)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qeasingcurve.html#setOvershoot
+    pub fn set_overshoot(&mut self, overshoot: qreal) {
+        cpp!(unsafe [self as "QEasingCurve*", overshoot as "qreal"] {
+            self->setOvershoot(overshoot);
+        })
+    }
+
+    /// Wrapper around [`setPeriod(qreal)`][method] method.
+    ///
+    /// [method]: https://doc.qt.io/qt-5/qeasingcurve.html#setPeriod
+    pub fn set_period(&mut self, period: qreal) {
+        cpp!(unsafe [self as "QEasingCurve*", period as "qreal"] {
+            self->setPeriod(period);
+        })
+    }
+}
+impl From<EasingCurveType> for QEasingCurve {
+    fn from(curve_type: EasingCurveType) -> Self {
+        QEasingCurve::new(curve_type)
+    }
+}
+
+/// Bindings for [`Qt::Orientation`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#Orientation-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    Horizontal = 1,
+    Vertical = 2,
+}
+
+/// Bindings for [`Qt::ScrollBarPolicy`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#ScrollBarPolicy-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScrollBarPolicy {
+    ScrollBarAsNeeded = 0,
+    ScrollBarAlwaysOff = 1,
+    ScrollBarAlwaysOn = 2,
+}
+
+/// Bindings for [`Qt::CaseSensitivity`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#CaseSensitivity-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CaseSensitivity {
+    CaseInsensitive = 0,
+    CaseSensitive = 1,
+}
+
+/// Bindings for [`Qt::CheckState`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#CheckState-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CheckState {
+    Unchecked = 0,
+    PartiallyChecked = 1,
+    Checked = 2,
+}
+
+/// Bindings for [`Qt::MatchFlags`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qt.html#MatchFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct MatchFlags(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl MatchFlags {
+    pub const MatchExactly: MatchFlags = MatchFlags(0);
+    pub const MatchContains: MatchFlags = MatchFlags(1);
+    pub const MatchStartsWith: MatchFlags = MatchFlags(2);
+    pub const MatchEndsWith: MatchFlags = MatchFlags(3);
+    pub const MatchWildcard: MatchFlags = MatchFlags(5);
+    pub const MatchFixedString: MatchFlags = MatchFlags(8);
+    pub const MatchCaseSensitive: MatchFlags = MatchFlags(16);
+    pub const MatchWrap: MatchFlags = MatchFlags(32);
+    pub const MatchRecursive: MatchFlags = MatchFlags(64);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: MatchFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for MatchFlags {
+    type Output = MatchFlags;
+    fn bitor(self, other: MatchFlags) -> MatchFlags {
+        MatchFlags(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for MatchFlags {
+    fn bitor_assign(&mut self, other: MatchFlags) {
+        self.0 |= other.0;
+    }
+}
+
+/// Bindings for [`Qt::Alignment`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qt.html#AlignmentFlag-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Alignment(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl Alignment {
+    pub const AlignLeft: Alignment = Alignment(0x0001);
+    pub const AlignRight: Alignment = Alignment(0x0002);
+    pub const AlignHCenter: Alignment = Alignment(0x0004);
+    pub const AlignJustify: Alignment = Alignment(0x0008);
+    pub const AlignAbsolute: Alignment = Alignment(0x0010);
+    pub const AlignTop: Alignment = Alignment(0x0020);
+    pub const AlignBottom: Alignment = Alignment(0x0040);
+    pub const AlignVCenter: Alignment = Alignment(0x0080);
+    pub const AlignBaseline: Alignment = Alignment(0x0100);
+    pub const AlignCenter: Alignment = Alignment(0x0084);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: Alignment) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Alignment {
+    type Output = Alignment;
+    fn bitor(self, other: Alignment) -> Alignment {
+        Alignment(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Alignment {
+    fn bitor_assign(&mut self, other: Alignment) {
+        self.0 |= other.0;
+    }
+}
+
+/// Bindings for [`Qt::DockWidgetArea`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#DockWidgetArea-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DockWidgetArea {
+    NoDockWidgetArea = 0,
+    LeftDockWidgetArea = 0x1,
+    RightDockWidgetArea = 0x2,
+    TopDockWidgetArea = 0x4,
+    BottomDockWidgetArea = 0x8,
+}
+
+/// Bindings for [`Qt::DockWidgetAreas`][flags] flags.
+///
+/// [flags]: https://doc.qt.io/qt-5/qt.html#DockWidgetArea-enum
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct DockWidgetAreas(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl DockWidgetAreas {
+    pub const NoDockWidgetArea: DockWidgetAreas = DockWidgetAreas(0);
+    pub const LeftDockWidgetArea: DockWidgetAreas = DockWidgetAreas(0x1);
+    pub const RightDockWidgetArea: DockWidgetAreas = DockWidgetAreas(0x2);
+    pub const TopDockWidgetArea: DockWidgetAreas = DockWidgetAreas(0x4);
+    pub const BottomDockWidgetArea: DockWidgetAreas = DockWidgetAreas(0x8);
+    pub const AllDockWidgetAreas: DockWidgetAreas = DockWidgetAreas(0xf);
+
+    /// Returns true if all the flags in `other` are set
+    pub fn contains(self, other: DockWidgetAreas) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<DockWidgetArea> for DockWidgetAreas {
+    fn from(area: DockWidgetArea) -> Self {
+        DockWidgetAreas(area as u32)
+    }
+}
+
+impl BitOr for DockWidgetAreas {
+    type Output = DockWidgetAreas;
+    fn bitor(self, other: DockWidgetAreas) -> DockWidgetAreas {
+        DockWidgetAreas(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for DockWidgetAreas {
+    fn bitor_assign(&mut self, other: DockWidgetAreas) {
+        self.0 |= other.0;
+    }
+}
+
+/// Bindings for [`Qt::ToolButtonStyle`][enum] enum.
+///
+/// [enum]: https://doc.qt.io/qt-5/qt.html#ToolButtonStyle-enum
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ToolButtonStyle {
+    ToolButtonIconOnly = 0,
+    ToolButtonTextOnly = 1,
+    ToolButtonTextBesideIcon = 2,
+    ToolButtonTextUnderIcon = 3,
+    ToolButtonFollowStyle = 4,
+}
+
+#[test]
+fn test_qeasingcurve() {
+    let linear = QEasingCurve::default();
+    assert_eq!(linear.curve_type(), EasingCurveType::Linear);
+    assert_eq!(linear.value_for_progress(0.25), 0.25);
+    let curve: QEasingCurve = EasingCurveType::InQuad.into();
+    assert_eq!(curve.curve_type(), EasingCurveType::InQuad);
+    assert_eq!(curve.value_for_progress(0.5), 0.25);
+    assert!(curve != linear);
+}
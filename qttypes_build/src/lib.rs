@@ -0,0 +1,264 @@
+/* Copyright (C) 2018 Olivier Goffart <ogoffart@woboq.com>
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+associated documentation files (the "Software"), to deal in the Software without restriction,
+including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense,
+and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial
+portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT
+NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES
+OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+//! Locate the Qt installation from a build script.
+//!
+//! This is the detection used by the build script of the `qttypes` crate. Crates which depend
+//! on `qttypes` should rather use the `DEP_QT_VERSION`, `DEP_QT_INCLUDE_PATH` and
+//! `DEP_QT_LIBRARY_PATH` variables it exposes. This crate is for build scripts which need to
+//! find Qt on their own.
+//!
+//! ```no_run
+//! // build.rs
+//! let qt = qttypes_build::QtInstall::detect().unwrap_or_else(|e| panic!("{}", e));
+//! println!("cargo:rustc-link-search={}", qt.library_path);
+//! ```
+
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub use semver::Version;
+
+fn qmake_query(var: &str) -> Result<String, std::io::Error> {
+    let qmake = std::env::var("QMAKE").unwrap_or("qmake".to_string());
+    let stdout: Vec<u8> = Command::new(qmake).args(["-query", var]).output()?.stdout;
+    let stdout = String::from_utf8(stdout).expect("UTF-8 conversion failed");
+    Ok(stdout.trim().to_string())
+}
+
+fn core_header_path(file: &str, qt_include_path: &str, qt_library_path: &str) -> PathBuf {
+    let cargo_target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    let mut path = PathBuf::from(qt_include_path);
+    path.push("QtCore");
+    path.push(file);
+    if cargo_target_os == "macos" && !path.exists() {
+        path = Path::new(qt_library_path).join("QtCore.framework/Headers");
+        path.push(file);
+    }
+    path
+}
+
+fn detect_version_from_header(qt_include_path: &str, qt_library_path: &str) -> String {
+    const VERSION_HEADER: &str = "qtcoreversion.h";
+    let path = core_header_path(VERSION_HEADER, qt_include_path, qt_library_path);
+    let f = std::fs::File::open(&path).unwrap_or_else(|_| panic!("Cannot open `{:?}`", path));
+
+    // Find declaration of QTCORE_VERSION_STR
+    for line in BufReader::new(f).lines() {
+        let line = line.expect("UTF-8 conversion failed for qtcoreversion.h");
+        if line.contains("QTCORE_VERSION_STR") {
+            return line.split('\"').nth(1).expect("Parsing QTCORE_VERSION_STR").into();
+        }
+    }
+    panic!("Could not detect Qt version from include paths")
+}
+
+/// A Qt installation found by [`QtInstall::detect`].
+#[derive(Clone, Debug)]
+pub struct QtInstall {
+    /// The Qt version
+    pub version: Version,
+    /// The include directory to give to the `cpp_build` crate to locate the Qt headers
+    pub include_path: String,
+    /// The path containing the Qt libraries
+    pub library_path: String,
+}
+
+/// The error returned by [`QtInstall::detect`].
+#[derive(Debug)]
+pub enum DetectError {
+    /// Only one of `QT_INCLUDE_PATH` and `QT_LIBRARY_PATH` is set.
+    IncompleteEnv,
+    /// None of the detection methods found Qt.
+    NotFound(Vec<String>),
+}
+
+impl std::fmt::Display for DetectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DetectError::IncompleteEnv => write!(
+                f,
+                "QT_INCLUDE_PATH and QT_LIBRARY_PATH env variable must be either both empty or both set."
+            ),
+            DetectError::NotFound(tried) => {
+                writeln!(f, "Error: Could not find Qt. Make sure 'qmake' is in your path, or set QT_INCLUDE_PATH and QT_LIBRARY_PATH.")?;
+                for t in tried {
+                    writeln!(f, " - {}", t)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DetectError {}
+
+impl QtInstall {
+    fn new(version: &str, include_path: String, library_path: String) -> Option<QtInstall> {
+        let version = version.parse::<Version>().ok()?;
+        Some(QtInstall { version, include_path, library_path })
+    }
+
+    /// Find the Qt installation.
+    ///
+    /// The `QT_INCLUDE_PATH` and `QT_LIBRARY_PATH` environment variables always take precedence.
+    /// Then, if `QMAKE` is set, that qmake is queried. Otherwise we try, in order, the `qmake`
+    /// in the `PATH`, pkg-config, and the common installation directories.
+    ///
+    /// This must be called from a build script: it prints the `cargo:rerun-if-env-changed`
+    /// lines for the environment variables it reads.
+    pub fn detect() -> Result<QtInstall, DetectError> {
+        println!("cargo:rerun-if-env-changed=QT_INCLUDE_PATH");
+        println!("cargo:rerun-if-env-changed=QT_LIBRARY_PATH");
+        println!("cargo:rerun-if-env-changed=QMAKE");
+        println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+        match (
+            std::env::var("QT_INCLUDE_PATH").ok().filter(|x| !x.is_empty()),
+            std::env::var("QT_LIBRARY_PATH").ok().filter(|x| !x.is_empty()),
+        ) {
+            (Some(qt_include_path), Some(qt_library_path)) => {
+                let qt_version = detect_version_from_header(&qt_include_path, &qt_library_path);
+                return QtInstall::new(&qt_version, qt_include_path, qt_library_path).ok_or_else(
+                    || DetectError::NotFound(vec![format!("Invalid Qt version {}", qt_version)]),
+                );
+            }
+            (None, None) => {}
+            (Some(_), None) | (None, Some(_)) => return Err(DetectError::IncompleteEnv),
+        }
+
+        let mut tried = Vec::new();
+        if std::env::var_os("QMAKE").is_some() {
+            return QtInstall::from_qmake().map_err(|e| {
+                tried.push(format!("qmake from the QMAKE environment variable: {}", e));
+                DetectError::NotFound(tried)
+            });
+        }
+        match QtInstall::from_qmake() {
+            Ok(install) => return Ok(install),
+            Err(e) => tried.push(format!("qmake: {}", e)),
+        }
+        match QtInstall::from_pkg_config() {
+            Some(install) => return Ok(install),
+            None => tried.push("pkg-config: Qt5Core or Qt6Core not found".into()),
+        }
+        match QtInstall::from_common_paths() {
+            Some(install) => return Ok(install),
+            None => tried.push("common installation directories: not found".into()),
+        }
+        Err(DetectError::NotFound(tried))
+    }
+
+    /// Returns the path of a header of the QtCore module, such as `qconfig.h`.
+    pub fn core_header(&self, file: &str) -> PathBuf {
+        core_header_path(file, &self.include_path, &self.library_path)
+    }
+
+    fn from_qmake() -> Result<QtInstall, std::io::Error> {
+        let qt_version = qmake_query("QT_VERSION")?;
+        let qt_include_path = qmake_query("QT_INSTALL_HEADERS")?;
+        let qt_library_path = qmake_query("QT_INSTALL_LIBS")?;
+        QtInstall::new(&qt_version, qt_include_path, qt_library_path).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Invalid Qt version '{}'", qt_version),
+            )
+        })
+    }
+
+    fn from_pkg_config() -> Option<QtInstall> {
+        let pkg_config = std::env::var("PKG_CONFIG").unwrap_or("pkg-config".to_string());
+        let query = |args: &[&str]| -> Option<String> {
+            let output = Command::new(&pkg_config).args(args).output().ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            let stdout = String::from_utf8(output.stdout).ok()?;
+            Some(stdout.trim().to_string()).filter(|x| !x.is_empty())
+        };
+        ["Qt6Core", "Qt5Core"].iter().find_map(|module| {
+            let qt_version = query(&["--modversion", module])?;
+            let qt_include_path = query(&["--variable=includedir", module])?;
+            let qt_library_path = query(&["--variable=libdir", module])?;
+            QtInstall::new(&qt_version, qt_include_path, qt_library_path)
+        })
+    }
+
+    fn from_common_paths() -> Option<QtInstall> {
+        let cargo_target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+        // Candidate (include, library) directories.
+        let mut candidates: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let mut add_prefix = |prefix: PathBuf| {
+            candidates.push((prefix.join("include"), prefix.join("lib")));
+        };
+        match cargo_target_os.as_str() {
+            "windows" => {
+                // The online installer puts Qt in C:\Qt\<version>\<compiler>
+                let mut versions: Vec<PathBuf> = std::fs::read_dir("C:\\Qt")
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .map(|e| e.path())
+                    .collect();
+                versions.sort();
+                for version in versions.iter().rev() {
+                    for compiler in std::fs::read_dir(version).into_iter().flatten().flatten() {
+                        add_prefix(compiler.path());
+                    }
+                }
+            }
+            "macos" => {
+                for prefix in &[
+                    "/opt/homebrew/opt/qt",
+                    "/opt/homebrew/opt/qt@5",
+                    "/usr/local/opt/qt",
+                    "/usr/local/opt/qt@5",
+                ] {
+                    add_prefix(PathBuf::from(prefix));
+                }
+            }
+            _ => {
+                for multiarch in &["x86_64-linux-gnu", "aarch64-linux-gnu", "arm-linux-gnueabihf"] {
+                    for major in &["qt6", "qt5"] {
+                        candidates.push((
+                            Path::new("/usr/include").join(multiarch).join(major),
+                            Path::new("/usr/lib").join(multiarch),
+                        ));
+                    }
+                }
+                for major in &["qt6", "qt5", "qt"] {
+                    candidates
+                        .push((Path::new("/usr/include").join(major), PathBuf::from("/usr/lib64")));
+                    candidates
+                        .push((Path::new("/usr/include").join(major), PathBuf::from("/usr/lib")));
+                }
+            }
+        }
+        candidates.into_iter().find_map(|(include, lib)| {
+            let (include, lib) = (include.to_str()?.to_string(), lib.to_str()?.to_string());
+            if !Path::new(&include).join("QtCore/qtcoreversion.h").exists()
+                && !Path::new(&lib).join("QtCore.framework/Headers/qtcoreversion.h").exists()
+            {
+                return None;
+            }
+            let qt_version = detect_version_from_header(&include, &lib);
+            QtInstall::new(&qt_version, include, lib)
+        })
+    }
+}